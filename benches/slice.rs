@@ -0,0 +1,110 @@
+/*
+ * @file benches/slice.rs
+ *
+ * @module benches
+ *
+ * @brief Benchmark of numeric slices copied in bulk against elements written one by one.
+ *
+ * @details
+ * Benchmark of numeric slices copied in bulk against elements written one by one. Element wraps a
+ * numeric and only implements required functions of Tampon, so it uses the per-element loops like any
+ * implementor. Both are written with the same wire format.
+ *
+ * Run with `cargo bench --bench slice`.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use tampon::{ Tampon, buffer, serialize, deserialize, try_deserialize, Error };
+
+// Count of elements of slices (100 MB of u8)
+static ELEMENT_COUNT: usize = 100 * 1024 * 1024;
+
+// Count of runs kept for each measure, the fastest one is reported
+static RUN_COUNT: usize = 5;
+
+/// Numeric written element by element with default functions of Tampon.
+#[derive(Clone, Copy)]
+struct Element<T>(T);
+
+impl<T: Tampon> Tampon for Element<T> {
+    fn bytes_size(&self) -> usize {
+        self.0.bytes_size()
+    }
+
+    fn serialize(&self, buffer : &mut [u8]) -> usize {
+        self.0.serialize(buffer)
+    }
+
+    fn deserialize(buffer : &[u8]) -> (Self, usize) {
+        let (value, bytes_read) = T::deserialize(buffer);
+        (Element(value), bytes_read)
+    }
+
+    fn try_deserialize(buffer : &[u8]) -> Result<(Self, usize), Error> {
+        let (value, bytes_read) = T::try_deserialize(buffer)?;
+        Ok((Element(value), bytes_read))
+    }
+
+    fn min_bytes_size() -> usize {
+        T::min_bytes_size()
+    }
+}
+
+fn main() {
+    bench::<u8>("u8", ELEMENT_COUNT, 7);
+    bench::<f32>("f32", ELEMENT_COUNT / 4, 1.5);
+    bench::<u64>("u64", ELEMENT_COUNT / 8, 7);
+}
+
+/// Compare bulk and per-element serialize!, deserialize! and try_deserialize! of `count` elements of `value`.
+fn bench<T: Tampon + Copy + PartialEq + core::fmt::Debug>(name : &str, count : usize, value : T) {
+    let bulk:Vec<T> = vec![value; count];
+    let element:Vec<Element<T>> = vec![Element(value); count];
+
+    // Same wire format, so both read the same buffer
+    let mut buffer = buffer!([bulk]:T);
+    assert_eq!(buffer, buffer!([element]:Element<T>));
+
+    // Serialize into existing buffer, allocation of buffer! would hide copy
+    let serialize_bulk = measure(|| serialize!(buffer, [bulk]:T));
+    let serialize_element = measure(|| serialize!(buffer, [element]:Element<T>));
+
+    let deserialize_bulk = measure(|| { deserialize!(buffer, [v]:T); v });
+    let deserialize_element = measure(|| { deserialize!(buffer, [v]:Element<T>); v });
+
+    let try_deserialize_bulk = measure(|| (|| -> Result<Vec<T>, Error> { try_deserialize!(buffer, [v]:T); Ok(v) })());
+    let try_deserialize_element = measure(|| (|| -> Result<Vec<Element<T>>, Error> { try_deserialize!(buffer, [v]:Element<T>); Ok(v) })());
+
+    println!("[{}; {}]", name, count);
+    report("serialize!", serialize_bulk, serialize_element);
+    report("deserialize!", deserialize_bulk, deserialize_element);
+    report("try_deserialize!", try_deserialize_bulk, try_deserialize_element);
+}
+
+/// Fastest duration of `f` over RUN_COUNT runs.
+fn measure<R>(mut f : impl FnMut() -> R) -> Duration {
+    (0..RUN_COUNT).map(|_| {
+        let started = Instant::now();
+        black_box(f());
+        started.elapsed()
+    }).min().unwrap()
+}
+
+/// Print durations of bulk and per-element with the gain of bulk.
+fn report(name : &str, bulk : Duration, element : Duration) {
+    println!("  {:<18} bulk={:>12?} element={:>12?} gain=x{:.1}", name, bulk, element,
+        element.as_secs_f64() / bulk.as_secs_f64().max(f64::EPSILON));
+}
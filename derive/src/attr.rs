@@ -0,0 +1,163 @@
+/*
+ * @file tampon_derive/attr.rs
+ *
+ * @module tampon_derive
+ *
+ * @brief Parse #[tampon(...)] attributes of structs, enums and fields.
+ *
+ * @details
+ * Parse #[tampon(...)] attributes of structs, enums and fields.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{ Attribute, Expr, Ident, Path };
+
+/// Width of enum tag.
+#[derive(Clone, Copy)]
+pub enum TagWidth {
+    U8,
+    U16,
+    U32,
+    Varint,
+}
+
+impl TagWidth {
+    /// Maximum tag value.
+    pub fn max(self) -> u64 {
+        match self {
+            TagWidth::U8 => u8::MAX as u64,
+            TagWidth::U16 => u16::MAX as u64,
+            TagWidth::U32 => u32::MAX as u64,
+            TagWidth::Varint => u64::MAX,
+        }
+    }
+}
+
+impl quote::ToTokens for TagWidth {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        tokens.extend(match self {
+            TagWidth::U8 => quote!(::tampon::TagWidth::U8),
+            TagWidth::U16 => quote!(::tampon::TagWidth::U16),
+            TagWidth::U32 => quote!(::tampon::TagWidth::U32),
+            TagWidth::Varint => quote!(::tampon::TagWidth::Varint),
+        });
+    }
+}
+
+/// Attributes of field.
+#[derive(Default)]
+pub struct FieldAttrs {
+    /// `#[tampon(skip)]`
+    pub skip: bool,
+
+    /// `#[tampon(default = expr)]`
+    pub default: Option<Expr>,
+
+    /// `#[tampon(with = module)]`
+    pub with: Option<Path>,
+
+    /// `#[tampon(varint)]`
+    pub varint: bool,
+}
+
+/// Get tag width from `#[tampon(tag = width)]` attribute of enum.
+pub fn tag_width(attrs: &[Attribute]) -> syn::Result<TagWidth> {
+    let mut width = TagWidth::U8;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("tampon")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("tag") {
+                let ident: Ident = meta.value()?.parse()?;
+                width = match ident.to_string().as_str() {
+                    "u8" => TagWidth::U8,
+                    "u16" => TagWidth::U16,
+                    "u32" => TagWidth::U32,
+                    "varint" => TagWidth::Varint,
+                    _ => return Err(syn::Error::new_spanned(ident, "tag must be u8, u16, u32 or varint")),
+                };
+                Ok(())
+            } else {
+                Err(meta.error("unsupported tampon attribute"))
+            }
+        })?;
+    }
+
+    Ok(width)
+}
+
+/// Get `#[tampon(presence_bitmap)]` attribute of struct.
+pub fn presence_bitmap(attrs: &[Attribute]) -> syn::Result<bool> {
+    let mut bitmap = false;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("tampon")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("presence_bitmap") {
+                bitmap = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported tampon attribute"))
+            }
+        })?;
+    }
+
+    Ok(bitmap)
+}
+
+/// Get `#[tampon(skip)]`, `#[tampon(default = expr)]`, `#[tampon(with = module)]` and `#[tampon(varint)]` attributes of field.
+pub fn field_attrs(attrs: &[Attribute]) -> syn::Result<FieldAttrs> {
+    let mut field = FieldAttrs::default();
+
+    // Attributes declaring default, with and varint, for error spans
+    let (mut default_attr, mut with_attr, mut varint_attr) = (None, None, None);
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("tampon")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                field.skip = true;
+                Ok(())
+            } else if meta.path.is_ident("default") {
+                field.default = Some(meta.value()?.parse()?);
+                default_attr = Some(attr);
+                Ok(())
+            } else if meta.path.is_ident("with") {
+                field.with = Some(meta.value()?.parse()?);
+                with_attr = Some(attr);
+                Ok(())
+            } else if meta.path.is_ident("varint") {
+                field.varint = true;
+                varint_attr = Some(attr);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported tampon attribute"))
+            }
+        })?;
+    }
+
+    // Options can be split across attributes, so they are validated once all are parsed
+    if let (Some(attr), false) = (default_attr, field.skip) {
+        return Err(syn::Error::new_spanned(attr, "#[tampon(default = ...)] requires #[tampon(skip)]"));
+    }
+
+    if let (Some(attr), true) = (with_attr, field.skip) {
+        return Err(syn::Error::new_spanned(attr, "#[tampon(with = ...)] can't be used with #[tampon(skip)]"));
+    }
+
+    if let (Some(attr), true) = (varint_attr, field.skip || field.with.is_some()) {
+        return Err(syn::Error::new_spanned(attr, "#[tampon(varint)] can't be used with #[tampon(skip)] or #[tampon(with = ...)]"));
+    }
+
+    Ok(field)
+}
@@ -0,0 +1,330 @@
+/*
+ * @file tampon_derive/field.rs
+ *
+ * @module tampon_derive
+ *
+ * @brief Fields of structs and enum variants and the code generated for them.
+ *
+ * @details
+ * Fields of structs and enum variants and the code generated for them.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{ format_ident, quote };
+use syn::{ parse_quote, Expr, Fields, GenericArgument, Ident, Member, Path, PathArguments, Type };
+
+use crate::attr::field_attrs;
+
+/// Kind of field.
+pub enum FieldKind {
+    /// `(v):type`
+    Variable(Type),
+
+    /// `[v]:type`
+    Slice(Type),
+
+    /// Not serialized, filled with expression or Default::default() on decode.
+    Skip(Option<Expr>),
+
+    /// Serialized with functions of module.
+    With(Path),
+
+    /// `Option` of type with presence written as bit of the struct presence bitmap.
+    Optional(Type, usize),
+}
+
+/// Field of struct or variant to serialize.
+pub struct Field {
+    /// Member of struct or variant (`name` or `0`).
+    pub member: Member,
+
+    /// Local variable used when deserializing or matching variant.
+    pub local: Ident,
+
+    /// Kind and type of field.
+    pub kind: FieldKind,
+}
+
+/// Code generated for fields of a struct or variant.
+///
+/// Generated code expects `buffer`, `bytes_copied`, `bytes_read`, `limits` and `budget` variables, streaming code expects
+/// `stream` and `limits` variables.
+#[derive(Default)]
+pub struct FieldsCode {
+    /// Expressions added to get size in bytes.
+    pub bytes_size: Vec<TokenStream2>,
+
+    /// Expressions added to `bytes_copied`.
+    pub serialize: Vec<TokenStream2>,
+
+    /// Statements declaring local variables and increasing `bytes_read`.
+    pub deserialize: Vec<TokenStream2>,
+
+    /// Expressions added to `bytes_copied`, can use `?`.
+    pub try_serialize: Vec<TokenStream2>,
+
+    /// Statements declaring local variables and increasing `bytes_read`, can use `?`.
+    pub try_deserialize: Vec<TokenStream2>,
+
+    /// Statements writing into `stream`, can use `?`.
+    pub serialize_stream: Vec<TokenStream2>,
+
+    /// Statements declaring local variables read from `stream`, can use `?`.
+    pub deserialize_stream: Vec<TokenStream2>,
+}
+
+impl FieldsCode {
+    /// Generate code of `fields` where `access` gives the expression of field value.
+    pub fn new(fields: &[Field], access: impl Fn(&Field) -> TokenStream2) -> FieldsCode {
+        let mut code = FieldsCode::default();
+
+        // Presence bitmap is written before fields, one bit per optional field
+        let optionals: Vec<(usize, TokenStream2)> = fields.iter().filter_map(|field| match field.kind {
+            FieldKind::Optional(_, bit) => Some((bit, access(field))),
+            _ => None,
+        }).collect();
+
+        if !optionals.is_empty() {
+            let len = optionals.len().div_ceil(8);
+            let presence = optionals.iter().map(|(bit, value)| {
+                let (index, mask) = (bit / 8, 1u8 << (bit % 8));
+                quote!(if (#value).is_some() { presence[#index] |= #mask; })
+            });
+            let serialize = quote!({
+                let mut presence = [0u8; #len];
+                #(#presence)*
+                buffer[bytes_copied..bytes_copied + #len].copy_from_slice(&presence);
+                #len
+            });
+
+            code.bytes_size.push(quote!(#len));
+            code.serialize.push(serialize.clone());
+            code.deserialize.push(quote! {
+                let tampon_presence: [u8; #len] = buffer[bytes_read..bytes_read + #len].try_into().expect("Incorrect length!");
+                bytes_read += #len;
+            });
+            code.try_serialize.push(serialize.clone());
+            code.try_deserialize.push(quote! {
+                let tampon_presence: [u8; #len] = ::tampon::error::read_bytes(buffer, bytes_read)?;
+                bytes_read += #len;
+            });
+            code.serialize_stream.push(quote! {
+                stream.write_with(#len, |buffer| {
+                    let bytes_copied = 0;
+                    Ok(#serialize)
+                })?;
+            });
+            code.deserialize_stream.push(quote! {
+                let tampon_presence: [u8; #len] = stream.read_with(|buffer| Ok((::tampon::error::read_bytes(buffer, 0)?, #len)))?;
+            });
+        }
+
+        for field in fields {
+            let value = access(field);
+            let local = &field.local;
+
+            // Hidden parsers keep offsets relative to buffer start
+            match &field.kind {
+                FieldKind::Variable(ty) => {
+                    code.bytes_size.push(quote!(::tampon::bytes_size!((#value):#ty)));
+                    code.serialize.push(quote!(::tampon::serialize_parser!(buffer, bytes_copied, (#value):#ty)));
+                    code.deserialize.push(quote!(::tampon::deserialize_parser!(buffer, 0, bytes_read, (#local):#ty);));
+                    code.try_serialize.push(quote!(::tampon::try_serialize_parser!(buffer, bytes_copied, (#value):#ty)));
+                    code.try_deserialize.push(quote!(::tampon::try_deserialize_parser!(buffer, bytes_read, limits, budget, (#local):#ty);));
+                    code.serialize_stream.push(quote!(::tampon::serialize_to_writer_parser!(stream, (#value):#ty);));
+                    code.deserialize_stream.push(quote!(::tampon::deserialize_from_reader_parser!(stream, limits, (#local):#ty);));
+                },
+                FieldKind::Slice(ty) => {
+                    code.bytes_size.push(quote!(::tampon::bytes_size!([#value]:#ty)));
+                    code.serialize.push(quote!(::tampon::serialize_parser!(buffer, bytes_copied, [#value]:#ty)));
+                    code.deserialize.push(quote!(::tampon::deserialize_parser!(buffer, 0, bytes_read, [#local]:#ty);));
+                    code.try_serialize.push(quote!(::tampon::try_serialize_parser!(buffer, bytes_copied, [#value]:#ty)));
+                    code.try_deserialize.push(quote!(::tampon::try_deserialize_parser!(buffer, bytes_read, limits, budget, [#local]:#ty);));
+                    code.serialize_stream.push(quote!(::tampon::serialize_to_writer_parser!(stream, [#value]:#ty);));
+                    code.deserialize_stream.push(quote!(::tampon::deserialize_from_reader_parser!(stream, limits, [#local]:#ty);));
+                },
+                FieldKind::Skip(default) => {
+                    let default = match default {
+                        Some(expr) => quote!(#expr),
+                        None => quote!(::core::default::Default::default()),
+                    };
+                    code.deserialize.push(quote!(let #local = #default;));
+                    code.try_deserialize.push(quote!(let #local = #default;));
+                    code.deserialize_stream.push(quote!(let #local = #default;));
+                },
+                FieldKind::With(module) => {
+                    code.bytes_size.push(quote!(#module::bytes_size(&#value)));
+                    code.serialize.push(quote!(#module::serialize(&#value, &mut buffer[bytes_copied..])));
+                    code.deserialize.push(quote! {
+                        let #local = {
+                            let (value, size) = #module::deserialize(&buffer[bytes_read..]);
+                            bytes_read += size;
+                            value
+                        };
+                    });
+
+                    // Errors of module are relative to the buffer of field and need to be offset
+                    code.try_serialize.push(quote!(#module::try_serialize(&#value, &mut buffer[bytes_copied..]).map_err(|err| err.offset_by(bytes_copied))?));
+                    code.try_deserialize.push(quote! {
+                        let #local = {
+                            let (value, size) = #module::try_deserialize(&buffer[bytes_read..]).map_err(|err| err.offset_by(bytes_read))?;
+                            bytes_read += size;
+                            value
+                        };
+                    });
+
+                    // Size of value in module is unknown until read, missing bytes announced by try_deserialize are read from stream
+                    code.serialize_stream.push(quote!(stream.write_with(#module::bytes_size(&#value), |buffer| #module::try_serialize(&#value, buffer))?;));
+                    code.deserialize_stream.push(quote!(let #local = stream.read_with(#module::try_deserialize)?;));
+                },
+                FieldKind::Optional(ty, bit) => {
+                    let (index, mask) = (bit / 8, 1u8 << (bit % 8));
+
+                    // None isn't written, Some is written without presence byte
+                    code.bytes_size.push(quote!(match &#value { Some(value) => ::tampon::Tampon::bytes_size(value), None => 0 }));
+                    code.serialize.push(quote!(match &#value {
+                        Some(value) => <#ty as ::tampon::Tampon>::serialize(value, &mut buffer[bytes_copied..]),
+                        None => 0,
+                    }));
+                    code.deserialize.push(quote! {
+                        let #local = if tampon_presence[#index] & #mask != 0 {
+                            let (value, size) = <#ty as ::tampon::Tampon>::deserialize(&buffer[bytes_read..]);
+                            bytes_read += size;
+                            Some(value)
+                        } else {
+                            None
+                        };
+                    });
+                    code.try_serialize.push(quote!(match &#value {
+                        Some(value) => <#ty as ::tampon::Tampon>::try_serialize(value, &mut buffer[bytes_copied..]).map_err(|err| err.offset_by(bytes_copied))?,
+                        None => 0,
+                    }));
+                    code.try_deserialize.push(quote! {
+                        let #local = if tampon_presence[#index] & #mask != 0 {
+                            let (value, size) = <#ty as ::tampon::Tampon>::try_deserialize_with(&buffer[bytes_read..], limits, budget).map_err(|err| err.offset_by(bytes_read))?;
+                            bytes_read += size;
+                            Some(value)
+                        } else {
+                            None
+                        };
+                    });
+                    code.serialize_stream.push(quote! {
+                        if let Some(value) = &#value {
+                            <#ty as ::tampon::Tampon>::serialize_stream(value, stream)?;
+                        }
+                    });
+                    code.deserialize_stream.push(quote! {
+                        let #local = if tampon_presence[#index] & #mask != 0 {
+                            Some(<#ty as ::tampon::Tampon>::try_deserialize_stream(stream, limits)?)
+                        } else {
+                            None
+                        };
+                    });
+                },
+            }
+        }
+
+        code
+    }
+}
+
+/// Collect fields in declaration order, `Option` fields use the presence bitmap if `bitmap` is true.
+pub fn collect_fields(fields: &Fields, bitmap: bool) -> syn::Result<Vec<Field>> {
+    let mut bit = 0;
+
+    fields.iter().enumerate().map(|(index, field)| {
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(index.into()),
+        };
+
+        let attrs = field_attrs(&field.attrs)?;
+        let kind = if attrs.skip {
+            FieldKind::Skip(attrs.default)
+        } else if let Some(module) = attrs.with {
+            FieldKind::With(module)
+        } else if attrs.varint {
+            varint_kind(&field.ty)
+        } else {
+            match (bitmap, single_argument(&field.ty, "Option")) {
+                (true, Some(inner)) => {
+                    bit += 1;
+                    FieldKind::Optional(inner.clone(), bit - 1)
+                },
+                _ => field_kind(&field.ty),
+            }
+        };
+
+        Ok(Field { member, local: format_ident!("tampon_field_{}", index), kind })
+    }).collect()
+}
+
+/// Constructor of struct or variant from local variables.
+pub fn constructor(path: TokenStream2, data: &Fields, fields: &[Field]) -> TokenStream2 {
+    let locals = fields.iter().map(|field| &field.local);
+    let members = fields.iter().map(|field| &field.member);
+
+    match data {
+        Fields::Named(_) => quote!(#path { #(#members: #locals),* }),
+        Fields::Unnamed(_) => quote!(#path ( #(#locals),* )),
+        Fields::Unit => quote!(#path),
+    }
+}
+
+/// Pattern of variant binding local variables, skipped fields are ignored.
+pub fn pattern(path: TokenStream2, data: &Fields, fields: &[Field]) -> TokenStream2 {
+    let bindings = fields.iter().map(|field| match field.kind {
+        FieldKind::Skip(_) => quote!(_),
+        _ => { let local = &field.local; quote!(#local) },
+    });
+    let members = fields.iter().map(|field| &field.member);
+
+    match data {
+        Fields::Named(_) => quote!(#path { #(#members: #bindings),* }),
+        Fields::Unnamed(_) => quote!(#path ( #(#bindings),* )),
+        Fields::Unit => quote!(#path),
+    }
+}
+
+/// Get the kind of field from its type, `Vec<T>` is written as a slice of `T`.
+fn field_kind(ty: &Type) -> FieldKind {
+    match single_argument(ty, "Vec") {
+        Some(inner) => FieldKind::Slice(inner.clone()),
+        None => FieldKind::Variable(ty.clone()),
+    }
+}
+
+/// Get the kind of field written as varint, integers of `Vec<T>` are written as a slice of `Varint<T>`.
+fn varint_kind(ty: &Type) -> FieldKind {
+    match field_kind(ty) {
+        FieldKind::Slice(inner) => FieldKind::Slice(parse_quote!(::tampon::Varint<#inner>)),
+        _ => FieldKind::Variable(parse_quote!(::tampon::Varint<#ty>)),
+    }
+}
+
+/// Get `T` of type written as `name<T>`.
+fn single_argument<'a>(ty: &'a Type, name: &str) -> Option<&'a Type> {
+    if let Type::Path(path) = ty {
+        if path.qself.is_none() && path.path.segments.len() == 1 && path.path.segments[0].ident == name {
+            if let PathArguments::AngleBracketed(args) = &path.path.segments[0].arguments {
+                if let (1, Some(GenericArgument::Type(inner))) = (args.args.len(), args.args.first()) {
+                    return Some(inner);
+                }
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,339 @@
+/*
+ * @file tampon_derive/lib.rs
+ *
+ * @module tampon_derive
+ *
+ * @brief Derive macro generating implementation of Tampon trait.
+ *
+ * @details
+ * Derive macro generating implementation of Tampon trait with the same wire format as
+ * bytes_size!, serialize! and deserialize! macros.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+//! Derive macro of [`tampon`](https://docs.rs/tampon) crate. Use it with the `derive` feature of `tampon` instead of
+//! depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{ parse_macro_input, parse_quote, Data, DataEnum, DeriveInput, Fields };
+
+use attr::{ presence_bitmap, tag_width };
+use field::{ collect_fields, constructor, pattern, FieldsCode };
+
+/// Parse #[tampon(...)] attributes
+mod attr;
+
+/// Fields and generated code
+mod field;
+
+/// ##### Derive macro generating the implementation of trait `Tampon` for structs and enums.
+///
+/// Fields are serialized in declaration order with the same wire format as `bytes_size!`, `serialize!`
+/// and `deserialize!` macros, so derived and hand-written implementations interoperate.
+///
+/// Enum variants are written as a discriminant tag followed by their fields. The tag is the discriminant of the
+/// variant, written as `u8` unless specified with `#[tampon(tag = u8|u16|u32|varint)]` on the enum.
+///
+/// # Struct attribute(s)
+/// * `#[tampon(presence_bitmap)]` - Presence of `Option` fields is packed into a bitmap of one bit per field written
+///   before the fields, instead of a presence byte per field. `None` fields aren't written.
+///
+/// # Field attribute(s)
+/// * `#[tampon(skip)]` - Field is not serialized and is filled with `Default::default()` on decode.
+/// * `#[tampon(skip, default = expr)]` - Field is not serialized and is filled with `expr` on decode.
+/// * `#[tampon(varint)]` - Integer field, or `Vec` of integers, is written as variable length integer like `Varint<T>` in macros.
+/// * `#[tampon(with = module)]` - Field is serialized with functions of `module` :
+///     * `fn bytes_size(value: &T) -> usize`
+///     * `fn serialize(value: &T, buffer: &mut [u8]) -> usize`
+///     * `fn deserialize(buffer: &[u8]) -> (T, usize)`
+///     * `fn try_serialize(value: &T, buffer: &mut [u8]) -> Result<usize, tampon::Error>`
+///     * `fn try_deserialize(buffer: &[u8]) -> Result<(T, usize), tampon::Error>`
+///
+/// `try_serialize` and `try_deserialize` of `with` modules are called by the `try_` functions of `Tampon` and must not
+/// panic on untrusted buffers. Their errors are offset from the start of the struct.
+/// `deserialize_from_reader` reads `with` fields by calling `try_deserialize` again with the missing bytes announced by
+/// `Error::UnexpectedEnd` and `Error::InvalidLength`.
+///
+/// # Compatible field(s)
+/// * `bool`
+/// * `char`
+/// * All numeric types, `usize` and `isize` are written as `u64` and `i64`
+/// * `String`
+/// * Implementors of trait `Tampon`, including generic and path-qualified types
+/// * `Option` of the above types
+/// * Tuples of up to 12 of the above types
+/// * Arrays `[T; N]` of the above types, written without length prefix
+/// * `Vec` of the above types
+/// * `VecDeque`, `HashSet`, `BTreeSet`, `HashMap`, `BTreeMap` and `Sorted` of the above types
+#[proc_macro_derive(Tampon, attributes(tampon))]
+pub fn derive_tampon(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let result = match &input.data {
+        Data::Struct(data) => expand_struct(&input, &data.fields),
+        Data::Enum(data) => expand_enum(&input, data),
+        Data::Union(_) => Err(syn::Error::new_spanned(&input.ident, "#[derive(Tampon)] doesn't support unions")),
+    };
+
+    match result {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Generate implementation of Tampon for struct.
+fn expand_struct(input: &DeriveInput, data: &Fields) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let fields = collect_fields(data, presence_bitmap(&input.attrs)?)?;
+    let constructor = constructor(quote!(#name), data, &fields);
+
+    let FieldsCode { bytes_size, serialize, deserialize, try_serialize, try_deserialize, serialize_stream, deserialize_stream } = FieldsCode::new(&fields, |field| {
+        let member = &field.member;
+        quote!(self.#member)
+    });
+
+    let body = quote! {
+        fn bytes_size(&self) -> usize {
+            0 #(+ #bytes_size)*
+        }
+
+        fn serialize(&self, buffer : &mut [u8]) -> usize {
+            let mut bytes_copied = 0;
+            #(bytes_copied += #serialize;)*
+            bytes_copied
+        }
+
+        fn deserialize(buffer : &[u8]) -> (Self, usize) {
+            let mut bytes_read = 0;
+            #(#deserialize)*
+            (#constructor, bytes_read)
+        }
+
+        fn try_serialize(&self, buffer : &mut [u8]) -> Result<usize, ::tampon::Error> {
+            // Verify size before writing anything
+            let required = ::tampon::Tampon::bytes_size(self);
+            if required > buffer.len() {
+                return Err(::tampon::Error::BufferTooSmall { required, available: buffer.len() });
+            }
+
+            let mut bytes_copied = 0;
+            #(bytes_copied += #try_serialize;)*
+            Ok(bytes_copied)
+        }
+
+        fn try_deserialize(buffer : &[u8]) -> Result<(Self, usize), ::tampon::Error> {
+            <Self as ::tampon::Tampon>::try_deserialize_with(buffer, &::tampon::Limits::default(), &mut ::tampon::Budget::new())
+        }
+
+        fn try_deserialize_with(buffer : &[u8], limits : &::tampon::Limits, budget : &mut ::tampon::Budget) -> Result<(Self, usize), ::tampon::Error> {
+            let mut bytes_read = 0;
+            #(#try_deserialize)*
+            Ok((#constructor, bytes_read))
+        }
+
+        #[inline]
+        fn is_fallible() -> bool {
+            true
+        }
+
+        fn serialize_stream(&self, stream : &mut ::tampon::stream::StreamWriter<'_>) -> Result<(), ::tampon::Error> {
+            // Fields are written one by one, so big fields don't need a buffer of the whole struct
+            #(#serialize_stream)*
+            Ok(())
+        }
+
+        fn try_deserialize_stream(stream : &mut ::tampon::stream::StreamReader<'_>, limits : &::tampon::Limits) -> Result<Self, ::tampon::Error> {
+            #(#deserialize_stream)*
+            Ok(#constructor)
+        }
+    };
+
+    Ok(implementation(input, body))
+}
+
+/// Generate implementation of Tampon for enum.
+fn expand_enum(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream2> {
+    let width = tag_width(&input.attrs)?;
+
+    if data.variants.is_empty() {
+        return Err(syn::Error::new_spanned(&input.ident, "#[derive(Tampon)] enum must have at least one variant"));
+    }
+
+    let mut bytes_size = Vec::new();
+    let mut serialize = Vec::new();
+    let mut deserialize = Vec::new();
+    let mut try_serialize = Vec::new();
+    let mut try_deserialize = Vec::new();
+    let mut serialize_stream = Vec::new();
+    let mut deserialize_stream = Vec::new();
+
+    // Tag follow discriminant rules of Rust, starting at 0 and incrementing from previous
+    let mut next_tag: u64 = 0;
+
+    for variant in &data.variants {
+        let tag = match &variant.discriminant {
+            Some((_, expr)) => discriminant(expr)?,
+            None => next_tag,
+        };
+        if tag > width.max() {
+            return Err(syn::Error::new_spanned(variant, format!("#[derive(Tampon)] tag {} doesn't fit in tag width", tag)));
+        }
+        next_tag = tag.wrapping_add(1);
+
+        let ident = &variant.ident;
+        let fields = collect_fields(&variant.fields, false)?;
+        let pattern = pattern(quote!(Self::#ident), &variant.fields, &fields);
+        let constructor = constructor(quote!(Self::#ident), &variant.fields, &fields);
+
+        // Variant fields are bound by reference
+        let code = FieldsCode::new(&fields, |field| {
+            let local = &field.local;
+            quote!(*#local)
+        });
+        let (code_size, code_ser, code_de, code_try_ser, code_try_de) =
+            (&code.bytes_size, &code.serialize, &code.deserialize, &code.try_serialize, &code.try_deserialize);
+        let (code_ser_stream, code_de_stream) = (&code.serialize_stream, &code.deserialize_stream);
+
+        // Fields are written after tag
+        bytes_size.push(quote!(#pattern => #width.size(#tag) #(+ #code_size)*,));
+        serialize.push(quote!(#pattern => {
+            let mut bytes_copied = #width.serialize(#tag, buffer);
+            #(bytes_copied += #code_ser;)*
+            bytes_copied
+        },));
+        deserialize.push(quote!(#tag => {
+            #(#code_de)*
+            (#constructor, bytes_read)
+        },));
+        try_serialize.push(quote!(#pattern => {
+            let mut bytes_copied = #width.try_serialize(#tag, buffer)?;
+            #(bytes_copied += #code_try_ser;)*
+            Ok(bytes_copied)
+        },));
+        try_deserialize.push(quote!(#tag => {
+            #(#code_try_de)*
+            Ok((#constructor, bytes_read))
+        },));
+        serialize_stream.push(quote!(#pattern => {
+            stream.write_with(#width.size(#tag), |buffer| #width.try_serialize(#tag, buffer))?;
+            #(#code_ser_stream)*
+            Ok(())
+        },));
+        deserialize_stream.push(quote!(#tag => {
+            #(#code_de_stream)*
+            Ok(#constructor)
+        },));
+    }
+
+    let body = quote! {
+        fn bytes_size(&self) -> usize {
+            match self {
+                #(#bytes_size)*
+            }
+        }
+
+        fn serialize(&self, buffer : &mut [u8]) -> usize {
+            match self {
+                #(#serialize)*
+            }
+        }
+
+        fn deserialize(buffer : &[u8]) -> (Self, usize) {
+            let (tag, mut bytes_read) = #width.deserialize(buffer);
+
+            match tag {
+                #(#deserialize)*
+                _ => panic!("{}", ::tampon::Error::UnknownTag { offset: 0, tag }),
+            }
+        }
+
+        fn try_serialize(&self, buffer : &mut [u8]) -> Result<usize, ::tampon::Error> {
+            // Verify size before writing tag
+            let required = ::tampon::Tampon::bytes_size(self);
+            if required > buffer.len() {
+                return Err(::tampon::Error::BufferTooSmall { required, available: buffer.len() });
+            }
+
+            match self {
+                #(#try_serialize)*
+            }
+        }
+
+        fn try_deserialize(buffer : &[u8]) -> Result<(Self, usize), ::tampon::Error> {
+            <Self as ::tampon::Tampon>::try_deserialize_with(buffer, &::tampon::Limits::default(), &mut ::tampon::Budget::new())
+        }
+
+        fn try_deserialize_with(buffer : &[u8], limits : &::tampon::Limits, budget : &mut ::tampon::Budget) -> Result<(Self, usize), ::tampon::Error> {
+            let (tag, mut bytes_read) = #width.try_deserialize(buffer)?;
+
+            match tag {
+                #(#try_deserialize)*
+                _ => Err(::tampon::Error::UnknownTag { offset: 0, tag }),
+            }
+        }
+
+        #[inline]
+        fn is_fallible() -> bool {
+            true
+        }
+
+        fn serialize_stream(&self, stream : &mut ::tampon::stream::StreamWriter<'_>) -> Result<(), ::tampon::Error> {
+            match self {
+                #(#serialize_stream)*
+            }
+        }
+
+        fn try_deserialize_stream(stream : &mut ::tampon::stream::StreamReader<'_>, limits : &::tampon::Limits) -> Result<Self, ::tampon::Error> {
+            let offset = stream.bytes_read();
+            let tag = stream.read_with(|buffer| #width.try_deserialize(buffer))?;
+
+            match tag {
+                #(#deserialize_stream)*
+                _ => Err(::tampon::Error::UnknownTag { offset, tag }),
+            }
+        }
+    };
+
+    Ok(implementation(input, body))
+}
+
+/// Wrap body into implementation of Tampon, type parameters must implement Tampon.
+fn implementation(input: &DeriveInput, body: TokenStream2) -> TokenStream2 {
+    let name = &input.ident;
+
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(::tampon::Tampon));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // Struct or variant without fields don't use all variables
+    quote! {
+        #[automatically_derived]
+        #[allow(unused_mut, unused_variables)]
+        impl #impl_generics ::tampon::Tampon for #name #ty_generics #where_clause {
+            #body
+        }
+    }
+}
+
+/// Get tag of variant from its discriminant.
+fn discriminant(expr: &syn::Expr) -> syn::Result<u64> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(int), .. }) => int.base10_parse::<u64>(),
+        _ => Err(syn::Error::new_spanned(expr, "#[derive(Tampon)] discriminant must be an integer literal")),
+    }
+}
@@ -0,0 +1,290 @@
+/*
+ * @file tampon_derive/tests/derive_attr_test.rs
+ *
+ * @module tampon_derive::tests
+ *
+ * @brief Contains integration tests for #[tampon(...)] field attributes.
+ *
+ * @details
+ * Contains integration tests for #[tampon(...)] field attributes.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use std::collections::HashMap;
+use tampon::{ Tampon, Error, Budget, Limits, buffer };
+
+// Custom codec writing a u64 timestamp as 6 bytes
+mod timestamp {
+    pub fn bytes_size(_value: &u64) -> usize {
+        6
+    }
+
+    pub fn serialize(value: &u64, buffer: &mut [u8]) -> usize {
+        buffer[0..6].copy_from_slice(&value.to_le_bytes()[0..6]);
+        6
+    }
+
+    pub fn deserialize(buffer: &[u8]) -> (u64, usize) {
+        let mut bytes = [0u8; 8];
+        bytes[0..6].copy_from_slice(&buffer[0..6]);
+        (u64::from_le_bytes(bytes), 6)
+    }
+
+    pub fn try_serialize(value: &u64, buffer: &mut [u8]) -> Result<usize, tampon::Error> {
+        if *value >= 1 << 48 {
+            return Err(tampon::Error::OutOfRange { offset: 0, value: *value as i128 });
+        }
+        if buffer.len() < 6 {
+            return Err(tampon::Error::BufferTooSmall { required: 6, available: buffer.len() });
+        }
+        Ok(serialize(value, buffer))
+    }
+
+    pub fn try_deserialize(buffer: &[u8]) -> Result<(u64, usize), tampon::Error> {
+        if buffer.len() < 6 {
+            return Err(tampon::Error::UnexpectedEnd { offset: 0, needed: 6, available: buffer.len() });
+        }
+        Ok(deserialize(buffer))
+    }
+}
+
+// Runtime state that must not hit the wire
+#[derive(Debug, PartialEq)]
+struct Handle(u32);
+
+// Struct mixing wire data and runtime state
+#[derive(Tampon, Debug, PartialEq)]
+struct Session {
+    id:u32,
+    #[tampon(skip)]
+    cache:HashMap<u32, String>,
+    #[tampon(with = timestamp)]
+    created:u64,
+    #[tampon(skip, default = Handle(7))]
+    handle:Handle,
+    names:Vec<String>,
+}
+
+// Tuple struct with attributes
+#[derive(Tampon, Debug, PartialEq)]
+struct Pair(#[tampon(skip)] u8, #[tampon(with = timestamp)] u64);
+
+// Enum with attributes in variants
+#[derive(Tampon, Debug, PartialEq)]
+enum Event {
+    Open { #[tampon(with = timestamp)] at: u64, #[tampon(skip)] retries: u8 },
+    Close(#[tampon(skip, default = 1)] u8, u16),
+}
+
+// Struct with options split across attributes
+#[derive(Tampon, Debug, PartialEq)]
+struct Split {
+    #[tampon(default = Handle(3))]
+    #[tampon(skip)]
+    handle:Handle,
+    id:u16,
+}
+
+// Struct with all fields skipped
+#[derive(Tampon, Debug, PartialEq)]
+struct Runtime {
+    #[tampon(skip)]
+    cache:Vec<u8>,
+}
+
+// Struct with presence of optional fields packed into a bitmap
+#[derive(Tampon, Debug, PartialEq)]
+#[tampon(presence_bitmap)]
+struct Profile {
+    id:u32,
+    o1:Option<u8>,
+    o2:Option<String>,
+    o3:Option<u16>,
+    o4:Option<u8>,
+    o5:Option<u8>,
+    o6:Option<u8>,
+    o7:Option<u8>,
+    o8:Option<u8>,
+    o9:Option<i32>,
+    #[tampon(skip)]
+    o10:Option<u8>,
+}
+
+// Struct with optional fields written with presence bytes
+#[derive(Tampon, Debug, PartialEq)]
+struct Contact {
+    o1:Option<u8>,
+    o2:Option<String>,
+}
+
+// Struct with integers written as varint
+#[derive(Tampon, Debug, PartialEq)]
+struct Counter {
+    #[tampon(varint)]
+    hits:u64,
+    #[tampon(varint)]
+    delta:i32,
+    #[tampon(varint)]
+    samples:Vec<u32>,
+    flags:u16,
+}
+
+// Serialize a value with its implementation of Tampon
+fn to_buffer<T: Tampon>(value: &T) -> Vec<u8> {
+    let mut buffer = vec![0u8; value.bytes_size()];
+    assert_eq!(value.serialize(&mut buffer), buffer.len());
+    buffer
+}
+
+// Create a session with runtime state
+fn session() -> Session {
+    let mut cache = HashMap::new();
+    cache.insert(1, String::from("cached"));
+
+    Session { id: 12, cache, created: 0x0000_0102_0304_0506, handle: Handle(99), names: vec![String::from("a")] }
+}
+
+#[test]
+// Test skipped fields are not written and filled on decode
+fn derive_attr_skip() {
+    let to = session();
+    let buffer = to_buffer(&to);
+
+    let (id, created, names) = (to.id, to.created, &to.names);
+    let expected = buffer!((id):u32, [vec![6u8, 5, 4, 3, 2, 1]]:u8, [names]:String);
+    assert_eq!(buffer[0..4], expected[0..4]);
+    assert_eq!(buffer[4..10], expected[8..14]);
+    assert_eq!(buffer[10..], expected[14..]);
+    assert_eq!(created, 0x0000_0102_0304_0506);
+
+    let (from, size) = Session::deserialize(&buffer);
+    assert_eq!(size, buffer.len());
+    assert_eq!(from, Session { id: 12, cache: HashMap::new(), created: to.created, handle: Handle(7), names: to.names });
+}
+
+#[test]
+// Test fields serialized with custom codec
+fn derive_attr_with() {
+    let to = Pair(5, 0x0000_0A0B_0C0D_0E0F);
+    assert_eq!(to.bytes_size(), 6);
+
+    let buffer = to_buffer(&to);
+    assert_eq!(buffer, vec![15, 14, 13, 12, 11, 10]);
+    assert_eq!(Pair::deserialize(&buffer), (Pair(0, 0x0000_0A0B_0C0D_0E0F), 6));
+}
+
+#[test]
+// Test attributes of enum variants fields
+fn derive_attr_enum() {
+    let to = Event::Open { at: 1, retries: 3 };
+    let buffer = to_buffer(&to);
+    assert_eq!(buffer, vec![0, 1, 0, 0, 0, 0, 0]);
+    assert_eq!(Event::deserialize(&buffer), (Event::Open { at: 1, retries: 0 }, 7));
+
+    let to = Event::Close(9, 2);
+    let buffer = to_buffer(&to);
+    assert_eq!(buffer, vec![1, 2, 0]);
+    assert_eq!(Event::try_deserialize(&buffer), Ok((Event::Close(1, 2), 3)));
+}
+
+#[test]
+// Test options of a field split across attributes
+fn derive_attr_split() {
+    let to = Split { handle: Handle(9), id: 258 };
+    let buffer = to_buffer(&to);
+    assert_eq!(buffer, vec![2, 1]);
+    assert_eq!(Split::deserialize(&buffer), (Split { handle: Handle(3), id: 258 }, 2));
+}
+
+#[test]
+// Test struct with all fields skipped
+fn derive_attr_all_skipped() {
+    let to = Runtime { cache: vec![1, 2, 3] };
+    assert_eq!(to.bytes_size(), 0);
+    assert_eq!(Runtime::deserialize(&[]), (Runtime { cache: Vec::new() }, 0));
+}
+
+#[test]
+// Test fallible functions with attributes
+fn derive_attr_fallible() {
+    let to = session();
+    let mut buffer = vec![0u8; 8];
+    assert_eq!(to.try_serialize(&mut buffer), Err(Error::BufferTooSmall { required: to.bytes_size(), available: 8 }));
+
+    let mut buffer = vec![0u8; to.bytes_size()];
+    assert_eq!(to.try_serialize(&mut buffer), Ok(buffer.len()));
+
+    // Offset of error include custom codec size
+    assert_eq!(Session::try_deserialize(&buffer[0..12]), Err(Error::UnexpectedEnd { offset: 10, needed: 4, available: 2 }));
+    assert_eq!(Session::try_deserialize(&buffer).map(|(from, _)| from.handle), Ok(Handle(7)));
+
+    // Errors of custom codec are offset from start of struct
+    assert_eq!(Session::try_deserialize(&buffer[0..8]), Err(Error::UnexpectedEnd { offset: 4, needed: 6, available: 4 }));
+    let to = Session { created: 1 << 48, ..session() };
+    assert_eq!(to.try_serialize(&mut buffer), Err(Error::OutOfRange { offset: 4, value: 1 << 48 }));
+}
+
+#[test]
+// Test optional fields with presence byte and presence bitmap
+fn derive_attr_presence_bitmap() {
+    let to = Contact { o1: Some(3), o2: None };
+    assert_eq!(to_buffer(&to), vec![1, 3, 0]);
+    assert_eq!(Contact::deserialize(&[1, 3, 0]), (to, 3));
+
+    // 9 optional fields use 2 bytes of bitmap, None fields aren't written
+    let to = Profile { id: 7, o1: None, o2: Some(String::from("ab")), o3: Some(0x0102), o4: None, o5: None, o6: None,
+        o7: None, o8: None, o9: Some(-1), o10: Some(5) };
+    assert_eq!(to.bytes_size(), 2 + 4 + 6 + 2 + 4);
+
+    let buffer = to_buffer(&to);
+    assert_eq!(buffer, vec![0b0000_0110, 0b0000_0001, 7, 0, 0, 0, 2, 0, 0, 0, 97, 98, 2, 1, 255, 255, 255, 255]);
+    assert_eq!(Profile::deserialize(&buffer), (Profile { o10: None, ..to }, buffer.len()));
+
+    let to = Profile { id: 1, o1: Some(1), o2: None, o3: None, o4: None, o5: None, o6: None, o7: None, o8: Some(8),
+        o9: None, o10: None };
+    let buffer = to_buffer(&to);
+    assert_eq!(buffer, vec![0b1000_0001, 0, 1, 0, 0, 0, 1, 8]);
+    assert_eq!(Profile::try_deserialize(&buffer), Ok((to, buffer.len())));
+}
+
+#[test]
+// Test fallible functions with presence bitmap
+fn derive_attr_presence_bitmap_fallible() {
+    let to = Profile { id: 7, o1: None, o2: Some(String::from("abc")), o3: None, o4: None, o5: None, o6: None,
+        o7: None, o8: None, o9: Some(1), o10: None };
+    let mut buffer = vec![0u8; to.bytes_size()];
+    assert_eq!(to.try_serialize(&mut buffer), Ok(buffer.len()));
+
+    // Bitmap and values missing
+    assert_eq!(Profile::try_deserialize(&buffer[0..1]), Err(Error::UnexpectedEnd { offset: 0, needed: 2, available: 1 }));
+    assert_eq!(Profile::try_deserialize(&buffer[0..15]), Err(Error::UnexpectedEnd { offset: 13, needed: 4, available: 2 }));
+
+    // Limits are passed to optional fields
+    assert_eq!(Profile::try_deserialize_with(&buffer, &Limits::new(16, 2, 1024), &mut Budget::new()), Err(Error::LimitExceeded { offset: 6, length: 3, limit: 2 }));
+}
+
+#[test]
+// Test integers fields written as varint
+fn derive_attr_varint() {
+    let to = Counter { hits: 300, delta: -2, samples: vec![1, 128], flags: 5 };
+    assert_eq!(to.bytes_size(), 2 + 1 + 4 + 1 + 2 + 2);
+
+    let buffer = to_buffer(&to);
+    assert_eq!(buffer, vec![0xAC, 0x02, 3, 2, 0, 0, 0, 1, 0x80, 0x01, 5, 0]);
+    assert_eq!(Counter::deserialize(&buffer), (Counter { samples: to.samples.clone(), ..to }, buffer.len()));
+    assert_eq!(Counter::try_deserialize(&buffer), Ok((to, buffer.len())));
+
+    // Overlong varints are rejected
+    assert_eq!(Counter::try_deserialize(&[0x80, 0x00]), Err(Error::InvalidValue { offset: 0, reason: String::from("varint is overlong") }));
+}
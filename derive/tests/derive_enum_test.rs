@@ -0,0 +1,134 @@
+/*
+ * @file tampon_derive/tests/derive_enum_test.rs
+ *
+ * @module tampon_derive::tests
+ *
+ * @brief Contains integration tests for #[derive(Tampon)] on enums.
+ *
+ * @details
+ * Contains integration tests for #[derive(Tampon)] on enums.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use tampon::{ Tampon, Error, buffer, tampon_enum };
+
+// Enum with unit, tuple and struct variants and default u8 tag
+#[derive(Tampon, Debug, PartialEq)]
+enum Message {
+    Ping,
+    Data(u32, Vec<u8>),
+    Login { name: String, tokens: Vec<u32> },
+}
+
+// Enum with explicit discriminants
+#[derive(Tampon, Debug, PartialEq)]
+#[tampon(tag = u16)]
+enum Explicit {
+    A = 10,
+    B,
+    C = 300,
+}
+
+// Enum with varint tag
+#[derive(Tampon, Debug, PartialEq)]
+#[tampon(tag = varint)]
+#[repr(u16)]
+enum Wide {
+    Small(bool),
+    Big { value: i64 } = 1000,
+}
+
+// Same enum declared with tampon_enum! used to test interoperability
+tampon_enum! {
+    #[derive(Debug, PartialEq)]
+    enum MessageMacro : u8 {
+        Ping = 0,
+        Data(u32, [u8]) = 1,
+        Login { name: String, tokens: [u32] } = 2,
+    }
+}
+
+// Serialize a value with its implementation of Tampon
+fn to_buffer<T: Tampon>(value: &T) -> Vec<u8> {
+    let mut buffer = vec![0u8; value.bytes_size()];
+    assert_eq!(value.serialize(&mut buffer), buffer.len());
+    buffer
+}
+
+#[test]
+// Test derived enum round trip of every kind of variant
+fn derive_enum_round_trip() {
+    for to in [Message::Ping, Message::Data(7, vec![1, 2, 3]), Message::Login { name: String::from("user"), tokens: vec![1, 2] }] {
+        let buffer = to_buffer(&to);
+        assert_eq!(Message::deserialize(&buffer), (to, buffer.len()));
+    }
+}
+
+#[test]
+// Test derived enum wire format
+fn derive_enum_wire_format() {
+    assert_eq!(to_buffer(&Message::Ping), vec![0]);
+    assert_eq!(to_buffer(&Message::Data(7, vec![1, 2])), vec![1, 7, 0, 0, 0, 2, 0, 0, 0, 1, 2]);
+    assert_eq!(to_buffer(&Message::Login { name: String::from("a"), tokens: vec![] }), vec![2, 1, 0, 0, 0, 97, 0, 0, 0, 0]);
+
+    // Same format as tampon_enum!
+    let to = MessageMacro::Login { name: String::from("user"), tokens: vec![1, 2] };
+    let buffer = buffer!((to):MessageMacro);
+    assert_eq!(Message::deserialize(&buffer), (Message::Login { name: String::from("user"), tokens: vec![1, 2] }, buffer.len()));
+}
+
+#[test]
+// Test derived enum with explicit discriminants and wider tags
+fn derive_enum_tag_width() {
+    assert_eq!(to_buffer(&Explicit::A), vec![10, 0]);
+    assert_eq!(to_buffer(&Explicit::B), vec![11, 0]);
+    assert_eq!(to_buffer(&Explicit::C), vec![44, 1]);
+    assert_eq!(Explicit::deserialize(&[44, 1]), (Explicit::C, 2));
+
+    assert_eq!(to_buffer(&Wide::Small(true)), vec![0, 1]);
+    assert_eq!(to_buffer(&Wide::Big { value: -1 }), vec![232, 7, 255, 255, 255, 255, 255, 255, 255, 255]);
+    assert_eq!(Wide::deserialize(&[232, 7, 255, 255, 255, 255, 255, 255, 255, 255]), (Wide::Big { value: -1 }, 10));
+}
+
+#[test]
+// Test derived enum returning error for unknown tag
+fn derive_enum_unknown_tag() {
+    assert_eq!(Message::try_deserialize(&[3]), Err(Error::UnknownTag { offset: 0, tag: 3 }));
+    assert_eq!(Explicit::try_deserialize(&[12, 0]), Err(Error::UnknownTag { offset: 0, tag: 12 }));
+    assert_eq!(Wide::try_deserialize(&[232, 8]), Err(Error::UnknownTag { offset: 0, tag: 1128 }));
+}
+
+#[test]
+#[should_panic]
+// Test derived enum panicking for unknown tag
+fn derive_enum_unknown_tag_panic() {
+    Message::deserialize(&[3]);
+}
+
+#[test]
+// Test derived enum fallible functions
+fn derive_enum_fallible() {
+    let to = Message::Data(7, vec![1, 2]);
+    let mut buffer = vec![0u8; 4];
+    assert_eq!(to.try_serialize(&mut buffer), Err(Error::BufferTooSmall { required: 11, available: 4 }));
+    assert_eq!(buffer, vec![0u8; 4]);
+
+    let mut buffer = vec![0u8; 11];
+    assert_eq!(to.try_serialize(&mut buffer), Ok(11));
+
+    // Offset of errors include tag
+    assert_eq!(Message::try_deserialize(&buffer[0..10]), Err(Error::InvalidLength { offset: 5, length: 2, available: 1 }));
+    assert_eq!(Message::try_deserialize(&[]), Err(Error::UnexpectedEnd { offset: 0, needed: 1, available: 0 }));
+    assert_eq!(Message::try_deserialize(&buffer), Ok((to, 11)));
+}
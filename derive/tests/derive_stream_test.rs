@@ -0,0 +1,150 @@
+/*
+ * @file tampon_derive/tests/derive_stream_test.rs
+ *
+ * @module tampon_derive::tests
+ *
+ * @brief Contains integration tests for streaming derived implementations.
+ *
+ * @details
+ * Contains integration tests for serialize_to_writer and deserialize_from_reader of derived implementations.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use tampon::{ Tampon, Error, buffer };
+
+// Custom codec writing a u16 as 1 byte
+mod small {
+    pub fn bytes_size(_value: &u16) -> usize {
+        1
+    }
+
+    pub fn serialize(value: &u16, buffer: &mut [u8]) -> usize {
+        buffer[0] = *value as u8;
+        1
+    }
+
+    pub fn deserialize(buffer: &[u8]) -> (u16, usize) {
+        (buffer[0] as u16, 1)
+    }
+
+    pub fn try_serialize(value: &u16, buffer: &mut [u8]) -> Result<usize, tampon::Error> {
+        match buffer.first_mut() {
+            Some(byte) => { *byte = *value as u8; Ok(1) },
+            None => Err(tampon::Error::BufferTooSmall { required: 1, available: 0 }),
+        }
+    }
+
+    pub fn try_deserialize(buffer: &[u8]) -> Result<(u16, usize), tampon::Error> {
+        match buffer.first() {
+            Some(byte) => Ok((*byte as u16, 1)),
+            None => Err(tampon::Error::UnexpectedEnd { offset: 0, needed: 1, available: 0 }),
+        }
+    }
+}
+
+// Entity of a snapshot
+#[derive(Tampon, Debug, PartialEq, Clone)]
+enum Entity {
+    Empty,
+    Unit { id: u32, name: String },
+    Path(Vec<(f32, f32)>),
+}
+
+// Optional parts of a snapshot
+#[derive(Tampon, Debug, PartialEq, Clone)]
+#[tampon(presence_bitmap)]
+struct Parts {
+    title:Option<String>,
+    seed:Option<u64>,
+    #[tampon(varint)]
+    counters:Vec<u64>,
+}
+
+// Snapshot bigger than the internal buffer of streams
+#[derive(Tampon, Debug, PartialEq, Clone)]
+struct Snapshot {
+    tick:u64,
+    heights:Vec<f32>,
+    entities:Vec<Entity>,
+    parts:Parts,
+    #[tampon(skip)]
+    dirty:bool,
+}
+
+// Struct with field serialized with module
+#[derive(Tampon, Debug, PartialEq)]
+struct Compact {
+    id:u32,
+    #[tampon(with = small)]
+    level:u16,
+}
+
+// Snapshot with `len` elements in each collection
+fn snapshot(len : usize) -> Snapshot {
+    Snapshot {
+        tick: len as u64,
+        heights: (0..len).map(|index| index as f32).collect(),
+        entities: (0..len).map(|index| match index % 3 {
+            0 => Entity::Empty,
+            1 => Entity::Unit { id: index as u32, name: format!("unit {}", index) },
+            _ => Entity::Path(vec![(1.0, 2.0); index % 5]),
+        }).collect(),
+        parts: Parts { title: Some("x".repeat(len)), seed: None, counters: (0..len as u64).map(|index| index * 1000).collect() },
+        dirty: false,
+    }
+}
+
+#[test]
+// Derived implementations stream the bytes of buffer!
+fn derive_stream_round_trip() {
+    for len in [0, 10, 20000] {
+        let snapshot = snapshot(len);
+
+        let mut writer:Vec<u8> = Vec::new();
+        assert_eq!(snapshot.serialize_to_writer(&mut writer), Ok(snapshot.bytes_size()));
+        assert_eq!(writer, buffer!((snapshot):Snapshot));
+
+        assert_eq!(Snapshot::deserialize_from_reader(&mut &writer[..]), Ok((snapshot.clone(), writer.len())));
+    }
+}
+
+#[test]
+// Errors of derived implementations are offset from start of stream
+fn derive_stream_errors() {
+    let mut buffer = buffer!((snapshot(10)):Snapshot);
+
+    // Unknown tag of first entity after tick, heights and entities length
+    buffer[8 + 44 + 4] = 9;
+    assert_eq!(Snapshot::deserialize_from_reader(&mut &buffer[..]), Err(Error::UnknownTag { offset: 56, tag: 9 }));
+    assert_eq!(Snapshot::deserialize_from_reader(&mut &buffer[..50]), Err(Error::UnexpectedEnd { offset: 48, needed: 4, available: 2 }));
+
+}
+
+#[test]
+// Fields with module are read with the missing bytes announced by try_deserialize
+fn derive_stream_with() {
+    let compact = Compact { id: 1, level: 2 };
+    let mut writer:Vec<u8> = Vec::new();
+    assert_eq!(compact.serialize_to_writer(&mut writer), Ok(5));
+    assert_eq!(writer, vec![1, 0, 0, 0, 2]);
+
+    // Trailing bytes aren't read
+    writer.push(9);
+    let mut reader = &writer[..];
+    assert_eq!(Compact::deserialize_from_reader(&mut reader), Ok((compact, 5)));
+    assert_eq!(reader, [9]);
+
+    // Reader ending in field is offset from start of stream
+    assert_eq!(Compact::deserialize_from_reader(&mut &writer[..4]), Err(Error::UnexpectedEnd { offset: 4, needed: 1, available: 0 }));
+}
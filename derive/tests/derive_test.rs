@@ -0,0 +1,219 @@
+/*
+ * @file tampon_derive/tests/derive_test.rs
+ *
+ * @module tampon_derive::tests
+ *
+ * @brief Contains integration tests for #[derive(Tampon)].
+ *
+ * @details
+ * Contains integration tests for #[derive(Tampon)].
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use tampon::{ Tampon, Error, Budget, Limits, bytes_size, serialize, deserialize, buffer };
+
+// Named fields struct with every compatible type
+#[derive(Tampon, Debug, PartialEq)]
+struct Named {
+    f1:bool,
+    f2:u8,
+    f3:i128,
+    f4:f64,
+    f5:String,
+    v1:Vec<u16>,
+    v2:Vec<String>,
+    t1:Tuple,
+    t2:Vec<Unit>,
+}
+
+// Tuple struct
+#[derive(Tampon, Debug, PartialEq)]
+struct Tuple(u32, Vec<i8>);
+
+// Unit struct
+#[derive(Tampon, Debug, PartialEq)]
+struct Unit;
+
+// Struct implementing Tampon by hand used to test interoperability
+#[derive(Debug, PartialEq)]
+struct HandWritten {
+    f1:u32,
+    v1:Vec<i8>,
+}
+
+impl Tampon for HandWritten {
+    fn bytes_size(&self) -> usize {
+        bytes_size!((self.f1):u32, [self.v1]:i8)
+    }
+
+    fn serialize(&self, buffer : &mut [u8]) -> usize {
+        serialize!(buffer, to_size, (self.f1):u32, [self.v1]:i8);
+        to_size
+    }
+
+    fn deserialize(buffer : &[u8]) -> (Self, usize) {
+        deserialize!(buffer, from_size, (f1):u32, [v1]:i8);
+        (HandWritten { f1, v1 }, from_size)
+    }
+}
+
+// Generic struct
+#[derive(Tampon, Debug, PartialEq)]
+struct Generic<T> {
+    f1:T,
+    v1:Vec<T>,
+}
+
+// Module used for path-qualified fields
+mod shapes {
+    #[derive(tampon::Tampon, Debug, PartialEq)]
+    pub struct Point(pub i16, pub i16);
+}
+
+// Struct with generic and path-qualified fields
+#[derive(Tampon, Debug, PartialEq)]
+struct Composite {
+    f1:Generic<shapes::Point>,
+    f2:self::shapes::Point,
+    v1:Vec<Generic<Tuple>>,
+    v2:Vec<shapes::Point>,
+}
+
+// Struct with fixed-size array fields
+#[derive(Tampon, Debug, PartialEq)]
+struct Keys {
+    hash:[u8; 4],
+    pairs:Vec<[u16; 2]>,
+}
+
+// Struct with nested containers
+#[derive(Tampon, Debug, PartialEq)]
+struct Frames {
+    samples:Vec<Vec<f32>>,
+    labels:Vec<Vec<String>>,
+}
+
+// Create a Named with values
+fn named() -> Named {
+    Named { f1: true, f2: 255, f3: i128::MIN, f4: 2.5, f5: String::from("Tampon"), v1: vec![1, 2, 3],
+        v2: vec![String::from("a"), String::from("bc")], t1: Tuple(77, vec![-1, 1]), t2: vec![Unit, Unit] }
+}
+
+#[test]
+// Test derived implementation round trip
+fn derive_named_round_trip() {
+    let to = named();
+    let mut buffer = vec![0u8; to.bytes_size()];
+
+    assert_eq!(to.serialize(&mut buffer), buffer.len());
+    assert_eq!(Named::deserialize(&buffer), (to, buffer.len()));
+}
+
+#[test]
+// Test derived implementation use the same wire format as macros
+fn derive_named_wire_format() {
+    let to = named();
+    let mut buffer = vec![0u8; to.bytes_size()];
+    to.serialize(&mut buffer);
+
+    let expected = buffer!((to.f1):bool, (to.f2):u8, (to.f3):i128, (to.f4):f64, (to.f5):String, [to.v1]:u16, [to.v2]:String, (to.t1):Tuple, [to.t2]:Unit);
+    assert_eq!(buffer, expected);
+}
+
+#[test]
+// Test derived tuple and unit structs
+fn derive_tuple_unit() {
+    let to = Tuple(12, vec![i8::MIN, i8::MAX]);
+    assert_eq!(to.bytes_size(), 10);
+
+    let mut buffer = vec![0u8; 10];
+    to.serialize(&mut buffer);
+    assert_eq!(buffer, vec![12, 0, 0, 0, 2, 0, 0, 0, 128, 127]);
+    assert_eq!(Tuple::deserialize(&buffer), (to, 10));
+
+    assert_eq!(Unit.bytes_size(), 0);
+    assert_eq!(Unit::deserialize(&[]), (Unit, 0));
+}
+
+#[test]
+// Test derived and hand-written implementations interoperate
+fn derive_interoperate() {
+    let hand = HandWritten { f1: 12, v1: vec![i8::MIN, i8::MAX] };
+    let mut buffer = vec![0u8; hand.bytes_size()];
+    hand.serialize(&mut buffer);
+
+    assert_eq!(Tuple::deserialize(&buffer), (Tuple(12, vec![i8::MIN, i8::MAX]), 10));
+}
+
+#[test]
+// Test derived generic struct
+fn derive_generic() {
+    let to = Generic { f1: Tuple(1, vec![]), v1: vec![Tuple(2, vec![3])] };
+    let mut buffer = vec![0u8; to.bytes_size()];
+    to.serialize(&mut buffer);
+
+    assert_eq!(Generic::<Tuple>::deserialize(&buffer), (to, buffer.len()));
+}
+
+#[test]
+// Test derived generic and path-qualified fields
+fn derive_generic_fields() {
+    let to = Composite { f1: Generic { f1: shapes::Point(1, 2), v1: vec![] }, f2: shapes::Point(-1, -2),
+        v1: vec![Generic { f1: Tuple(3, vec![4]), v1: vec![] }], v2: vec![shapes::Point(5, 6)] };
+    let mut buffer = vec![0u8; to.bytes_size()];
+    to.serialize(&mut buffer);
+
+    let expected = buffer!((to.f1):Generic<shapes::Point>, (to.f2):shapes::Point, [to.v1]:Generic<Tuple>, [to.v2]:shapes::Point);
+    assert_eq!(buffer, expected);
+    assert_eq!(Composite::try_deserialize(&buffer), Ok((to, buffer.len())));
+}
+
+#[test]
+// Test derived fallible functions
+fn derive_fallible() {
+    let to = named();
+    let mut buffer = vec![0u8; 8];
+    assert_eq!(to.try_serialize(&mut buffer), Err(Error::BufferTooSmall { required: to.bytes_size(), available: 8 }));
+
+    let mut buffer = vec![0u8; to.bytes_size()];
+    assert_eq!(to.try_serialize(&mut buffer), Ok(buffer.len()));
+    assert_eq!(Named::try_deserialize(&buffer[0..buffer.len() - 1]), Err(Error::UnexpectedEnd { offset: buffer.len() - 4, needed: 4, available: 3 }));
+
+    // Limits are passed to nested structs
+    assert_eq!(Named::try_deserialize_with(&buffer, &Limits::new(2, 16, 1024), &mut Budget::new()), Err(Error::LimitExceeded { offset: 36, length: 3, limit: 2 }));
+    assert_eq!(Named::try_deserialize(&buffer), Ok((to, buffer.len())));
+}
+
+#[test]
+// Test derived fixed-size array fields are written without length prefix
+fn derive_array() {
+    let to = Keys { hash: [1, 2, 3, 4], pairs: vec![[5, 6]] };
+    let mut buffer = vec![0u8; to.bytes_size()];
+    to.serialize(&mut buffer);
+
+    assert_eq!(buffer, vec![1, 2, 3, 4, 1, 0, 0, 0, 5, 0, 6, 0]);
+    assert_eq!(Keys::try_deserialize(&buffer), Ok((to, buffer.len())));
+}
+
+#[test]
+// Test derived nested container fields
+fn derive_nested() {
+    let to = Frames { samples: vec![vec![1.0, 2.0], vec![]], labels: vec![vec![String::from("a")]] };
+    let mut buffer = vec![0u8; to.bytes_size()];
+    to.serialize(&mut buffer);
+
+    let expected = buffer!([to.samples]:Vec<f32>, [to.labels]:Vec<String>);
+    assert_eq!(buffer, expected);
+    assert_eq!(Frames::try_deserialize(&buffer), Ok((to, buffer.len())));
+}
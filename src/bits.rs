@@ -0,0 +1,410 @@
+/*
+ * @file tampon/bits.rs
+ *
+ * @module tampon
+ *
+ * @brief Contain BitWriter and BitReader used to write and read fields smaller than a byte.
+ *
+ * @details
+ * Contain BitWriter and BitReader used to write and read fields smaller than a byte, like 3-bit opcodes,
+ * 12-bit identifiers or 1-bit flags. Bits are written most significant bit first or least significant bit
+ * first according to BitOrder.
+ *
+ * Once aligned to a byte boundary, the bytes count returned can be used to continue with the byte
+ * oriented macros, so implementors of Tampon can mix bit fields with other fields.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ * https://en.wikipedia.org/wiki/Bit_numbering
+ *
+ * @todo
+ */
+
+use crate::Error;
+
+/// Maximum width in bits of a value.
+const MAX_WIDTH : u32 = u64::BITS;
+
+/// ##### Order of bits written and read in each byte by [`BitWriter`] and [`BitReader`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BitOrder {
+    /// Bits are written from the most significant bit of each byte, and values from their most significant bit.
+    /// Used by most network protocols.
+    #[default]
+    MsbFirst,
+
+    /// Bits are written from the least significant bit of each byte, and values from their least significant bit.
+    /// Used by formats like [`DEFLATE`](https://en.wikipedia.org/wiki/Deflate).
+    LsbFirst,
+}
+
+/**********
+* HELPERS *
+**********/
+/// Mask of the `width` lowest bits.
+#[inline]
+fn mask(width : u32) -> u64 {
+    if width >= MAX_WIDTH { u64::MAX } else { (1 << width) - 1 }
+}
+
+/// Verify `width` is at most 64 bits.
+#[inline]
+fn check_width(width : u32, offset : usize) -> Result<(), Error> {
+    if width > MAX_WIDTH {
+        return Err(Error::InvalidValue { offset, reason: format!("bit width {} is bigger than 64", width) });
+    }
+    Ok(())
+}
+
+/// Verify signed `value` fits in `width` bits as two's complement.
+#[inline]
+fn check_signed(value : i64, width : u32, offset : usize) -> Result<(), Error> {
+    let fits = match width {
+        0 => value == 0,
+        MAX_WIDTH => true,
+        _ => value >= -(1 << (width - 1)) && value < (1 << (width - 1)),
+    };
+
+    if !fits {
+        return Err(Error::OutOfRange { offset, value: value as i128 });
+    }
+    Ok(())
+}
+
+/// Extend sign of `value` of `width` bits.
+#[inline]
+fn sign_extend(value : u64, width : u32) -> i64 {
+    if width == 0 {
+        return 0;
+    }
+    let shift = MAX_WIDTH - width;
+    ((value << shift) as i64) >> shift
+}
+
+/*************
+* BIT WRITER *
+*************/
+/// ##### Writer of values of any width from 0 to 64 bits into a buffer.
+///
+/// Values are written one after the other without padding, in the [`BitOrder`] given. Written bits replace the bits
+/// of buffer, so it doesn't need to be wiped. [`align`](BitWriter::align) pads the last byte with 0 bits and returns
+/// the bytes count written, so the byte oriented macros can continue after bit fields.
+///
+/// # Example(s)
+/// ```
+/// use tampon::{ Tampon, BitWriter, BitReader, BitOrder, bytes_size, serialize, deserialize };
+///
+/// // Header with a 3-bit opcode, a 12-bit id and a 1-bit flag followed by a name
+/// #[derive(Debug, PartialEq)]
+/// struct Header {
+///     opcode : u8,
+///     id : u16,
+///     urgent : bool,
+///     name : String,
+/// }
+///
+/// impl Tampon for Header {
+///     fn bytes_size(&self) -> usize {
+///         (3 + 12 + 1) / 8 + bytes_size!((self.name):String)
+///     }
+///
+///     fn serialize(&self, buffer : &mut [u8]) -> usize {
+///         let mut writer = BitWriter::new(buffer, BitOrder::MsbFirst);
+///         writer.write_bits(self.opcode as u64, 3);
+///         writer.write_bits(self.id as u64, 12);
+///         writer.write_bool(self.urgent);
+///         let bits_size = writer.align();
+///
+///         let rest = &mut buffer[bits_size..];
+///         serialize!(rest, bytes_copied, (self.name):String);
+///         bits_size + bytes_copied
+///     }
+///
+///     fn deserialize(buffer : &[u8]) -> (Self, usize) {
+///         let mut reader = BitReader::new(buffer, BitOrder::MsbFirst);
+///         let opcode = reader.read_bits(3) as u8;
+///         let id = reader.read_bits(12) as u16;
+///         let urgent = reader.read_bool();
+///         let bits_size = reader.align();
+///
+///         deserialize!(buffer[bits_size..], bytes_read, (name):String);
+///         (Header { opcode, id, urgent, name }, bits_size + bytes_read)
+///     }
+/// }
+///
+/// let header = Header { opcode: 5, id: 0xABC, urgent: true, name: String::from("a") };
+/// let mut buffer = vec![0u8; header.bytes_size()];
+/// assert_eq!(header.serialize(&mut buffer), 7);
+/// assert_eq!(buffer, vec![0b1011_0101, 0b0111_1001, 1, 0, 0, 0, b'a']);
+/// assert_eq!(Header::deserialize(&buffer), (header, 7));
+/// ```
+pub struct BitWriter<'a> {
+    /// Buffer written into.
+    buffer : &'a mut [u8],
+
+    /// Order of bits in bytes.
+    order : BitOrder,
+
+    /// Position in bits of next value.
+    position : usize,
+}
+
+impl<'a> BitWriter<'a> {
+    /// Create a writer starting at the first bit of `buffer`.
+    pub fn new(buffer : &'a mut [u8], order : BitOrder) -> BitWriter<'a> {
+        BitWriter { buffer, order, position: 0 }
+    }
+
+    /// Position in bits of the next value written.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Count of bytes written, including the last byte partially written.
+    #[inline]
+    pub fn bytes_written(&self) -> usize {
+        self.position.div_ceil(8)
+    }
+
+    /// Write the `width` lowest bits of `value`.
+    ///
+    /// # Panic(s)
+    /// * Will panic! if `width` is bigger than 64, if `value` doesn't fit in `width` bits or if buffer is too small.
+    pub fn write_bits(&mut self, value : u64, width : u32) {
+        if let Err(err) = self.try_write_bits(value, width) {
+            panic!("{}", err);
+        }
+    }
+
+    /// Write the `width` lowest bits of `value` without panicking. Nothing is written on error.
+    ///
+    /// # Error(s)
+    /// * [`Error::InvalidValue`] if `width` is bigger than 64.
+    /// * [`Error::OutOfRange`] if `value` doesn't fit in `width` bits.
+    /// * [`Error::BufferTooSmall`] if buffer ends before the last bit of `value`.
+    pub fn try_write_bits(&mut self, value : u64, width : u32) -> Result<(), Error> {
+        let offset = self.position / 8;
+        check_width(width, offset)?;
+        if value & !mask(width) != 0 {
+            return Err(Error::OutOfRange { offset, value: value as i128 });
+        }
+
+        let required = (self.position + width as usize).div_ceil(8);
+        if required > self.buffer.len() {
+            return Err(Error::BufferTooSmall { required, available: self.buffer.len() });
+        }
+
+        // Write the bits fitting in the current byte until value is written
+        let mut remaining = width;
+        while remaining > 0 {
+            let bit = (self.position % 8) as u32;
+            let count = remaining.min(8 - bit);
+            let (chunk, shift) = match self.order {
+                BitOrder::MsbFirst => ((value >> (remaining - count)) & mask(count), 8 - bit - count),
+                BitOrder::LsbFirst => ((value >> (width - remaining)) & mask(count), bit),
+            };
+
+            let byte = &mut self.buffer[self.position / 8];
+            *byte = (*byte & !((mask(count) as u8) << shift)) | ((chunk as u8) << shift);
+
+            self.position += count as usize;
+            remaining -= count;
+        }
+        Ok(())
+    }
+
+    /// Write signed `value` as two's complement of `width` bits.
+    ///
+    /// # Panic(s)
+    /// * Will panic! if `width` is bigger than 64, if `value` doesn't fit in `width` bits or if buffer is too small.
+    pub fn write_signed(&mut self, value : i64, width : u32) {
+        if let Err(err) = self.try_write_signed(value, width) {
+            panic!("{}", err);
+        }
+    }
+
+    /// Write signed `value` as two's complement of `width` bits without panicking. Nothing is written on error.
+    ///
+    /// # Error(s)
+    /// * [`Error::InvalidValue`] if `width` is bigger than 64.
+    /// * [`Error::OutOfRange`] if `value` doesn't fit in `width` bits.
+    /// * [`Error::BufferTooSmall`] if buffer ends before the last bit of `value`.
+    pub fn try_write_signed(&mut self, value : i64, width : u32) -> Result<(), Error> {
+        check_width(width, self.position / 8)?;
+        check_signed(value, width, self.position / 8)?;
+        self.try_write_bits(value as u64 & mask(width), width)
+    }
+
+    /// Write `value` as a single bit.
+    ///
+    /// # Panic(s)
+    /// * Will panic! if buffer is full.
+    #[inline]
+    pub fn write_bool(&mut self, value : bool) {
+        self.write_bits(value as u64, 1)
+    }
+
+    /// Write `value` as a single bit without panicking.
+    ///
+    /// # Error(s)
+    /// * [`Error::BufferTooSmall`] if buffer is full.
+    #[inline]
+    pub fn try_write_bool(&mut self, value : bool) -> Result<(), Error> {
+        self.try_write_bits(value as u64, 1)
+    }
+
+    /// Pad the last byte written with 0 bits and return the bytes count written.
+    pub fn align(&mut self) -> usize {
+        let padding = (8 - self.position % 8) % 8;
+        if padding > 0 {
+            // Padding always fit in the last byte
+            self.write_bits(0, padding as u32);
+        }
+        self.bytes_written()
+    }
+}
+
+/*************
+* BIT READER *
+*************/
+/// ##### Reader of values of any width from 0 to 64 bits from a buffer.
+///
+/// Values are read one after the other in the [`BitOrder`] they were written with. [`align`](BitReader::align) skips the
+/// padding bits of the last byte and returns the bytes count read, so the byte oriented macros can continue after bit
+/// fields. See [`BitWriter`] for an example mixing bit fields and macros.
+///
+/// # Example(s)
+/// ```
+/// use tampon::{ BitReader, BitOrder };
+///
+/// let buffer = [0b1011_0101, 0b0111_1001];
+///
+/// let mut reader = BitReader::new(&buffer, BitOrder::MsbFirst);
+/// assert_eq!(reader.read_bits(3), 5);
+/// assert_eq!(reader.read_signed(4), -6);
+/// assert_eq!(reader.read_bits(9), 0b1_0111_1001);
+/// assert!(reader.try_read_bool().is_err());
+/// ```
+pub struct BitReader<'a> {
+    /// Buffer read from.
+    buffer : &'a [u8],
+
+    /// Order of bits in bytes.
+    order : BitOrder,
+
+    /// Position in bits of next value.
+    position : usize,
+}
+
+impl<'a> BitReader<'a> {
+    /// Create a reader starting at the first bit of `buffer`.
+    pub fn new(buffer : &'a [u8], order : BitOrder) -> BitReader<'a> {
+        BitReader { buffer, order, position: 0 }
+    }
+
+    /// Position in bits of the next value read.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Count of bytes read, including the last byte partially read.
+    #[inline]
+    pub fn bytes_read(&self) -> usize {
+        self.position.div_ceil(8)
+    }
+
+    /// Read an unsigned value of `width` bits.
+    ///
+    /// # Panic(s)
+    /// * Will panic! if `width` is bigger than 64 or if buffer ends before the last bit of value.
+    pub fn read_bits(&mut self, width : u32) -> u64 {
+        match self.try_read_bits(width) {
+            Ok(value) => value,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Read an unsigned value of `width` bits without panicking. Nothing is read on error.
+    ///
+    /// # Error(s)
+    /// * [`Error::InvalidValue`] if `width` is bigger than 64.
+    /// * [`Error::UnexpectedEnd`] if buffer ends before the last bit of value.
+    pub fn try_read_bits(&mut self, width : u32) -> Result<u64, Error> {
+        let offset = self.position / 8;
+        check_width(width, offset)?;
+
+        let needed = (self.position % 8 + width as usize).div_ceil(8);
+        let available = self.buffer.len() - offset;
+        if needed > available {
+            return Err(Error::UnexpectedEnd { offset, needed, available });
+        }
+
+        // Read the bits of the current byte until value is read
+        let mut value : u64 = 0;
+        let mut remaining = width;
+        while remaining > 0 {
+            let bit = (self.position % 8) as u32;
+            let count = remaining.min(8 - bit);
+            let byte = self.buffer[self.position / 8] as u64;
+
+            value = match self.order {
+                BitOrder::MsbFirst => (value << count) | ((byte >> (8 - bit - count)) & mask(count)),
+                BitOrder::LsbFirst => value | (((byte >> bit) & mask(count)) << (width - remaining)),
+            };
+
+            self.position += count as usize;
+            remaining -= count;
+        }
+        Ok(value)
+    }
+
+    /// Read a signed value written as two's complement of `width` bits.
+    ///
+    /// # Panic(s)
+    /// * Will panic! if `width` is bigger than 64 or if buffer ends before the last bit of value.
+    pub fn read_signed(&mut self, width : u32) -> i64 {
+        sign_extend(self.read_bits(width), width)
+    }
+
+    /// Read a signed value written as two's complement of `width` bits without panicking.
+    ///
+    /// # Error(s)
+    /// * [`Error::InvalidValue`] if `width` is bigger than 64.
+    /// * [`Error::UnexpectedEnd`] if buffer ends before the last bit of value.
+    pub fn try_read_signed(&mut self, width : u32) -> Result<i64, Error> {
+        Ok(sign_extend(self.try_read_bits(width)?, width))
+    }
+
+    /// Read a single bit as [`bool`].
+    ///
+    /// # Panic(s)
+    /// * Will panic! if buffer has no bit left.
+    #[inline]
+    pub fn read_bool(&mut self) -> bool {
+        self.read_bits(1) != 0
+    }
+
+    /// Read a single bit as [`bool`] without panicking.
+    ///
+    /// # Error(s)
+    /// * [`Error::UnexpectedEnd`] if buffer has no bit left.
+    #[inline]
+    pub fn try_read_bool(&mut self) -> Result<bool, Error> {
+        Ok(self.try_read_bits(1)? != 0)
+    }
+
+    /// Skip the padding bits of the last byte read and return the bytes count read.
+    pub fn align(&mut self) -> usize {
+        self.position = self.bytes_read() * 8;
+        self.position / 8
+    }
+}
@@ -0,0 +1,351 @@
+/*
+ * @file tampon/borrow.rs
+ *
+ * @module tampon
+ *
+ * @brief Contain TamponBorrow trait used to deserialize values borrowed from buffer.
+ *
+ * @details
+ * Contain TamponBorrow trait used to deserialize values borrowed from buffer without copy. Macros
+ * deserialize! and try_deserialize! dispatch types through TamponBorrow, which is implemented for
+ * every implementor of Tampon.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use std::borrow::Cow;
+
+use crate::{ Tampon, Error, Limits, Budget, LengthPrefix, SLICE_SIZE_IN_BYTES };
+use crate::tampon::slice_capacity;
+
+/// ##### Trait used to deserialize values borrowed from a buffer of lifetime `'a` without copy.
+/// 
+/// [`&str`](str), `&[u8]`, [`Cow<str>`](Cow) and [`Cow<[u8]>`](Cow) are read with the same wire format as [`String`]
+/// and `[u8]` but point into the buffer instead of allocating. Implemented for every implementor of
+/// [`Tampon`](trait.Tampon.html), so borrowed and owned values can be mixed in [`deserialize!`] and [`try_deserialize!`].
+/// 
+/// Borrowed values are serialized as [`str`] and `[u8]`.
+/// 
+/// # Example(s)
+/// ```
+/// use std::borrow::Cow;
+/// use tampon::{ buffer, deserialize, try_deserialize, Error };
+/// 
+/// // Packet borrowing its name and payload from buffer
+/// struct Packet<'a> {
+///     id:u32,
+///     name:&'a str,
+///     payload:Cow<'a, [u8]>,
+/// }
+/// 
+/// fn inspect(buffer : &[u8]) -> Result<Packet<'_>, Error> {
+///     try_deserialize!(buffer, (id):u32, (name):&str, (payload):Cow<[u8]>);
+///     Ok(Packet { id, name, payload })
+/// }
+/// 
+/// let name = "large";
+/// let payload = vec![7u8; 1024];
+/// let buffer = buffer!((12):u32, (name):str, (payload):[u8]);
+/// 
+/// let packet = inspect(&buffer).unwrap();
+/// assert_eq!(packet.id, 12);
+/// assert_eq!(packet.name, "large");
+/// assert!(matches!(packet.payload, Cow::Borrowed(_)));
+/// 
+/// // Borrowed values can be used in deserialize! too
+/// deserialize!(buffer, (_id):u32, (name):&str);
+/// assert_eq!(name.as_ptr(), buffer[8..].as_ptr());
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be deserialized by tampon",
+    label = "`{Self}` doesn't implement trait `Tampon` or `TamponBorrow`",
+    note = "compatible types are implementors of trait `Tampon` and borrowed types &str, &[u8], Cow<str> and Cow<[u8]>"
+)]
+pub trait TamponBorrow<'a> : Sized {
+    /// Deserialize object borrowing from buffer, returning it with the count of bytes read.
+    /// 
+    /// # Panic(s)
+    /// * Will panic! if `buffer` is too small or contains invalid data.
+    fn deserialize_borrowed(buffer : &'a [u8]) -> (Self, usize);
+
+    /// Deserialize object borrowing from buffer without panicking.
+    /// 
+    /// Calls [`TamponBorrow::try_deserialize_borrowed_with`] with default [`Limits`](struct.Limits.html) and a new [`Budget`](struct.Budget.html).
+    fn try_deserialize_borrowed(buffer : &'a [u8]) -> Result<(Self, usize), Error> {
+        Self::try_deserialize_borrowed_with(buffer, &Limits::default(), &mut Budget::new())
+    }
+
+    /// Deserialize object borrowing from buffer without panicking while enforcing `limits`, counting allocations in `budget`.
+    fn try_deserialize_borrowed_with(buffer : &'a [u8], limits : &Limits, budget : &mut Budget) -> Result<(Self, usize), Error>;
+
+    /// Hidden function returning the minimum bytes an element takes in buffer. Not meant to be used directly.
+    #[doc(hidden)]
+    fn min_bytes_size() -> usize {
+        0
+    }
+
+    /// Hidden function returning the minimum bytes an element takes in buffer with strings and slices written
+    /// after a length prefix encoded as `prefix`. Not meant to be used directly.
+    #[doc(hidden)]
+    fn min_bytes_size_prefixed(prefix : LengthPrefix) -> usize {
+        let _ = prefix;
+        Self::min_bytes_size()
+    }
+
+    /// Hidden function deserializing object with strings and slices written after a length prefix encoded as `prefix`.
+    /// Not meant to be used directly.
+    #[doc(hidden)]
+    fn deserialize_borrowed_prefixed(buffer : &'a [u8], prefix : LengthPrefix) -> (Self, usize) {
+        let _ = prefix;
+        Self::deserialize_borrowed(buffer)
+    }
+
+    /// Hidden function deserializing object with strings and slices written after a length prefix encoded as `prefix`
+    /// without panicking. Not meant to be used directly.
+    #[doc(hidden)]
+    fn try_deserialize_borrowed_prefixed(buffer : &'a [u8], limits : &Limits, budget : &mut Budget, prefix : LengthPrefix) -> Result<(Self, usize), Error> {
+        let _ = prefix;
+        Self::try_deserialize_borrowed_with(buffer, limits, budget)
+    }
+
+    /// Hidden function reading `len` elements written one after the other into a vector allocated once.
+    /// Not meant to be used directly.
+    #[doc(hidden)]
+    fn deserialize_borrowed_slice(buffer : &'a [u8], len : usize, prefix : LengthPrefix) -> (Vec<Self>, usize) {
+        let mut vec = Vec::with_capacity(slice_capacity(len, Self::min_bytes_size_prefixed(prefix), buffer.len()));
+        let mut bytes_read = 0;
+        for _ in 0..len {
+            let (elem, size) = Self::deserialize_borrowed_prefixed(&buffer[bytes_read..], prefix);
+            vec.push(elem);
+            bytes_read += size;
+        }
+        (vec, bytes_read)
+    }
+
+    /// Hidden function reading `len` elements written one after the other without panicking.
+    /// Not meant to be used directly.
+    #[doc(hidden)]
+    fn try_deserialize_borrowed_slice(buffer : &'a [u8], len : usize, limits : &Limits, budget : &mut Budget, prefix : LengthPrefix) -> Result<(Vec<Self>, usize), Error> {
+        // Error will be returned as soon as buffer ends.
+        let mut vec = Vec::with_capacity(slice_capacity(len, Self::min_bytes_size_prefixed(prefix), buffer.len()));
+        let mut bytes_read = 0;
+        for _ in 0..len {
+            let offset = bytes_read;
+            let (elem, size) = Self::try_deserialize_borrowed_prefixed(&buffer[offset..], limits, budget, prefix).map_err(|err| err.offset_by(offset))?;
+            vec.push(elem);
+            bytes_read += size;
+        }
+        Ok((vec, bytes_read))
+    }
+}
+
+/// Owned implementors don't borrow from buffer.
+impl<'a, T: Tampon> TamponBorrow<'a> for T {
+    #[inline]
+    fn deserialize_borrowed(buffer : &'a [u8]) -> (Self, usize) {
+        T::deserialize(buffer)
+    }
+
+    #[inline]
+    fn try_deserialize_borrowed_with(buffer : &'a [u8], limits : &Limits, budget : &mut Budget) -> Result<(Self, usize), Error> {
+        T::try_deserialize_with(buffer, limits, budget)
+    }
+
+    #[inline]
+    fn min_bytes_size() -> usize {
+        <T as Tampon>::min_bytes_size()
+    }
+
+    #[inline]
+    fn min_bytes_size_prefixed(prefix : LengthPrefix) -> usize {
+        <T as Tampon>::min_bytes_size_prefixed(prefix)
+    }
+
+    #[inline]
+    fn deserialize_borrowed_prefixed(buffer : &'a [u8], prefix : LengthPrefix) -> (Self, usize) {
+        T::deserialize_prefixed(buffer, prefix)
+    }
+
+    #[inline]
+    fn try_deserialize_borrowed_prefixed(buffer : &'a [u8], limits : &Limits, budget : &mut Budget, prefix : LengthPrefix) -> Result<(Self, usize), Error> {
+        T::try_deserialize_prefixed(buffer, limits, budget, prefix)
+    }
+
+    #[inline]
+    fn deserialize_borrowed_slice(buffer : &'a [u8], len : usize, prefix : LengthPrefix) -> (Vec<Self>, usize) {
+        T::deserialize_slice(buffer, len, prefix)
+    }
+
+    #[inline]
+    fn try_deserialize_borrowed_slice(buffer : &'a [u8], len : usize, limits : &Limits, budget : &mut Budget, prefix : LengthPrefix) -> Result<(Vec<Self>, usize), Error> {
+        T::try_deserialize_slice(buffer, len, limits, budget, prefix)
+    }
+}
+
+/*******
+* &STR *
+*******/
+/// &str is read like [`String`].
+impl<'a> TamponBorrow<'a> for &'a str {
+    #[inline]
+    fn deserialize_borrowed(buffer : &'a [u8]) -> (Self, usize) {
+        Self::deserialize_borrowed_prefixed(buffer, LengthPrefix::default())
+    }
+
+    #[inline]
+    fn try_deserialize_borrowed_with(buffer : &'a [u8], limits : &Limits, budget : &mut Budget) -> Result<(Self, usize), Error> {
+        Self::try_deserialize_borrowed_prefixed(buffer, limits, budget, LengthPrefix::default())
+    }
+
+    #[inline]
+    fn min_bytes_size() -> usize {
+        SLICE_SIZE_IN_BYTES
+    }
+
+    #[inline]
+    fn min_bytes_size_prefixed(prefix : LengthPrefix) -> usize {
+        prefix.min_size()
+    }
+
+    fn deserialize_borrowed_prefixed(buffer : &'a [u8], prefix : LengthPrefix) -> (Self, usize) {
+        let (bytes, bytes_read) = <&[u8]>::deserialize_borrowed_prefixed(buffer, prefix);
+        (core::str::from_utf8(bytes).expect("UTF8 String incorrect!"), bytes_read)
+    }
+
+    fn try_deserialize_borrowed_prefixed(buffer : &'a [u8], limits : &Limits, _budget : &mut Budget, prefix : LengthPrefix) -> Result<(Self, usize), Error> {
+        // Get size of string to retrieve
+        let (string_size, size) = prefix.try_deserialize(buffer)?;
+
+        // Validate length prefix against limits and remaining bytes of buffer, nothing is allocated
+        limits.check_str(0, string_size, buffer.len() - size)?;
+
+        match core::str::from_utf8(&buffer[size..size + string_size]) {
+            Ok(string) => Ok((string, size + string_size)),
+            Err(err) => Err(Error::InvalidUtf8 { offset: size + err.valid_up_to() }),
+        }
+    }
+}
+
+/********
+* &[U8] *
+********/
+/// &[u8] is read like `[v]:u8`.
+impl<'a> TamponBorrow<'a> for &'a [u8] {
+    #[inline]
+    fn deserialize_borrowed(buffer : &'a [u8]) -> (Self, usize) {
+        Self::deserialize_borrowed_prefixed(buffer, LengthPrefix::default())
+    }
+
+    #[inline]
+    fn try_deserialize_borrowed_with(buffer : &'a [u8], limits : &Limits, budget : &mut Budget) -> Result<(Self, usize), Error> {
+        Self::try_deserialize_borrowed_prefixed(buffer, limits, budget, LengthPrefix::default())
+    }
+
+    #[inline]
+    fn min_bytes_size() -> usize {
+        SLICE_SIZE_IN_BYTES
+    }
+
+    #[inline]
+    fn min_bytes_size_prefixed(prefix : LengthPrefix) -> usize {
+        prefix.min_size()
+    }
+
+    fn deserialize_borrowed_prefixed(buffer : &'a [u8], prefix : LengthPrefix) -> (Self, usize) {
+        // Get size of slice
+        let (slice_size, size) = prefix.deserialize(buffer);
+        (&buffer[size..size + slice_size], size + slice_size)
+    }
+
+    fn try_deserialize_borrowed_prefixed(buffer : &'a [u8], limits : &Limits, budget : &mut Budget, prefix : LengthPrefix) -> Result<(Self, usize), Error> {
+        let (slice_size, size) = prefix.try_deserialize(buffer)?;
+
+        // Nothing is allocated, only the length is verified
+        limits.check_slice(0, slice_size, core::mem::size_of::<u8>(), 0, buffer.len() - size, budget)?;
+
+        Ok((&buffer[size..size + slice_size], size + slice_size))
+    }
+}
+
+/******
+* COW *
+******/
+/// Cow<str> is read like [`String`] and borrowed from buffer.
+impl<'a> TamponBorrow<'a> for Cow<'a, str> {
+    #[inline]
+    fn deserialize_borrowed(buffer : &'a [u8]) -> (Self, usize) {
+        Self::deserialize_borrowed_prefixed(buffer, LengthPrefix::default())
+    }
+
+    #[inline]
+    fn try_deserialize_borrowed_with(buffer : &'a [u8], limits : &Limits, budget : &mut Budget) -> Result<(Self, usize), Error> {
+        Self::try_deserialize_borrowed_prefixed(buffer, limits, budget, LengthPrefix::default())
+    }
+
+    #[inline]
+    fn min_bytes_size() -> usize {
+        SLICE_SIZE_IN_BYTES
+    }
+
+    #[inline]
+    fn min_bytes_size_prefixed(prefix : LengthPrefix) -> usize {
+        prefix.min_size()
+    }
+
+    #[inline]
+    fn deserialize_borrowed_prefixed(buffer : &'a [u8], prefix : LengthPrefix) -> (Self, usize) {
+        let (string, bytes_read) = <&str>::deserialize_borrowed_prefixed(buffer, prefix);
+        (Cow::Borrowed(string), bytes_read)
+    }
+
+    #[inline]
+    fn try_deserialize_borrowed_prefixed(buffer : &'a [u8], limits : &Limits, budget : &mut Budget, prefix : LengthPrefix) -> Result<(Self, usize), Error> {
+        let (string, bytes_read) = <&str>::try_deserialize_borrowed_prefixed(buffer, limits, budget, prefix)?;
+        Ok((Cow::Borrowed(string), bytes_read))
+    }
+}
+
+/// Cow<[u8]> is read like `[v]:u8` and borrowed from buffer.
+impl<'a> TamponBorrow<'a> for Cow<'a, [u8]> {
+    #[inline]
+    fn deserialize_borrowed(buffer : &'a [u8]) -> (Self, usize) {
+        Self::deserialize_borrowed_prefixed(buffer, LengthPrefix::default())
+    }
+
+    #[inline]
+    fn try_deserialize_borrowed_with(buffer : &'a [u8], limits : &Limits, budget : &mut Budget) -> Result<(Self, usize), Error> {
+        Self::try_deserialize_borrowed_prefixed(buffer, limits, budget, LengthPrefix::default())
+    }
+
+    #[inline]
+    fn min_bytes_size() -> usize {
+        SLICE_SIZE_IN_BYTES
+    }
+
+    #[inline]
+    fn min_bytes_size_prefixed(prefix : LengthPrefix) -> usize {
+        prefix.min_size()
+    }
+
+    #[inline]
+    fn deserialize_borrowed_prefixed(buffer : &'a [u8], prefix : LengthPrefix) -> (Self, usize) {
+        let (bytes, bytes_read) = <&[u8]>::deserialize_borrowed_prefixed(buffer, prefix);
+        (Cow::Borrowed(bytes), bytes_read)
+    }
+
+    #[inline]
+    fn try_deserialize_borrowed_prefixed(buffer : &'a [u8], limits : &Limits, budget : &mut Budget, prefix : LengthPrefix) -> Result<(Self, usize), Error> {
+        let (bytes, bytes_read) = <&[u8]>::try_deserialize_borrowed_prefixed(buffer, limits, budget, prefix)?;
+        Ok((Cow::Borrowed(bytes), bytes_read))
+    }
+}
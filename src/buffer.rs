@@ -24,7 +24,7 @@
 /// ##### Variadic macro used to create a [`buffer`](https://en.wikipedia.org/wiki/Data_buffer) and [`serialize`](https://en.wikipedia.org/wiki/Serialization) [`compatible variables`](macro.buffer.html#compatible-variabless). 
 /// 
 /// # Description
-/// Variadic macro used to create a [`buffer`](https://en.wikipedia.org/wiki/Data_buffer) and [`serialize`](https://en.wikipedia.org/wiki/Serialization) [`bool`], [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html) (except usize, isize), [`String`] and implementors of trait [`Tampon`](trait.Tampon.html).
+/// Variadic macro used to create a [`buffer`](https://en.wikipedia.org/wiki/Data_buffer) and [`serialize`](https://en.wikipedia.org/wiki/Serialization) [`bool`], [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html), [`String`] and implementors of trait [`Tampon`](trait.Tampon.html).
 /// Also work with [`slice`] by using brackets `[]` instead of parenthesis `()`.
 /// 
 /// # Usage
@@ -58,18 +58,33 @@
 /// 
 /// # Compatible variables(s)
 /// * [`bool`]
-/// * All [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html) except [`usize`] and [`isize`]
+/// * [`char`] written as its 4 bytes [`Unicode scalar value`](https://www.unicode.org/glossary/#unicode_scalar_value)
+/// * All [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html), [`usize`] and [`isize`] are written as [`u64`] and [`i64`]
 /// * [`String`] 
 /// * Implementors of trait [`Tampon`](trait.Tampon.html)
-/// * [`slice`] of the above types
+/// * Type aliases, generic and path-qualified types of the above like `type UserId = u32`, `Wrapper<u32>` or `std::string::String`
+/// * [`Option`] of the above types written as a presence byte followed by the value when [`Some`]
+/// * [`tuple`] of up to 12 of the above types written as the concatenation of their members
+/// * [`array`] `[T; N]` of the above types written as its `N` elements without length prefix
+/// * [`slice`] of the above types, including nested containers like `[v]:Vec<f32>` for a `Vec<Vec<f32>>`
+/// * [`str`] and `[T]` written like [`String`] and slices, to serialize borrowed values
+/// * [`VecDeque`](std::collections::VecDeque), [`HashSet`](std::collections::HashSet), [`BTreeSet`](std::collections::BTreeSet), [`HashMap`](std::collections::HashMap) and [`BTreeMap`](std::collections::BTreeMap) of the above types written like slices, maps write each key followed by its value
+/// * Integers of fields typed [`Varint<T>`](struct.Varint.html) or its aliases like [`varu64`](type.varu64.html) are written as variable length [`LEB128`](https://en.wikipedia.org/wiki/LEB128), signed integers with [`ZigZag`](https://protobuf.dev/programming-guides/encoding/#signed-ints)
+/// * Slices of [`bool`] of fields typed [`Packed<bool>`](struct.Packed.html) are written with 8 booleans per byte after the length prefix
 /// 
 /// # Endianness
 /// * [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html) bytes are written as [`little endian`](https://en.wikipedia.org/wiki/Endianness).
+/// * [`usize`] and [`isize`] are always written as 8 bytes [`u64`] and [`i64`], whatever the target pointer width.
+/// * Fields typed [`BigEndian<T>`](struct.BigEndian.html) or its aliases like [`u16be`](type.u16be.html) are written as [`big endian`](https://en.wikipedia.org/wiki/Endianness), including the length prefix of strings and slices.
+///
+/// # Length prefix
+/// * Length of [`String`] and slices is written as a [`u32`] prefix by default.
+/// * Fields typed [`Prefixed<P, T>`](struct.Prefixed.html) write the length prefix of `T` and of its nested strings and slices as `P`, one of [`u8`], [`u16`], [`u32`], [`u64`] or [`Leb128`](struct.Leb128.html).
 #[macro_export]
 macro_rules! buffer {
 
     // Expression without tail
-    (($expr:expr $(,$extra:expr)*):$type:ident) => {{
+    (($expr:expr $(,$extra:expr)*):$type:ty) => {{
         // Get size needed for variable serialization
         let buffer_size = $crate::bytes_size!(($expr $(,$extra)*):$type);
 
@@ -84,7 +99,7 @@ macro_rules! buffer {
     } as Vec<u8> };
 
     // Expression with tail
-    (($expr:expr $(,$extra:expr)*):$type:ident, $($tail:tt)*) => {{
+    (($expr:expr $(,$extra:expr)*):$type:ty, $($tail:tt)*) => {{
         let buffer_size = $crate::bytes_size!(($expr $(,$extra)*):$type, $($tail)*);
         let mut buffer:Vec<u8> = vec![0;buffer_size];
         $crate::serialize!(buffer, ($expr $(,$extra)*):$type, $($tail)*);
@@ -92,7 +107,7 @@ macro_rules! buffer {
     } as Vec<u8> };
 
     // Slice without tail
-    ([$expr:expr $(,$extra:expr)*]:$type:ident) => { {
+    ([$expr:expr $(,$extra:expr)*]:$type:ty) => { {
         let buffer_size = $crate::bytes_size!([$expr $(,$extra)*]:$type);
         let mut buffer:Vec<u8> = vec![0;buffer_size];
         $crate::serialize!(buffer, [$expr $(,$extra)*]:$type);
@@ -100,10 +115,11 @@ macro_rules! buffer {
     } as Vec<u8> };
 
     // Slice with tail
-    ([$expr:expr $(,$extra:expr)*]:$type:ident, $($tail:tt)*) => {{
+    ([$expr:expr $(,$extra:expr)*]:$type:ty, $($tail:tt)*) => {{
         let buffer_size = $crate::bytes_size!([$expr $(,$extra)*]:$type, $($tail)*);
         let mut buffer:Vec<u8> = vec![0;buffer_size];
         $crate::serialize!(buffer, [$expr $(,$extra)*]:$type, $($tail)*);
         buffer
     } as Vec<u8> };
+
 }
\ No newline at end of file
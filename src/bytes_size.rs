@@ -24,12 +24,12 @@
 
 /// For compatibility between [`32-bit and 64-bit`](https://www.geeksforgeeks.org/difference-32-bit-64-bit-operating-systems/) architectures,
 /// the maximum [`slice`] size allowed is [`u32::MAX`](https://doc.rust-lang.org/std/primitive.u32.html#associatedconstant.MAX)
-/// which take 4 bytes.
+/// which take 4 bytes. Other widths are selected with [`Prefixed<P, T>`](struct.Prefixed.html).
 pub const SLICE_SIZE_IN_BYTES:usize = 4;
 
 /// ##### Variadic macro used to get the size in [`bytes`](https://en.wikipedia.org/wiki/Byte) of [`compatible variables`](macro.bytes_size.html#compatible-variabless) to [`serialize`](https://en.wikipedia.org/wiki/Serialization).
 /// 
-/// Variadic macro used to get the size in [`bytes`](https://en.wikipedia.org/wiki/Byte) of [`bool`], [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html) (except usize, isize), [`String`] and implementors of trait [`Tampon`](trait.Tampon.html).
+/// Variadic macro used to get the size in [`bytes`](https://en.wikipedia.org/wiki/Byte) of [`bool`], [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html), [`String`] and implementors of trait [`Tampon`](trait.Tampon.html).
 /// Also work with [`slice`] by using brackets `[]` instead of parenthesis `()`.
 ///
 /// # Usage
@@ -63,11 +63,23 @@ pub const SLICE_SIZE_IN_BYTES:usize = 4;
 /// 
 /// # Compatible variables(s)
 /// * [`bool`]
-/// * All [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html) except [`usize`] and [`isize`]
+/// * [`char`] written as its 4 bytes [`Unicode scalar value`](https://www.unicode.org/glossary/#unicode_scalar_value)
+/// * All [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html), [`usize`] and [`isize`] are written as [`u64`] and [`i64`]
 /// * [`String`] 
 /// * Implementors of trait [`Tampon`](trait.Tampon.html)
-/// * [`slice`] of the above types
+/// * Type aliases, generic and path-qualified types of the above like `type UserId = u32`, `Wrapper<u32>` or `std::string::String`
+/// * [`Option`] of the above types written as a presence byte followed by the value when [`Some`]
+/// * [`tuple`] of up to 12 of the above types written as the concatenation of their members
+/// * [`array`] `[T; N]` of the above types written as its `N` elements without length prefix
+/// * [`slice`] of the above types, including nested containers like `[v]:Vec<f32>` for a `Vec<Vec<f32>>`
+/// * [`str`] and `[T]` written like [`String`] and slices, to serialize borrowed values
+/// * [`VecDeque`](std::collections::VecDeque), [`HashSet`](std::collections::HashSet), [`BTreeSet`](std::collections::BTreeSet), [`HashMap`](std::collections::HashMap) and [`BTreeMap`](std::collections::BTreeMap) of the above types written like slices, maps write each key followed by its value
+/// * Integers of fields typed [`Varint<T>`](struct.Varint.html) or its aliases like [`varu64`](type.varu64.html) are written as variable length [`LEB128`](https://en.wikipedia.org/wiki/LEB128), signed integers with [`ZigZag`](https://protobuf.dev/programming-guides/encoding/#signed-ints)
+/// * Slices of [`bool`] of fields typed [`Packed<bool>`](struct.Packed.html) are written with 8 booleans per byte after the length prefix
 /// 
+/// # Length prefix
+/// * Length of [`String`] and slices is written as a [`u32`] prefix by default.
+/// * Fields typed [`Prefixed<P, T>`](struct.Prefixed.html) write the length prefix of `T` and of its nested strings and slices as `P`, one of [`u8`], [`u16`], [`u32`], [`u64`] or [`Leb128`](struct.Leb128.html).
 #[macro_export]
 macro_rules! bytes_size {
     // Macro built with Incremental TT munchers pattern : https://danielkeep.github.io/tlborm/book/pat-incremental-tt-munchers.html
@@ -76,165 +88,43 @@ macro_rules! bytes_size {
     () => {{ 0 } as usize };
 
     // Without tail
-    (($expr:expr $(,$extra:expr)*):$type:ident) => {{
+    (($expr:expr $(,$extra:expr)*):$type:ty) => {{
         $crate::bytes_size_var!($expr => $type) $(+$crate::bytes_size_var!($extra => $type))*
     } as usize };
 
     // With tail
-    (($expr:expr $(,$extra:expr)*):$type:ident, $($tail:tt)*) => {{
+    (($expr:expr $(,$extra:expr)*):$type:ty, $($tail:tt)*) => {{
         $crate::bytes_size!($($tail)*) + $crate::bytes_size_var!($expr => $type) $(+$crate::bytes_size_var!($extra => $type))*
     } as usize };
 
     // Without tail
-    ([$expr:expr $(,$extra:expr)*]:$type:ident) => {{
+    ([$expr:expr $(,$extra:expr)*]:$type:ty) => {{
         $crate::bytes_size_var!($expr => [$type]) $(+$crate::bytes_size_var!($extra => [$type]))*
     } as usize };
 
     // With tail
-    ([$expr:expr $(,$extra:expr)*]:$type:ident, $($tail:tt)*) => {{
+    ([$expr:expr $(,$extra:expr)*]:$type:ty, $($tail:tt)*) => {{
         $crate::bytes_size!($($tail)*)  + $crate::bytes_size_var!($expr => [$type]) $(+$crate::bytes_size_var!($extra => [$type]))*
     } as usize };
+
 }
 
 /// Hidden extension of the bytes_size! macro. Not meant to be used directly (although it will still work).
 #[doc(hidden)]
 #[macro_export]
 macro_rules! bytes_size_var {
-    /**********
-    * BOOLEAN *
-    **********/
-    ($expr:expr => bool) => {{ 
-        // bool use 1 byte (even if 1 bit)
-        1 
-    } as usize };
-    ($expr:expr => [bool]) => {{ 
-        // Size padding + length of slice
-        $crate::SLICE_SIZE_IN_BYTES + $expr.len() 
-    } as usize };
-
-    /***********
-    * NUMERICS * 
-    ***********/
-    ($expr:expr => u8) => {{ 
-        // Size of type in bytes
-        core::mem::size_of::<u8>() 
-    } as usize };
-    ($expr:expr => [u8]) => {{ 
-        // Size padding + size of type * length of slice
-        $crate::SLICE_SIZE_IN_BYTES + core::mem::size_of::<u8>() * $expr.len() 
-    } as usize };
-
-    ($expr:expr => u16) => {{ 
-        core::mem::size_of::<u16>() 
-    } as usize };
-    ($expr:expr => [u16]) => {{ 
-        $crate::SLICE_SIZE_IN_BYTES + core::mem::size_of::<u16>() * $expr.len() 
-    } as usize };
-
-    ($expr:expr => u32) => {{ 
-        core::mem::size_of::<u32>() 
-    } as usize };
-    ($expr:expr => [u32]) => {{ 
-        $crate::SLICE_SIZE_IN_BYTES + core::mem::size_of::<u32>() * $expr.len() 
-    } as usize };
-
-    ($expr:expr => u64) => {{ 
-        core::mem::size_of::<u64>() 
-    } as usize };
-    ($expr:expr => [u64]) => {{ 
-        $crate::SLICE_SIZE_IN_BYTES + core::mem::size_of::<u64>() * $expr.len() 
-    } as usize };
-
-    ($expr:expr => u128) => {{ 
-        core::mem::size_of::<u128>() 
-    } as usize };
-    ($expr:expr => [u128]) => {{ 
-        $crate::SLICE_SIZE_IN_BYTES + core::mem::size_of::<u128>() * $expr.len() 
-    } as usize };
-
-    ($expr:expr => f32) => {{ 
-        core::mem::size_of::<f32>() 
-    } as usize };
-    ($expr:expr => [f32]) => {{ 
-        $crate::SLICE_SIZE_IN_BYTES + core::mem::size_of::<f32>() * $expr.len() 
-    } as usize };
-
-    ($expr:expr => f64) => {{ 
-        core::mem::size_of::<f64>() 
-    } as usize };
-    ($expr:expr => [f64]) => {{ 
-        $crate::SLICE_SIZE_IN_BYTES + core::mem::size_of::<f64>() * $expr.len() 
-    } as usize };
-
-    ($expr:expr => i8) => {{ 
-        core::mem::size_of::<i8>() 
-    } as usize };
-    ($expr:expr => [i8]) => {{ 
-        $crate::SLICE_SIZE_IN_BYTES + core::mem::size_of::<i8>() * $expr.len() 
-    } as usize };
-
-    ($expr:expr => i16) => {{ 
-        core::mem::size_of::<i16>() 
-    } as usize };
-    ($expr:expr => [i16]) => {{ 
-        $crate::SLICE_SIZE_IN_BYTES + core::mem::size_of::<i16>() * $expr.len() 
-    } as usize };
-
-    ($expr:expr => i32) => {{ 
-        core::mem::size_of::<i32>() 
-    } as usize };
-    ($expr:expr => [i32]) => {{ 
-        $crate::SLICE_SIZE_IN_BYTES + core::mem::size_of::<i32>() * $expr.len() 
-    } as usize };
+    // Types are dispatched through trait Encode, implemented for implementors of Tampon and markers like BigEndian
 
-    ($expr:expr => i64) => {{ 
-        core::mem::size_of::<i64>() 
-    } as usize };
-    ($expr:expr => [i64]) => {{ 
-        $crate::SLICE_SIZE_IN_BYTES + core::mem::size_of::<i64>() * $expr.len() 
+    // Slice affectator
+    ($expr:expr => [$type:ty]) => {{ 
+        // Numerics multiply their size, other types iterate slice since each element can have a different size
+        // Size padding + size of elements
+        #[allow(unused_imports)]
+        use $crate::encoding::{ EncodeValues as _, EncodeBorrowed as _ };
+        (&$crate::encoding::Elements::<$type, _>::new(&$expr[..])).slice_bytes_size()
     } as usize };
 
-    ($expr:expr => i128) => {{ 
-        core::mem::size_of::<i128>() 
-    } as usize };
-    ($expr:expr => [i128]) => {{ 
-        $crate::SLICE_SIZE_IN_BYTES + core::mem::size_of::<i128>() * $expr.len() 
+    ($expr:expr => $type:ty) => {{
+        <$type as $crate::encoding::Encode>::bytes_size(&$expr)
     } as usize };
-
-
-    /*********
-    * STRING * 
-    *********/
-    ($expr:expr => String) => {{ 
-        // String is a slice of char and need to pad the size
-        // String::len() gives size of string in bytes (https://doc.rust-lang.org/std/string/struct.String.html#method.len-1)
-        $crate::SLICE_SIZE_IN_BYTES + $expr.len()
-    } as usize };
-    ($expr:expr => [String]) => {{
-
-        // We have to iterate [String] since each String can have a different length
-        let mut bytes_size = 0;
-        for elem in $expr.iter() {
-            bytes_size += $crate::SLICE_SIZE_IN_BYTES + elem.len()
-        }
-        $crate::SLICE_SIZE_IN_BYTES + bytes_size
-
-    } as usize };
-
-
-    /***************
-    * TAMPON TRAIT * 
-    ***************/
-    ($expr:expr => $tampon:ident) => {{
-        $expr.bytes_size()
-    } as usize };
-    ($expr:expr => [$tampon:ident]) => {{ 
-        // We have to iterate [Tampon] since each tampon can have a different size
-        let mut bytes_size = 0;
-        for elem in $expr.iter() {
-            bytes_size += elem.bytes_size();
-        }
-        $crate::SLICE_SIZE_IN_BYTES + bytes_size
-    } as usize };
-
 }
\ No newline at end of file
@@ -0,0 +1,778 @@
+/*
+ * @file tampon/collection.rs
+ *
+ * @module tampon
+ *
+ * @brief Contain implementation of Tampon trait for collections.
+ *
+ * @details
+ * Contain implementation of Tampon trait for collections. Collections are written with a u32 length
+ * prefix of SLICE_SIZE_IN_BYTES followed by their elements, like slices of macros. Maps write each
+ * key followed by its value. Arrays have a length known at compile time and are written without prefix.
+ *
+ * Iteration order of hash collections is random, so Sorted wraps them to write entries sorted by
+ * encoded key.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use std::collections::{ BTreeMap, BTreeSet, HashMap, HashSet, VecDeque };
+use std::hash::{ BuildHasher, Hash };
+use std::ops::{ Deref, DerefMut };
+
+use crate::{ Tampon, Error, Limits, Budget, LengthPrefix, SLICE_SIZE_IN_BYTES };
+use crate::stream::{ StreamWriter, StreamReader, STREAM_BUFFER_SIZE };
+use crate::tampon::slice_capacity;
+
+/**********
+* HELPERS *
+**********/
+/// Write length prefix followed by each element.
+fn serialize_elements<'a, T: Tampon + 'a>(len : usize, elements : impl Iterator<Item = &'a T>, buffer : &mut [u8], prefix : LengthPrefix) -> usize {
+    // Write size of slice
+    let mut bytes_copied = prefix.serialize(len, buffer);
+
+    // Write each element after the previous one
+    for elem in elements {
+        bytes_copied += elem.serialize_prefixed(&mut buffer[bytes_copied..], prefix);
+    }
+
+    bytes_copied
+}
+
+/// Write length prefix followed by each element after verifying `required` size.
+fn try_serialize_elements<'a, T: Tampon + 'a>(len : usize, elements : impl Iterator<Item = &'a T>, required : usize, buffer : &mut [u8], prefix : LengthPrefix) -> Result<usize, Error> {
+    // Verify size before writing anything
+    if required > buffer.len() {
+        return Err(Error::BufferTooSmall { required, available: buffer.len() });
+    }
+
+    let mut bytes_copied = prefix.try_serialize(len, buffer)?;
+
+    // Errors of elements are relative to their own buffer and need to be offset
+    for elem in elements {
+        let offset = bytes_copied;
+        bytes_copied += elem.try_serialize_prefixed(&mut buffer[offset..], prefix).map_err(|err| err.offset_by(offset))?;
+    }
+
+    Ok(bytes_copied)
+}
+
+/// Write length prefix followed by each key and its value.
+fn serialize_entries<'a, K: Tampon + 'a, V: Tampon + 'a>(len : usize, entries : impl Iterator<Item = (&'a K, &'a V)>, buffer : &mut [u8], prefix : LengthPrefix) -> usize {
+    let mut bytes_copied = prefix.serialize(len, buffer);
+    for (key, value) in entries {
+        bytes_copied += key.serialize_prefixed(&mut buffer[bytes_copied..], prefix);
+        bytes_copied += value.serialize_prefixed(&mut buffer[bytes_copied..], prefix);
+    }
+
+    bytes_copied
+}
+
+/// Write length prefix followed by each key and its value after verifying `required` size.
+fn try_serialize_entries<'a, K: Tampon + 'a, V: Tampon + 'a>(len : usize, entries : impl Iterator<Item = (&'a K, &'a V)>, required : usize, buffer : &mut [u8], prefix : LengthPrefix) -> Result<usize, Error> {
+    if required > buffer.len() {
+        return Err(Error::BufferTooSmall { required, available: buffer.len() });
+    }
+
+    let mut bytes_copied = prefix.try_serialize(len, buffer)?;
+    for (key, value) in entries {
+        let offset = bytes_copied;
+        bytes_copied += key.try_serialize_prefixed(&mut buffer[offset..], prefix).map_err(|err| err.offset_by(offset))?;
+        let offset = bytes_copied;
+        bytes_copied += value.try_serialize_prefixed(&mut buffer[offset..], prefix).map_err(|err| err.offset_by(offset))?;
+    }
+
+    Ok(bytes_copied)
+}
+
+/// Read length prefix and give each element to `insert` which returns false if element is a duplicate.
+/// 
+/// # Panic(s)
+/// * Will panic! if `insert` returns false.
+fn deserialize_elements<T: Tampon>(buffer : &[u8], prefix : LengthPrefix, mut insert : impl FnMut(T) -> bool) -> usize {
+    // Get size of slice
+    let (slice_size, mut bytes_read) = prefix.deserialize(buffer);
+
+    // Read each element after the previous one
+    for _ in 0..slice_size {
+        let (elem, size) = T::deserialize_prefixed(&buffer[bytes_read..], prefix);
+        if !insert(elem) {
+            panic!("{}", Error::DuplicateKey { offset: bytes_read });
+        }
+        bytes_read += size;
+    }
+
+    bytes_read
+}
+
+/// Read length prefix and give each element to `insert` which returns false if element is a duplicate.
+fn try_deserialize_elements<T: Tampon>(buffer : &[u8], limits : &Limits, budget : &mut Budget, prefix : LengthPrefix, mut insert : impl FnMut(T) -> bool) -> Result<usize, Error> {
+    // Get size of slice
+    let (slice_size, mut bytes_read) = prefix.try_deserialize(buffer)?;
+
+    // Validate length prefix against limits and remaining bytes before allocating
+    limits.check_slice(0, slice_size, T::min_bytes_size_prefixed(prefix), core::mem::size_of::<T>(), buffer.len() - bytes_read, budget)?;
+
+    // Retrieve each element. Error will be returned as soon as buffer ends.
+    for _ in 0..slice_size {
+        let offset = bytes_read;
+        let (elem, size) = T::try_deserialize_prefixed(&buffer[offset..], limits, budget, prefix).map_err(|err| err.offset_by(offset))?;
+        if !insert(elem) {
+            return Err(Error::DuplicateKey { offset });
+        }
+        bytes_read += size;
+    }
+
+    Ok(bytes_read)
+}
+
+/// Write length prefix of `len` elements into `stream`.
+fn serialize_stream_len(len : usize, stream : &mut StreamWriter<'_>) -> Result<(), Error> {
+    let prefix = LengthPrefix::default();
+    stream.write_with(prefix.size(len), |buffer| prefix.try_serialize(len, buffer))
+}
+
+/// Write length prefix followed by each element into `stream` one by one.
+fn serialize_stream_elements<'a, T: Tampon + 'a>(len : usize, elements : impl Iterator<Item = &'a T>, stream : &mut StreamWriter<'_>) -> Result<(), Error> {
+    serialize_stream_len(len, stream)?;
+    for elem in elements {
+        elem.serialize_stream(stream)?;
+    }
+    Ok(())
+}
+
+/// Write length prefix followed by each key and its value into `stream` one by one.
+fn serialize_stream_entries<'a, K: Tampon + 'a, V: Tampon + 'a>(len : usize, entries : impl Iterator<Item = (&'a K, &'a V)>, stream : &mut StreamWriter<'_>) -> Result<(), Error> {
+    serialize_stream_len(len, stream)?;
+    for (key, value) in entries {
+        key.serialize_stream(stream)?;
+        value.serialize_stream(stream)?;
+    }
+    Ok(())
+}
+
+/// Read length prefix encoded as `prefix` from `stream` and validate it against limits before allocating.
+fn try_deserialize_stream_len<T: Tampon>(stream : &mut StreamReader<'_>, limits : &Limits, prefix : LengthPrefix) -> Result<usize, Error> {
+    let offset = stream.bytes_read();
+    let len = stream.read_with(|buffer| prefix.try_deserialize(buffer))?;
+
+    // Remaining bytes of reader are unknown, only limits can reject the length
+    limits.check_slice(offset, len, T::min_bytes_size_prefixed(prefix), core::mem::size_of::<T>(), usize::MAX, stream.budget())?;
+    Ok(len)
+}
+
+/// Read length prefix from `stream` and give each element to `insert` which returns false if element is a duplicate.
+fn try_deserialize_stream_elements<T: Tampon>(stream : &mut StreamReader<'_>, limits : &Limits, prefix : LengthPrefix, mut insert : impl FnMut(T) -> bool) -> Result<(), Error> {
+    let len = try_deserialize_stream_len::<T>(stream, limits, prefix)?;
+    for index in 0..len {
+        // Remaining elements take at least their minimum size
+        stream.prefetch((len - index).saturating_mul(T::min_bytes_size_prefixed(prefix)))?;
+        let offset = stream.bytes_read();
+        if !insert(T::try_deserialize_stream_prefixed(stream, limits, prefix)?) {
+            return Err(Error::DuplicateKey { offset });
+        }
+    }
+    Ok(())
+}
+
+/// Encode `key` with length prefixes encoded as `prefix`, like it is written, to sort entries of hash collections.
+fn encoded_key<K: Tampon>(key : &K, prefix : LengthPrefix) -> Vec<u8> {
+    let mut bytes = vec![0u8; key.bytes_size_prefixed(prefix)];
+    key.serialize_prefixed(&mut bytes, prefix);
+    bytes
+}
+
+/// Implement functions of Tampon with the default length prefix by calling their prefixed version.
+/// Collection is fallible if all its `$element` types are.
+macro_rules! tampon_default_prefix {
+    ($($element:ty),+) => {
+        fn bytes_size(&self) -> usize {
+            self.bytes_size_prefixed(LengthPrefix::default())
+        }
+
+        fn serialize(&self, buffer : &mut [u8]) -> usize {
+            self.serialize_prefixed(buffer, LengthPrefix::default())
+        }
+
+        fn deserialize(buffer : &[u8]) -> (Self, usize) {
+            Self::deserialize_prefixed(buffer, LengthPrefix::default())
+        }
+
+        fn try_serialize(&self, buffer : &mut [u8]) -> Result<usize, Error> {
+            self.try_serialize_prefixed(buffer, LengthPrefix::default())
+        }
+
+        fn try_deserialize(buffer : &[u8]) -> Result<(Self, usize), Error> {
+            Self::try_deserialize_with(buffer, &Limits::default(), &mut Budget::new())
+        }
+
+        fn try_deserialize_with(buffer : &[u8], limits : &Limits, budget : &mut Budget) -> Result<(Self, usize), Error> {
+            Self::try_deserialize_prefixed(buffer, limits, budget, LengthPrefix::default())
+        }
+
+        #[inline]
+        fn is_fallible() -> bool {
+            $( <$element as Tampon>::is_fallible() )&&+
+        }
+
+        #[inline]
+        fn try_deserialize_stream(stream : &mut StreamReader<'_>, limits : &Limits) -> Result<Self, Error> {
+            Self::try_deserialize_stream_prefixed(stream, limits, LengthPrefix::default())
+        }
+
+        #[inline]
+        fn min_bytes_size() -> usize {
+            // Length prefix of collection
+            SLICE_SIZE_IN_BYTES
+        }
+
+        #[inline]
+        fn min_bytes_size_prefixed(prefix : LengthPrefix) -> usize {
+            prefix.min_size()
+        }
+    };
+}
+
+/// Implement Tampon for `$collection` of elements added with `$insert`, which returns false for duplicates.
+macro_rules! tampon_elements {
+    ($collection:ty, $insert:expr, [$($bounds:tt)*]) => {
+        impl<$($bounds)*> Tampon for $collection {
+            tampon_default_prefix!(T);
+
+            fn bytes_size_prefixed(&self, prefix : LengthPrefix) -> usize {
+                // Size padding + size of each element
+                prefix.size(self.len()) + self.iter().map(|elem| elem.bytes_size_prefixed(prefix)).sum::<usize>()
+            }
+
+            fn serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> usize {
+                serialize_elements(self.len(), self.iter(), buffer, prefix)
+            }
+
+            fn try_serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> Result<usize, Error> {
+                try_serialize_elements(self.len(), self.iter(), self.bytes_size_prefixed(prefix), buffer, prefix)
+            }
+
+            fn deserialize_prefixed(buffer : &[u8], prefix : LengthPrefix) -> (Self, usize) {
+                let mut collection = Self::default();
+                let bytes_read = deserialize_elements(buffer, prefix, |elem| $insert(&mut collection, elem));
+                (collection, bytes_read)
+            }
+
+            fn try_deserialize_prefixed(buffer : &[u8], limits : &Limits, budget : &mut Budget, prefix : LengthPrefix) -> Result<(Self, usize), Error> {
+                let mut collection = Self::default();
+                let bytes_read = try_deserialize_elements(buffer, limits, budget, prefix, |elem| $insert(&mut collection, elem))?;
+                Ok((collection, bytes_read))
+            }
+
+            fn serialize_stream(&self, stream : &mut StreamWriter<'_>) -> Result<(), Error> {
+                // Elements are written one by one when collection is bigger than buffer of stream
+                let size = self.bytes_size();
+                if stream.fits(size) {
+                    return stream.write_with(size, |buffer| self.try_serialize(buffer));
+                }
+                serialize_stream_elements(self.len(), self.iter(), stream)
+            }
+
+            fn try_deserialize_stream_prefixed(stream : &mut StreamReader<'_>, limits : &Limits, prefix : LengthPrefix) -> Result<Self, Error> {
+                let mut collection = Self::default();
+                try_deserialize_stream_elements(stream, limits, prefix, |elem| $insert(&mut collection, elem))?;
+                Ok(collection)
+            }
+        }
+    };
+}
+
+/// Implement Tampon for `$collection` map of keys `K` and values `V`.
+macro_rules! tampon_entries {
+    ($collection:ty, [$($bounds:tt)*]) => {
+        impl<$($bounds)*> Tampon for $collection {
+            tampon_default_prefix!(K, V);
+
+            fn bytes_size_prefixed(&self, prefix : LengthPrefix) -> usize {
+                prefix.size(self.len()) + self.iter().map(|(key, value)| key.bytes_size_prefixed(prefix) + value.bytes_size_prefixed(prefix)).sum::<usize>()
+            }
+
+            fn serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> usize {
+                serialize_entries(self.len(), self.iter(), buffer, prefix)
+            }
+
+            fn try_serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> Result<usize, Error> {
+                try_serialize_entries(self.len(), self.iter(), self.bytes_size_prefixed(prefix), buffer, prefix)
+            }
+
+            fn deserialize_prefixed(buffer : &[u8], prefix : LengthPrefix) -> (Self, usize) {
+                // Entries are read as tuple of key and value
+                let mut map = Self::default();
+                let bytes_read = deserialize_elements(buffer, prefix, |(key, value):(K, V)| map.insert(key, value).is_none());
+                (map, bytes_read)
+            }
+
+            fn try_deserialize_prefixed(buffer : &[u8], limits : &Limits, budget : &mut Budget, prefix : LengthPrefix) -> Result<(Self, usize), Error> {
+                let mut map = Self::default();
+                let bytes_read = try_deserialize_elements(buffer, limits, budget, prefix, |(key, value):(K, V)| map.insert(key, value).is_none())?;
+                Ok((map, bytes_read))
+            }
+
+            fn serialize_stream(&self, stream : &mut StreamWriter<'_>) -> Result<(), Error> {
+                let size = self.bytes_size();
+                if stream.fits(size) {
+                    return stream.write_with(size, |buffer| self.try_serialize(buffer));
+                }
+                serialize_stream_entries(self.len(), self.iter(), stream)
+            }
+
+            fn try_deserialize_stream_prefixed(stream : &mut StreamReader<'_>, limits : &Limits, prefix : LengthPrefix) -> Result<Self, Error> {
+                let mut map = Self::default();
+                try_deserialize_stream_elements(stream, limits, prefix, |(key, value):(K, V)| map.insert(key, value).is_none())?;
+                Ok(map)
+            }
+        }
+    };
+}
+
+/********
+* SLICE *
+********/
+/// Write length prefix followed by elements of `slice`, numerics are copied in bulk.
+fn serialize_slice<T: Tampon>(slice : &[T], buffer : &mut [u8], prefix : LengthPrefix) -> usize {
+    let size = prefix.serialize(slice.len(), buffer);
+    size + T::serialize_slice(slice, &mut buffer[size..], prefix)
+}
+
+/// Write length prefix followed by elements of `slice` after verifying size of buffer.
+fn try_serialize_slice<T: Tampon>(slice : &[T], buffer : &mut [u8], prefix : LengthPrefix) -> Result<usize, Error> {
+    // Verify size before writing anything
+    let required = prefix.size(slice.len()) + T::slice_bytes_size(slice, prefix);
+    if required > buffer.len() {
+        return Err(Error::BufferTooSmall { required, available: buffer.len() });
+    }
+
+    let offset = prefix.try_serialize(slice.len(), buffer)?;
+
+    // Errors of elements are relative to the buffer of elements and need to be offset
+    Ok(offset + T::try_serialize_slice(slice, &mut buffer[offset..], prefix).map_err(|err| err.offset_by(offset))?)
+}
+
+/// Write length prefix followed by elements of `slice` into `stream`, in chunks fitting in its buffer.
+fn serialize_stream_slice<T: Tampon>(slice : &[T], stream : &mut StreamWriter<'_>) -> Result<(), Error> {
+    let prefix = LengthPrefix::default();
+    let size = slice.bytes_size();
+    if stream.fits(size) {
+        return stream.write_with(size, |buffer| try_serialize_slice(slice, buffer, prefix));
+    }
+
+    // Numerics of chunks are copied in bulk, elements bigger than buffer are written by themselves
+    serialize_stream_len(slice.len(), stream)?;
+    let (mut start, mut size) = (0, 0);
+    for (index, elem) in slice.iter().enumerate() {
+        let elem_size = elem.bytes_size();
+        if !stream.fits(size + elem_size) {
+            let chunk = &slice[start..index];
+            stream.write_with(size, |buffer| T::try_serialize_slice(chunk, buffer, prefix))?;
+            (start, size) = (index, 0);
+
+            if !stream.fits(elem_size) {
+                elem.serialize_stream(stream)?;
+                start = index + 1;
+                continue;
+            }
+        }
+        size += elem_size;
+    }
+
+    let chunk = &slice[start..];
+    stream.write_with(size, |buffer| T::try_serialize_slice(chunk, buffer, prefix))
+}
+
+/// Slice is written like `[v]:T` in macros and is deserialized as Vec, or with TamponBorrow as &[u8].
+impl<T: Tampon> Tampon for [T] {
+    fn bytes_size(&self) -> usize {
+        self.bytes_size_prefixed(LengthPrefix::default())
+    }
+
+    fn serialize(&self, buffer : &mut [u8]) -> usize {
+        serialize_slice(self, buffer, LengthPrefix::default())
+    }
+
+    fn try_serialize(&self, buffer : &mut [u8]) -> Result<usize, Error> {
+        try_serialize_slice(self, buffer, LengthPrefix::default())
+    }
+
+    fn bytes_size_prefixed(&self, prefix : LengthPrefix) -> usize {
+        prefix.size(self.len()) + T::slice_bytes_size(self, prefix)
+    }
+
+    fn serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> usize {
+        serialize_slice(self, buffer, prefix)
+    }
+
+    fn try_serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> Result<usize, Error> {
+        try_serialize_slice(self, buffer, prefix)
+    }
+
+    fn serialize_stream(&self, stream : &mut StreamWriter<'_>) -> Result<(), Error> {
+        serialize_stream_slice(self, stream)
+    }
+}
+
+/******
+* VEC *
+******/
+/// Vec is written exactly like `[v]:T` in macros and is allocated once when deserialized.
+impl<T: Tampon> Tampon for Vec<T> {
+    tampon_default_prefix!(T);
+
+    fn bytes_size_prefixed(&self, prefix : LengthPrefix) -> usize {
+        self.as_slice().bytes_size_prefixed(prefix)
+    }
+
+    fn serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> usize {
+        serialize_slice(self, buffer, prefix)
+    }
+
+    fn try_serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> Result<usize, Error> {
+        try_serialize_slice(self, buffer, prefix)
+    }
+
+    fn deserialize_prefixed(buffer : &[u8], prefix : LengthPrefix) -> (Self, usize) {
+        // Get size of slice
+        let (slice_size, size) = prefix.deserialize(buffer);
+
+        let (vec, bytes_read) = T::deserialize_slice(&buffer[size..], slice_size, prefix);
+        (vec, size + bytes_read)
+    }
+
+    fn try_deserialize_prefixed(buffer : &[u8], limits : &Limits, budget : &mut Budget, prefix : LengthPrefix) -> Result<(Self, usize), Error> {
+        let (slice_size, size) = prefix.try_deserialize(buffer)?;
+
+        // Validate length prefix against limits and remaining bytes before allocating
+        limits.check_slice(0, slice_size, T::min_bytes_size_prefixed(prefix), core::mem::size_of::<T>(), buffer.len() - size, budget)?;
+
+        let (vec, bytes_read) = T::try_deserialize_slice(&buffer[size..], slice_size, limits, budget, prefix)
+            .map_err(|err| err.offset_by(size))?;
+        Ok((vec, size + bytes_read))
+    }
+
+    fn serialize_stream(&self, stream : &mut StreamWriter<'_>) -> Result<(), Error> {
+        serialize_stream_slice(self, stream)
+    }
+
+    fn try_deserialize_stream_prefixed(stream : &mut StreamReader<'_>, limits : &Limits, prefix : LengthPrefix) -> Result<Self, Error> {
+        // Capacity is bounded by buffer of stream, so a corrupted length can't allocate more
+        let len = try_deserialize_stream_len::<T>(stream, limits, prefix)?;
+        let min_size = T::min_bytes_size_prefixed(prefix);
+        let mut vec = Vec::with_capacity(slice_capacity(len, min_size, STREAM_BUFFER_SIZE));
+        for index in 0..len {
+            // Remaining elements take at least their minimum size, so numerics are read by chunks
+            stream.prefetch((len - index).saturating_mul(min_size))?;
+            vec.push(T::try_deserialize_stream_prefixed(stream, limits, prefix)?);
+        }
+        Ok(vec)
+    }
+}
+
+/***********
+* VECDEQUE *
+***********/
+// VecDeque is written like Vec.
+tampon_elements!(VecDeque<T>, |deque:&mut VecDeque<T>, elem| { deque.push_back(elem); true }, [T: Tampon]);
+
+/***********
+* BTREESET *
+***********/
+// BTreeSet is written in order of elements.
+tampon_elements!(BTreeSet<T>, |set:&mut BTreeSet<T>, elem| set.insert(elem), [T: Tampon + Ord]);
+
+/**********
+* HASHSET *
+**********/
+// HashSet is written in iteration order, use Sorted for a deterministic order.
+tampon_elements!(HashSet<T, S>, |set:&mut HashSet<T, S>, elem| set.insert(elem), [T: Tampon + Eq + Hash, S: BuildHasher + Default]);
+
+/***********
+* BTREEMAP *
+***********/
+// BTreeMap is written in order of keys.
+tampon_entries!(BTreeMap<K, V>, [K: Tampon + Ord, V: Tampon]);
+
+/**********
+* HASHMAP *
+**********/
+// HashMap is written in iteration order, use Sorted for a deterministic order.
+tampon_entries!(HashMap<K, V, S>, [K: Tampon + Eq + Hash, V: Tampon, S: BuildHasher + Default]);
+
+/*********
+* SORTED *
+*********/
+/// ##### Wrapper of [`HashMap`] and [`HashSet`] writing entries sorted by encoded key.
+/// 
+/// Iteration order of hash collections changes between instances, so the same map can produce different buffers.
+/// `Sorted` writes entries sorted by the bytes of their encoded key so equal collections always produce the same
+/// buffer. The wire format is the same as the wrapped collection and both can read buffers of each other.
+/// 
+/// `Sorted` dereferences to the wrapped collection.
+/// 
+/// # Example(s)
+/// ```
+/// use std::collections::HashMap;
+/// use tampon::{ Sorted, buffer, deserialize };
+/// 
+/// let mut a:Sorted<HashMap<u8, String>> = Sorted::default();
+/// a.insert(2, String::from("two"));
+/// a.insert(1, String::from("one"));
+/// 
+/// // Key 1 is always written first
+/// let buffer = buffer!((a):Sorted<HashMap<u8, String>>);
+/// assert_eq!(buffer[0..5], [2, 0, 0, 0, 1]);
+/// 
+/// deserialize!(buffer, (b):HashMap<u8, String>);
+/// assert_eq!(*a, b);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Sorted<C>(pub C);
+
+impl<C> Deref for Sorted<C> {
+    type Target = C;
+
+    fn deref(&self) -> &C {
+        &self.0
+    }
+}
+
+impl<C> DerefMut for Sorted<C> {
+    fn deref_mut(&mut self) -> &mut C {
+        &mut self.0
+    }
+}
+
+impl<C> From<C> for Sorted<C> {
+    fn from(collection : C) -> Self {
+        Sorted(collection)
+    }
+}
+
+impl<T: Tampon + Eq + Hash, S: BuildHasher + Default> Sorted<HashSet<T, S>> {
+    /// Elements sorted by bytes encoded with length prefixes encoded as `prefix`.
+    fn sorted(&self, prefix : LengthPrefix) -> Vec<&T> {
+        let mut elements:Vec<(Vec<u8>, &T)> = self.0.iter().map(|elem| (encoded_key(elem, prefix), elem)).collect();
+        elements.sort_by(|a, b| a.0.cmp(&b.0));
+        elements.into_iter().map(|(_, elem)| elem).collect()
+    }
+}
+
+impl<T: Tampon + Eq + Hash, S: BuildHasher + Default> Tampon for Sorted<HashSet<T, S>> {
+    tampon_default_prefix!(T);
+
+    fn bytes_size_prefixed(&self, prefix : LengthPrefix) -> usize {
+        self.0.bytes_size_prefixed(prefix)
+    }
+
+    fn serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> usize {
+        serialize_elements(self.0.len(), self.sorted(prefix).into_iter(), buffer, prefix)
+    }
+
+    fn try_serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> Result<usize, Error> {
+        try_serialize_elements(self.0.len(), self.sorted(prefix).into_iter(), self.bytes_size_prefixed(prefix), buffer, prefix)
+    }
+
+    fn deserialize_prefixed(buffer : &[u8], prefix : LengthPrefix) -> (Self, usize) {
+        let (set, bytes_read) = HashSet::deserialize_prefixed(buffer, prefix);
+        (Sorted(set), bytes_read)
+    }
+
+    fn try_deserialize_prefixed(buffer : &[u8], limits : &Limits, budget : &mut Budget, prefix : LengthPrefix) -> Result<(Self, usize), Error> {
+        let (set, bytes_read) = HashSet::try_deserialize_prefixed(buffer, limits, budget, prefix)?;
+        Ok((Sorted(set), bytes_read))
+    }
+
+    fn try_deserialize_stream_prefixed(stream : &mut StreamReader<'_>, limits : &Limits, prefix : LengthPrefix) -> Result<Self, Error> {
+        Ok(Sorted(HashSet::try_deserialize_stream_prefixed(stream, limits, prefix)?))
+    }
+}
+
+impl<K: Tampon + Eq + Hash, V: Tampon, S: BuildHasher + Default> Sorted<HashMap<K, V, S>> {
+    /// Entries sorted by key bytes encoded with length prefixes encoded as `prefix`.
+    fn sorted(&self, prefix : LengthPrefix) -> Vec<(&K, &V)> {
+        let mut entries:Vec<(Vec<u8>, (&K, &V))> = self.0.iter().map(|entry| (encoded_key(entry.0, prefix), entry)).collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        entries.into_iter().map(|(_, entry)| entry).collect()
+    }
+}
+
+impl<K: Tampon + Eq + Hash, V: Tampon, S: BuildHasher + Default> Tampon for Sorted<HashMap<K, V, S>> {
+    tampon_default_prefix!(K, V);
+
+    fn bytes_size_prefixed(&self, prefix : LengthPrefix) -> usize {
+        self.0.bytes_size_prefixed(prefix)
+    }
+
+    fn serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> usize {
+        serialize_entries(self.0.len(), self.sorted(prefix).into_iter(), buffer, prefix)
+    }
+
+    fn try_serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> Result<usize, Error> {
+        // Keys are sorted with serialize and written with try_serialize to get offset of errors
+        try_serialize_entries(self.0.len(), self.sorted(prefix).into_iter(), self.bytes_size_prefixed(prefix), buffer, prefix)
+    }
+
+    fn deserialize_prefixed(buffer : &[u8], prefix : LengthPrefix) -> (Self, usize) {
+        let (map, bytes_read) = HashMap::deserialize_prefixed(buffer, prefix);
+        (Sorted(map), bytes_read)
+    }
+
+    fn try_deserialize_prefixed(buffer : &[u8], limits : &Limits, budget : &mut Budget, prefix : LengthPrefix) -> Result<(Self, usize), Error> {
+        let (map, bytes_read) = HashMap::try_deserialize_prefixed(buffer, limits, budget, prefix)?;
+        Ok((Sorted(map), bytes_read))
+    }
+
+    fn try_deserialize_stream_prefixed(stream : &mut StreamReader<'_>, limits : &Limits, prefix : LengthPrefix) -> Result<Self, Error> {
+        Ok(Sorted(HashMap::try_deserialize_stream_prefixed(stream, limits, prefix)?))
+    }
+}
+
+/********
+* ARRAY *
+********/
+/// Array is written as its `N` elements without length prefix.
+impl<T: Tampon, const N: usize> Tampon for [T; N] {
+    fn bytes_size(&self) -> usize {
+        self.bytes_size_prefixed(LengthPrefix::default())
+    }
+
+    fn serialize(&self, buffer : &mut [u8]) -> usize {
+        self.serialize_prefixed(buffer, LengthPrefix::default())
+    }
+
+    fn deserialize(buffer : &[u8]) -> (Self, usize) {
+        Self::deserialize_prefixed(buffer, LengthPrefix::default())
+    }
+
+    fn try_serialize(&self, buffer : &mut [u8]) -> Result<usize, Error> {
+        self.try_serialize_prefixed(buffer, LengthPrefix::default())
+    }
+
+    fn try_deserialize(buffer : &[u8]) -> Result<(Self, usize), Error> {
+        Self::try_deserialize_with(buffer, &Limits::default(), &mut Budget::new())
+    }
+
+    fn try_deserialize_with(buffer : &[u8], limits : &Limits, budget : &mut Budget) -> Result<(Self, usize), Error> {
+        Self::try_deserialize_prefixed(buffer, limits, budget, LengthPrefix::default())
+    }
+
+    #[inline]
+    fn is_fallible() -> bool {
+        T::is_fallible()
+    }
+
+    #[inline]
+    fn min_bytes_size() -> usize {
+        N * T::min_bytes_size()
+    }
+
+    #[inline]
+    fn min_bytes_size_prefixed(prefix : LengthPrefix) -> usize {
+        N * T::min_bytes_size_prefixed(prefix)
+    }
+
+    fn bytes_size_prefixed(&self, prefix : LengthPrefix) -> usize {
+        self.iter().map(|elem| elem.bytes_size_prefixed(prefix)).sum::<usize>()
+    }
+
+    fn serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> usize {
+        // Write each element after the previous one
+        let mut bytes_copied = 0;
+        for elem in self.iter() {
+            bytes_copied += elem.serialize_prefixed(&mut buffer[bytes_copied..], prefix);
+        }
+
+        bytes_copied
+    }
+
+    fn try_serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> Result<usize, Error> {
+        // Verify size before writing anything
+        let required = self.bytes_size_prefixed(prefix);
+        if required > buffer.len() {
+            return Err(Error::BufferTooSmall { required, available: buffer.len() });
+        }
+
+        // Errors of elements are relative to their own buffer and need to be offset
+        let mut bytes_copied = 0;
+        for elem in self.iter() {
+            let offset = bytes_copied;
+            bytes_copied += elem.try_serialize_prefixed(&mut buffer[offset..], prefix).map_err(|err| err.offset_by(offset))?;
+        }
+
+        Ok(bytes_copied)
+    }
+
+    fn deserialize_prefixed(buffer : &[u8], prefix : LengthPrefix) -> (Self, usize) {
+        // Elements are created in order, each one after the previous one
+        let mut bytes_read = 0;
+        let array = core::array::from_fn(|_| {
+            let (elem, size) = T::deserialize_prefixed(&buffer[bytes_read..], prefix);
+            bytes_read += size;
+            elem
+        });
+
+        (array, bytes_read)
+    }
+
+    fn try_deserialize_prefixed(buffer : &[u8], limits : &Limits, budget : &mut Budget, prefix : LengthPrefix) -> Result<(Self, usize), Error> {
+        // Retrieve each element. Error will be returned as soon as buffer ends.
+        let mut bytes_read = 0;
+        let mut vec:Vec<T> = Vec::with_capacity(N);
+        for _ in 0..N {
+            let offset = bytes_read;
+            let (elem, size) = T::try_deserialize_prefixed(&buffer[offset..], limits, budget, prefix).map_err(|err| err.offset_by(offset))?;
+            vec.push(elem);
+            bytes_read += size;
+        }
+
+        // Vec always contains N elements
+        match vec.try_into() {
+            Ok(array) => Ok((array, bytes_read)),
+            Err(_) => unreachable!(),
+        }
+    }
+
+    fn serialize_stream(&self, stream : &mut StreamWriter<'_>) -> Result<(), Error> {
+        let size = self.bytes_size();
+        if stream.fits(size) {
+            return stream.write_with(size, |buffer| self.try_serialize(buffer));
+        }
+        for elem in self.iter() {
+            elem.serialize_stream(stream)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn try_deserialize_stream(stream : &mut StreamReader<'_>, limits : &Limits) -> Result<Self, Error> {
+        Self::try_deserialize_stream_prefixed(stream, limits, LengthPrefix::default())
+    }
+
+    fn try_deserialize_stream_prefixed(stream : &mut StreamReader<'_>, limits : &Limits, prefix : LengthPrefix) -> Result<Self, Error> {
+        let mut vec:Vec<T> = Vec::with_capacity(N);
+        for index in 0..N {
+            stream.prefetch((N - index).saturating_mul(T::min_bytes_size_prefixed(prefix)))?;
+            vec.push(T::try_deserialize_stream_prefixed(stream, limits, prefix)?);
+        }
+
+        // Vec always contains N elements
+        match vec.try_into() {
+            Ok(array) => Ok(array),
+            Err(_) => unreachable!(),
+        }
+    }
+}
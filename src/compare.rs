@@ -46,6 +46,7 @@
 /// * `b2` - Second `Vec<u8>` buffer reference to compare.
 /// # Return
 /// Absolute difference between both buffers. Identical in size and content will return 0.
+#[allow(clippy::ptr_arg, clippy::assign_op_pattern)]
 pub fn compare_buffers(b1 : &Vec<u8>,  b2 : &Vec<u8>) -> usize {
         
     // Difference is initialize with the absolute difference in length
@@ -24,7 +24,7 @@
 /// ##### Variadic macro used to [`deserialize`](https://en.wikipedia.org/wiki/Serialization) [`compatible variables`](macro.deserialize.html#compatible-variabless) from a [`buffer`](https://en.wikipedia.org/wiki/Data_buffer). 
 /// 
 /// # Description
-/// Variadic macro used to [`deserialize`](https://en.wikipedia.org/wiki/Serialization) [`bool`], [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html) (except usize, isize), [`String`] and implementors of trait [`Tampon`](trait.Tampon.html).
+/// Variadic macro used to [`deserialize`](https://en.wikipedia.org/wiki/Serialization) [`bool`], [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html), [`String`] and implementors of trait [`Tampon`](trait.Tampon.html).
 /// Also work with [`slice`] by using brackets `[]` instead of parenthesis `()`.
 /// 
 /// <b>deserialize! automatically creates variables when retrieving data.</b>
@@ -63,13 +63,28 @@
 /// 
 /// # Compatible variables(s)
 /// * [`bool`]
-/// * All [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html) except [`usize`] and [`isize`]
+/// * [`char`] written as its 4 bytes [`Unicode scalar value`](https://www.unicode.org/glossary/#unicode_scalar_value)
+/// * All [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html), [`usize`] and [`isize`] are written as [`u64`] and [`i64`]
 /// * [`String`] 
 /// * Implementors of trait [`Tampon`](trait.Tampon.html)
-/// * [`slice`] of the above types
+/// * Type aliases, generic and path-qualified types of the above like `type UserId = u32`, `Wrapper<u32>` or `std::string::String`
+/// * [`Option`] of the above types written as a presence byte followed by the value when [`Some`]
+/// * [`tuple`] of up to 12 of the above types written as the concatenation of their members
+/// * [`array`] `[T; N]` of the above types written as its `N` elements without length prefix
+/// * [`slice`] of the above types, including nested containers like `[v]:Vec<f32>` for a `Vec<Vec<f32>>`
+/// * [`&str`](str), `&[u8]`, `Cow<str>` and `Cow<[u8]>` borrowed from `buffer` without copy, see [`TamponBorrow`](trait.TamponBorrow.html)
+/// * [`VecDeque`](std::collections::VecDeque), [`HashSet`](std::collections::HashSet), [`BTreeSet`](std::collections::BTreeSet), [`HashMap`](std::collections::HashMap) and [`BTreeMap`](std::collections::BTreeMap) of the above types written like slices, maps write each key followed by its value
+/// * Integers of fields typed [`Varint<T>`](struct.Varint.html) or its aliases like [`varu64`](type.varu64.html) are read as variable length [`LEB128`](https://en.wikipedia.org/wiki/LEB128), signed integers with [`ZigZag`](https://protobuf.dev/programming-guides/encoding/#signed-ints)
+/// * Slices of [`bool`] of fields typed [`Packed<bool>`](struct.Packed.html) are read with 8 booleans per byte after the length prefix
 /// 
 /// # Endianness
 /// * [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html) bytes are written as [`little endian`](https://en.wikipedia.org/wiki/Endianness).
+/// * [`usize`] and [`isize`] are always written as 8 bytes [`u64`] and [`i64`], whatever the target pointer width.
+/// * Fields typed [`BigEndian<T>`](struct.BigEndian.html) or its aliases like [`u16be`](type.u16be.html) are read as [`big endian`](https://en.wikipedia.org/wiki/Endianness), including the length prefix of strings and slices.
+/// 
+/// # Length prefix
+/// * Length of [`String`] and slices is read as a [`u32`] prefix by default.
+/// * Fields typed [`Prefixed<P, T>`](struct.Prefixed.html) read the length prefix of `T` and of its nested strings and slices as `P`, one of [`u8`], [`u16`], [`u32`], [`u64`] or [`Leb128`](struct.Leb128.html).
 /// 
 /// # Panic(s)
 /// * Will panic! if `buffer` length is smaller than all target length combined.
@@ -79,19 +94,19 @@ macro_rules! deserialize {
     * VARIABLES * 
     ************/
      // Expression without tail without bytes_read
-     ($buffer:expr, ($name:ident $(,$extra:ident)*):$type:ident) => {
+     ($buffer:expr, ($name:ident $(,$extra:ident)*):$type:ty) => {
         let mut temporary_bytes_read = 0;
         $crate::deserialize_parser!($buffer, 0, temporary_bytes_read, ($name $(,$extra)*):$type);
     };
 
     // Expression with tail without bytes_read
-    ($buffer:expr, ($name:ident $(,$extra:ident)*):$type:ident, $($tail:tt)*) => {
+    ($buffer:expr, ($name:ident $(,$extra:ident)*):$type:ty, $($tail:tt)*) => {
         let mut temporary_bytes_read = 0;
         $crate::deserialize_parser!($buffer, 0, temporary_bytes_read, ($name $(,$extra)*):$type, $($tail)*);
     };
 
     // Expression without tail with bytes_read
-    ($buffer:expr, $bytes_read:ident, ($name:ident $(,$extra:ident)*):$type:ident) => {
+    ($buffer:expr, $bytes_read:ident, ($name:ident $(,$extra:ident)*):$type:ty) => {
         // Initialize bytes_read token
         let mut $bytes_read = 0;
         // Send to deserialize_parser
@@ -99,7 +114,7 @@ macro_rules! deserialize {
     };
     
     // Expression with tail with bytes_read
-    ($buffer:expr, $bytes_read:ident, ($name:ident $(,$extra:ident)*):$type:ident, $($tail:tt)*) => {
+    ($buffer:expr, $bytes_read:ident, ($name:ident $(,$extra:ident)*):$type:ty, $($tail:tt)*) => {
         // Initialize bytes_read token
         let mut $bytes_read = 0;
         // Send to deserialize_parser
@@ -111,19 +126,19 @@ macro_rules! deserialize {
     * SLICES * 
     *********/
     // SLICE Without tail without bytes_read
-    ($buffer:expr, [$name:ident $(,$extra:ident)*]:$type:ident) => {
+    ($buffer:expr, [$name:ident $(,$extra:ident)*]:$type:ty) => {
         let mut temporary_bytes_read = 0;
         $crate::deserialize_parser!($buffer, 0, temporary_bytes_read, [$name $(,$extra)*]:$type);
     };
 
     // SLICE With tail without bytes_read
-    ($buffer:expr, [$name:ident $(,$extra:ident)*]:$type:ident, $($tail:tt)*) => {
+    ($buffer:expr, [$name:ident $(,$extra:ident)*]:$type:ty, $($tail:tt)*) => {
         let mut temporary_bytes_read = 0;
         $crate::deserialize_parser!($buffer, 0, temporary_bytes_read, [$name $(,$extra)*]:$type, $($tail)*);
     };
 
     // SLICE Without tail with bytes_read
-    ($buffer:expr, $bytes_read:ident, [$name:ident $(,$extra:ident)*]:$type:ident) => {
+    ($buffer:expr, $bytes_read:ident, [$name:ident $(,$extra:ident)*]:$type:ty) => {
         // Initialize bytes_read token
         let mut $bytes_read = 0;
         // Send to deserialize_parser
@@ -131,13 +146,13 @@ macro_rules! deserialize {
     };
 
     // SLICE With tail with bytes_read
-    ($buffer:expr, $bytes_read:ident, [$name:ident $(,$extra:ident)*]:$type:ident, $($tail:tt)*) => {
+    ($buffer:expr, $bytes_read:ident, [$name:ident $(,$extra:ident)*]:$type:ty, $($tail:tt)*) => {
         // Initialize bytes_read token
         let mut $bytes_read = 0;
         // Send to deserialize_parser
         $crate::deserialize_parser!($buffer, 0, $bytes_read, [$name $(,$extra)*]:$type, $($tail)*);
     };
-    
+
 }
 
 /// Hidden extension of the to_buffer! macro. Parse tokens. Not meant to be used directly (although it will still work).
@@ -147,7 +162,7 @@ macro_rules! deserialize_parser {
     // Macro built with Incremental TT munchers pattern : https://danielkeep.github.io/tlborm/book/pat-incremental-tt-munchers.html
 
     // Expression without tail with bytes_read
-    ($buffer:expr, $index:expr, $bytes_read:expr, ($name:ident $(,$extra:ident)*):$type:ident) => {
+    ($buffer:expr, $index:expr, $bytes_read:expr, ($name:ident $(,$extra:ident)*):$type:ty) => {
         // Get value from buffer into expression
         $crate::deserialize_retriever!($bytes_read, $buffer[$index + $bytes_read..$buffer.len()], $name => $type);
         // Get value from buffer into expression for extra
@@ -155,7 +170,7 @@ macro_rules! deserialize_parser {
     };
 
     // Expression with tail with bytes_read
-    ($buffer:expr, $index:expr, $bytes_read:expr, ($name:ident $(,$extra:ident)*):$type:ident, $($tail:tt)*) => {
+    ($buffer:expr, $index:expr, $bytes_read:expr, ($name:ident $(,$extra:ident)*):$type:ty, $($tail:tt)*) => {
         // Get value from buffer into expression
         $crate::deserialize_retriever!($bytes_read, $buffer[$index + $bytes_read..$buffer.len()], $name => $type);
         // Get value from buffer into expression for extra
@@ -165,7 +180,7 @@ macro_rules! deserialize_parser {
     };
 
     // SLICE Without tail with bytes_read
-    ($buffer:expr, $index:expr, $bytes_read:expr, [$name:ident $(,$extra:ident)*]:$type:ident) => {
+    ($buffer:expr, $index:expr, $bytes_read:expr, [$name:ident $(,$extra:ident)*]:$type:ty) => {
         // Get value from buffer into array
         $crate::deserialize_retriever!($bytes_read, $buffer[$index + $bytes_read..$buffer.len()], $name => [$type]);
         // Get value from buffer into array for extra
@@ -173,7 +188,7 @@ macro_rules! deserialize_parser {
     };
 
     // SLICE With tail with bytes_read
-    ($buffer:expr, $index:expr, $bytes_read:expr, [$name:ident $(,$extra:ident)*]:$type:ident, $($tail:tt)*) => {
+    ($buffer:expr, $index:expr, $bytes_read:expr, [$name:ident $(,$extra:ident)*]:$type:ty, $($tail:tt)*) => {
         // Get value from buffer into array
         $crate::deserialize_retriever!($bytes_read, $buffer[$index + $bytes_read..$buffer.len()], $name => [$type]);
         // Get value from buffer into array for extra
@@ -181,158 +196,26 @@ macro_rules! deserialize_parser {
         // Parse tail
         $crate::deserialize_parser!($buffer, $index, $bytes_read, $($tail)*);
     };
+
 }
 
 /// Hidden extension of the to_buffer! macro. Retrieve value from buffer. Not meant to be used directly (although it will still work).
 #[doc(hidden)]
 #[macro_export]
 macro_rules! deserialize_retriever {
-
+    // Types are dispatched through trait Decode, implemented for implementors of Tampon, borrowed types and markers like BigEndian
 
     // Slice affectator
-    ($bytes_read:expr, $buffer:expr, $name:ident => [$type:ident]) => {
-
-        // Keep bytes size of u32
-        let u32_bs = core::mem::size_of::<u32>();
-
-        // Get size of slice
-        let slice_size = <u32>::from_le_bytes($buffer[0..u32_bs].try_into().expect("Incorrect length!"));
-
-        // Increase $bytes_read by u32 size
-        $bytes_read += u32_bs;
-
-        // Init vector
-        let mut $name:Vec<$type> = Vec::new();
-
-        // Retrieve each slice
-        for i in 0..slice_size {
-
-            // Use index 0 because $buffer[].try_into() consume buffer length
-            $crate::deserialize_retriever!($bytes_read, $buffer, FB_TEMP_VARIABLE => $type);
-            $name.push(FB_TEMP_VARIABLE);   // Push temporary variable into vector
-        }       
-
-    };
-
-
-    /**********
-    * BOOLEAN *
-    **********/
-    ($bytes_read:expr, $buffer:expr, $name:ident => bool) => {
-        // Translate byte into u8
-        let u8val = <u8>::from_le_bytes($buffer[0..core::mem::size_of::<u8>()].try_into().expect("Incorrect length!"));
-
-        // Set bool value according to u8 value
-        let $name = if u8val == 0 {
-            false
-        } else {
-            true
-        };
-
-        // 1 byte was consumed for boolean
-        $bytes_read += core::mem::size_of::<u8>();
-    };
-
-
-    /***********
-    * NUMERICS * 
-    ***********/
-    ($bytes_read:expr, $buffer:expr, $name:ident => u8) => {
-        let $name = <u8>::from_le_bytes($buffer[0..core::mem::size_of::<u8>()].try_into().expect("Incorrect length!"));
-        $bytes_read += core::mem::size_of::<u8>();
-    };
-
-
-    ($bytes_read:expr, $buffer:expr, $name:ident => u16) => {
-        let $name = <u16>::from_le_bytes($buffer[0..core::mem::size_of::<u16>()].try_into().expect("Incorrect length!"));
-        $bytes_read += core::mem::size_of::<u16>();
-    };
-
-
-    ($bytes_read:expr, $buffer:expr, $name:ident => u32) => { 
-        let $name = <u32>::from_le_bytes($buffer[0..core::mem::size_of::<u32>()].try_into().expect("Incorrect length!"));
-        $bytes_read += core::mem::size_of::<u32>();
-    };
-
-
-    ($bytes_read:expr, $buffer:expr, $name:ident => u64) => {
-        let $name = <u64>::from_le_bytes($buffer[0..core::mem::size_of::<u64>()].try_into().expect("Incorrect length!"));
-        $bytes_read += core::mem::size_of::<u64>();
-    };
-
-
-    ($bytes_read:expr, $buffer:expr, $name:ident => u128) => {
-        let $name = <u128>::from_le_bytes($buffer[0..core::mem::size_of::<u128>()].try_into().expect("Incorrect length!"));
-        $bytes_read += core::mem::size_of::<u128>();
-    };
-
-
-    ($bytes_read:expr, $buffer:expr, $name:ident => f32) => {
-        let $name = <f32>::from_le_bytes($buffer[0..core::mem::size_of::<f32>()].try_into().expect("Incorrect length!"));
-        $bytes_read += core::mem::size_of::<f32>();
-    };
-
-    ($bytes_read:expr, $buffer:expr, $name:ident => f64) => {
-        let $name = <f64>::from_le_bytes($buffer[0..core::mem::size_of::<f64>()].try_into().expect("Incorrect length!"));
-        $bytes_read += core::mem::size_of::<f64>();
-    };
-
-
-    ($bytes_read:expr, $buffer:expr, $name:ident => i8) => {
-        let $name = <i8>::from_le_bytes($buffer[0..core::mem::size_of::<i8>()].try_into().expect("Incorrect length!"));
-        $bytes_read += core::mem::size_of::<i8>();
-    };
-
-
-    ($bytes_read:expr, $buffer:expr, $name:ident => i16) => {
-        let $name = <i16>::from_le_bytes($buffer[0..core::mem::size_of::<i16>()].try_into().expect("Incorrect length!"));
-        $bytes_read += core::mem::size_of::<i16>();
-    };
-
-
-    ($bytes_read:expr, $buffer:expr, $name:ident => i32) => {
-        let $name = <i32>::from_le_bytes($buffer[0..core::mem::size_of::<i32>()].try_into().expect("Incorrect length!"));
-        $bytes_read += core::mem::size_of::<i32>();
-    };
-
-
-    ($bytes_read:expr, $buffer:expr, $name:ident => i64) => {
-        let $name = <i64>::from_le_bytes($buffer[0..core::mem::size_of::<i64>()].try_into().expect("Incorrect length!"));
-        $bytes_read += core::mem::size_of::<i64>();
-    };
-
-
-    ($bytes_read:expr, $buffer:expr, $name:ident => i128) => {
-        let $name = <i128>::from_le_bytes($buffer[0..core::mem::size_of::<i128>()].try_into().expect("Incorrect length!"));
-        $bytes_read += core::mem::size_of::<i128>();
-    };
-    /*********
-    * STRING * 
-    *********/
-    ($bytes_read:expr, $buffer:expr, $name:ident => String) => {
-
-        // Keep bytes size of u32
-        let u32_bs = core::mem::size_of::<u32>();
-        
-        // Get size of string to retrieve
-        let string_size = (<u32>::from_le_bytes($buffer[0..u32_bs].try_into().expect("Incorrect length!"))) as usize;
-
-        // Use String::from_utf8 which is SAFE https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf8
-        let $name = String::from_utf8($buffer[u32_bs..u32_bs + string_size].to_vec()).expect("UTF8 String incorrect!"); 
-
-        // Return size used 
-        $bytes_read += u32_bs + string_size;
-
+    ($bytes_read:expr, $buffer:expr, $name:ident => [$type:ty]) => {
+        // Size of slice is read with elements. Vector is allocated once, numerics are copied in bulk.
+        let temp = <$type as $crate::encoding::Decode>::deserialize_slice(&$buffer);
+        let $name = temp.0;
+        $bytes_read += temp.1;
     };
 
-    /***************
-    * TAMPON TRAIT * 
-    ***************/
-    ($bytes_read:expr, $buffer:expr, $name:ident => $tampon:ident) => {
-        let temp = $tampon::deserialize(&$buffer);
+    ($bytes_read:expr, $buffer:expr, $name:ident => $type:ty) => {
+        let temp = <$type as $crate::encoding::Decode>::deserialize(&$buffer);
         let $name = temp.0;
         $bytes_read += temp.1;
     };
-
-
 }
\ No newline at end of file
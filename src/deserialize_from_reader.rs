@@ -0,0 +1,157 @@
+/*
+ * @file tampon/deserialize_from_reader.rs
+ *
+ * @module tampon
+ *
+ * @brief Macro used to deserialize values from a Read without reading past them.
+ *
+ * @details
+ * Macro used to deserialize values from a Read through a small internal buffer. Only the bytes of
+ * the values are read, so what follows them is still available in the Read.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ * https://doc.rust-lang.org/std/io/trait.Read.html
+ *
+ * @todo
+ */
+
+/// ##### Variadic macro used to [`deserialize`](https://en.wikipedia.org/wiki/Serialization) [`compatible variables`](macro.deserialize_from_reader.html#compatible-variabless) from a [`Read`](std::io::Read).
+///
+/// # Description
+/// Streaming counterpart of [`try_deserialize!`](macro.try_deserialize.html). Bytes are read from `reader` as values need them,
+/// so reading a big snapshot from a file doesn't need the entire snapshot in memory. Nothing after the last value is read,
+/// which allows reading several messages one after the other from the same `reader`. Hand-written implementors of
+/// [`Tampon`](trait.Tampon.html) are the exception and may read bytes already available past them, see
+/// [`Tampon::deserialize_from_reader`](trait.Tampon.html#method.deserialize_from_reader).
+///
+/// Since the remaining bytes of `reader` are unknown, length prefixes can only be verified against the optional
+/// [`Limits`](struct.Limits.html). Memory allocated still grows with the bytes really read, so a corrupted length prefix
+/// returns an error when `reader` ends instead of allocating it.
+///
+/// <b>deserialize_from_reader! automatically creates variables when retrieving data.</b> Like the [`?`](https://doc.rust-lang.org/reference/expressions/operator-expr.html#the-question-mark-operator) operator,
+/// it returns `Err` early from the enclosing function, which must return a [`Result`] with an error type implementing `From<tampon::Error>`.
+///
+/// # Usage
+/// `deserialize_from_reader!(reader, [bytes_read,] [limits = limits,] [0..n](v1, ..., vn):type, [0..n][s1, ..., sn]:type);`
+/// * `reader` - Mutable reference to implementor of [`Read`](std::io::Read) like [`File`](std::fs::File), [`TcpStream`](std::net::TcpStream) or `&[u8]`.
+/// * `bytes_read` - (Optional) Identifier here can be used to get the count of bytes read from reader.
+/// * `limits = limits` - (Optional) Reference to [`Limits`](struct.Limits.html) bounding slices, strings and total allocation. Passed to nested implementors of [`Tampon`](trait.Tampon.html).
+/// * One-to-many `(v1, ..., vn):type` where elements in `parenthesis()` are the variables to be read.
+/// * One-to-many `[s1, ..., sn]:type` where elements in `brackets[]` are the slices to be read.
+///
+/// # Example(s)
+/// ```
+/// // Import macro deserialize_from_reader, serialize_to_writer and Error
+/// use tampon::{ deserialize_from_reader, serialize_to_writer, Error };
+///
+/// fn read_packet(reader : &mut impl std::io::Read) -> Result<(u16, String, usize), Error> {
+///     // Deserialize data from reader. (variable are created during deserialization process)
+///     deserialize_from_reader!(reader, bytes_read, (a):u16, (b):String);
+///     Ok((a, b, bytes_read))
+/// }
+///
+/// // Two packets written one after the other
+/// let mut writer:Vec<u8> = Vec::new();
+/// serialize_to_writer!(&mut writer, (4554u16):u16, (String::from("Test")):String, (17u16):u16, (String::from("Next")):String).unwrap();
+///
+/// // Each call reads one packet only
+/// let mut reader = &writer[..];
+/// assert_eq!(read_packet(&mut reader), Ok((4554, String::from("Test"), 10)));
+/// assert_eq!(read_packet(&mut reader), Ok((17, String::from("Next"), 10)));
+///
+/// // Truncated packet returns an error instead of panicking
+/// assert_eq!(read_packet(&mut &writer[..5]), Err(Error::UnexpectedEnd { offset: 2, needed: 4, available: 3 }));
+/// ```
+///
+/// # Compatible variables(s)
+/// * Same as [`try_deserialize!`](macro.try_deserialize.html#compatible-variabless), except [`&str`](str), `&[u8]`, `Cow<str>` and `Cow<[u8]>`
+///   which borrow the buffer and can't borrow a reader.
+/// * Hand-written implementors of [`Tampon`](trait.Tampon.html) must return true from [`Tampon::is_fallible`](trait.Tampon.html#method.is_fallible).
+///
+/// # Error(s)
+/// * [`Error::Io`](enum.Error.html#variant.Io) if `reader` fails, with the count of bytes read before.
+/// * Same as [`try_deserialize!`](macro.try_deserialize.html#errors), offset from the start of the stream.
+///   [`Error::UnexpectedEnd`](enum.Error.html#variant.UnexpectedEnd) and [`Error::InvalidLength`](enum.Error.html#variant.InvalidLength) are returned when `reader` ends.
+/// * [`Error::InvalidValue`](enum.Error.html#variant.InvalidValue) if an implementor of [`Tampon`](trait.Tampon.html) isn't [`fallible`](trait.Tampon.html#method.is_fallible).
+#[macro_export]
+macro_rules! deserialize_from_reader {
+
+    // With bytes_read and limits
+    ($reader:expr, $bytes_read:ident, limits = $limits:expr, $($tail:tt)+) => {
+        let reader:&mut dyn std::io::Read = $reader;
+        let limits:&$crate::Limits = $limits;
+        let mut stream = $crate::stream::StreamReader::new(reader);
+        // Allocations of all targets are counted against the budget of stream
+        $crate::deserialize_from_reader_parser!(stream, limits, $($tail)+);
+        // Count of bytes read token
+        let $bytes_read:usize = stream.bytes_read();
+    };
+
+    // With limits
+    ($reader:expr, limits = $limits:expr, $($tail:tt)+) => {
+        let reader:&mut dyn std::io::Read = $reader;
+        let limits:&$crate::Limits = $limits;
+        let mut stream = $crate::stream::StreamReader::new(reader);
+        $crate::deserialize_from_reader_parser!(stream, limits, $($tail)+);
+    };
+
+    // With bytes_read
+    ($reader:expr, $bytes_read:ident, $($tail:tt)+) => {
+        let reader:&mut dyn std::io::Read = $reader;
+        let limits = $crate::Limits::default();
+        let mut stream = $crate::stream::StreamReader::new(reader);
+        $crate::deserialize_from_reader_parser!(stream, &limits, $($tail)+);
+        let $bytes_read:usize = stream.bytes_read();
+    };
+
+    // Without bytes_read
+    ($reader:expr, $($tail:tt)+) => {
+        let reader:&mut dyn std::io::Read = $reader;
+        let limits = $crate::Limits::default();
+        let mut stream = $crate::stream::StreamReader::new(reader);
+        $crate::deserialize_from_reader_parser!(stream, &limits, $($tail)+);
+    };
+}
+
+/// Hidden extension of the deserialize_from_reader! macro. Parse tokens. Not meant to be used directly (although it will still work).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! deserialize_from_reader_parser {
+    // Macro built with Incremental TT munchers pattern : https://danielkeep.github.io/tlborm/book/pat-incremental-tt-munchers.html
+    // Types are dispatched through trait DecodeStream, implemented for implementors of Tampon and markers like BigEndian
+
+    // Expression without tail
+    ($stream:expr, $limits:expr, ($name:ident $(,$extra:ident)*):$type:ty) => {
+        let $name = $stream.read::<$type>($limits)?;
+        $( let $extra = $stream.read::<$type>($limits)?; )*
+    };
+
+    // Expression with tail
+    ($stream:expr, $limits:expr, ($name:ident $(,$extra:ident)*):$type:ty, $($tail:tt)*) => {
+        let $name = $stream.read::<$type>($limits)?;
+        $( let $extra = $stream.read::<$type>($limits)?; )*
+        $crate::deserialize_from_reader_parser!($stream, $limits, $($tail)*);
+    };
+
+    // SLICE Without tail
+    ($stream:expr, $limits:expr, [$name:ident $(,$extra:ident)*]:$type:ty) => {
+        let $name = $stream.read_slice::<$type>($limits)?;
+        $( let $extra = $stream.read_slice::<$type>($limits)?; )*
+    };
+
+    // SLICE With tail
+    ($stream:expr, $limits:expr, [$name:ident $(,$extra:ident)*]:$type:ty, $($tail:tt)*) => {
+        let $name = $stream.read_slice::<$type>($limits)?;
+        $( let $extra = $stream.read_slice::<$type>($limits)?; )*
+        $crate::deserialize_from_reader_parser!($stream, $limits, $($tail)*);
+    };
+
+}
@@ -0,0 +1,384 @@
+/*
+ * @file tampon/encoding.rs
+ *
+ * @module tampon
+ *
+ * @brief Hidden traits used by macros to dispatch types to their encoding.
+ *
+ * @details
+ * Hidden traits used by macros to dispatch types to their encoding. Types of macros are either
+ * implementors of Tampon and TamponBorrow, encoded as themselves, or markers like BigEndian<T>,
+ * Prefixed<P, T>, Varint<T> and Packed<T> giving another encoding to values of type T.
+ *
+ * Slice functions include the length prefix, so markers can choose how it is written.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use core::borrow::Borrow;
+use core::marker::PhantomData;
+use crate::{ Tampon, TamponBorrow, Error, Limits, Budget, LengthPrefix, SLICE_SIZE_IN_BYTES };
+use crate::error::read_bytes;
+use crate::tampon::slice_capacity;
+use crate::stream::StreamWriter;
+
+/// Hidden trait used by bytes_size!, serialize! and try_serialize! to write values of type `Value`.
+/// Not meant to be used directly.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be serialized by tampon",
+    label = "`{Self}` doesn't implement trait `Tampon`",
+    note = "compatible types are bool, char, numeric types, String, Option, tuples, arrays, Vec, maps, sets, implementors of trait `Tampon`, BigEndian<T>, Prefixed<P, T>, Varint<T> and Packed<T>"
+)]
+pub trait Encode {
+    /// Type of values written.
+    type Value: ?Sized;
+
+    /// Size in bytes of `value`.
+    fn bytes_size(value : &Self::Value) -> usize;
+
+    /// Write `value` into `buffer` and return the bytes count written.
+    fn serialize(value : &Self::Value, buffer : &mut [u8]) -> usize;
+
+    /// Write `value` into `buffer` without panicking.
+    fn try_serialize(value : &Self::Value, buffer : &mut [u8]) -> Result<usize, Error> {
+        let required = Self::bytes_size(value);
+        if required > buffer.len() {
+            return Err(Error::BufferTooSmall { required, available: buffer.len() });
+        }
+        Ok(Self::serialize(value, buffer))
+    }
+
+    /// Size in bytes of the length prefix of `len` elements.
+    fn len_bytes_size(len : usize) -> usize {
+        let _ = len;
+        SLICE_SIZE_IN_BYTES
+    }
+
+    /// Write the length prefix of `len` elements and return the bytes count written.
+    fn serialize_len(len : usize, buffer : &mut [u8]) -> usize {
+        LengthPrefix::default().serialize(len, buffer)
+    }
+
+    /// Write the length prefix of `len` elements without panicking.
+    fn try_serialize_len(len : usize, buffer : &mut [u8]) -> Result<usize, Error> {
+        LengthPrefix::default().try_serialize(len, buffer)
+    }
+
+    /// Size in bytes of `slice` with its length prefix.
+    fn slice_bytes_size(slice : &[Self::Value]) -> usize where Self::Value: Sized {
+        Self::len_bytes_size(slice.len()) + slice.iter().map(|elem| Self::bytes_size(elem)).sum::<usize>()
+    }
+
+    /// Write length prefix followed by elements of `slice` and return the bytes count written.
+    fn serialize_slice(slice : &[Self::Value], buffer : &mut [u8]) -> usize where Self::Value: Sized {
+        let mut bytes_copied = Self::serialize_len(slice.len(), buffer);
+        for elem in slice.iter() {
+            bytes_copied += Self::serialize(elem, &mut buffer[bytes_copied..]);
+        }
+        bytes_copied
+    }
+
+    /// Write length prefix followed by elements of `slice` without panicking.
+    fn try_serialize_slice(slice : &[Self::Value], buffer : &mut [u8]) -> Result<usize, Error> where Self::Value: Sized {
+        // Verify size before writing anything
+        let required = Self::slice_bytes_size(slice);
+        if required > buffer.len() {
+            return Err(Error::BufferTooSmall { required, available: buffer.len() });
+        }
+
+        // Errors of elements are relative to their own buffer and need to be offset
+        let mut bytes_copied = Self::try_serialize_len(slice.len(), buffer)?;
+        for elem in slice.iter() {
+            let offset = bytes_copied;
+            bytes_copied += Self::try_serialize(elem, &mut buffer[offset..]).map_err(|err| err.offset_by(offset))?;
+        }
+        Ok(bytes_copied)
+    }
+
+    /// Write `value` into `stream`.
+    fn serialize_stream(value : &Self::Value, stream : &mut StreamWriter<'_>) -> Result<(), Error> {
+        stream.write_with(Self::bytes_size(value), |buffer| Self::try_serialize(value, buffer))
+    }
+
+    /// Write length prefix followed by elements of `slice` into `stream`.
+    fn serialize_slice_stream(slice : &[Self::Value], stream : &mut StreamWriter<'_>) -> Result<(), Error> where Self::Value: Sized {
+        stream.write_with(Self::slice_bytes_size(slice), |buffer| Self::try_serialize_slice(slice, buffer))
+    }
+}
+
+/// Hidden trait used by deserialize! and try_deserialize! to read values of type `Value` from a buffer of lifetime `'a`.
+/// Not meant to be used directly.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be deserialized by tampon",
+    label = "`{Self}` doesn't implement trait `Tampon` or `TamponBorrow`",
+    note = "compatible types are implementors of trait `Tampon`, borrowed types &str, &[u8], Cow<str> and Cow<[u8]>, BigEndian<T>, Prefixed<P, T>, Varint<T> and Packed<T>"
+)]
+pub trait Decode<'a> {
+    /// Type of values read.
+    type Value;
+
+    /// Read a value from `buffer` and return it with the bytes count read.
+    fn deserialize(buffer : &'a [u8]) -> (Self::Value, usize);
+
+    /// Read a value from `buffer` without panicking while enforcing `limits`, counting allocations in `budget`.
+    fn try_deserialize_with(buffer : &'a [u8], limits : &Limits, budget : &mut Budget) -> Result<(Self::Value, usize), Error>;
+
+    /// Minimum size in bytes of a value in buffer.
+    fn min_bytes_size() -> usize {
+        0
+    }
+
+    /// Read a length prefix and return it with the bytes count read.
+    fn deserialize_len(buffer : &[u8]) -> (usize, usize) {
+        (<u32>::from_le_bytes(buffer[0..SLICE_SIZE_IN_BYTES].try_into().expect("Incorrect length!")) as usize, SLICE_SIZE_IN_BYTES)
+    }
+
+    /// Read a length prefix without panicking.
+    fn try_deserialize_len(buffer : &[u8]) -> Result<(usize, usize), Error> {
+        Ok((<u32>::from_le_bytes(read_bytes(buffer, 0)?) as usize, SLICE_SIZE_IN_BYTES))
+    }
+
+    /// Read length prefix followed by elements into a vector allocated once.
+    fn deserialize_slice(buffer : &'a [u8]) -> (Vec<Self::Value>, usize) {
+        let (slice_size, mut bytes_read) = Self::deserialize_len(buffer);
+
+        let mut vec = Vec::with_capacity(slice_capacity(slice_size, Self::min_bytes_size(), buffer.len() - bytes_read));
+        for _ in 0..slice_size {
+            let (elem, size) = Self::deserialize(&buffer[bytes_read..]);
+            vec.push(elem);
+            bytes_read += size;
+        }
+        (vec, bytes_read)
+    }
+
+    /// Read length prefix followed by elements without panicking while enforcing `limits`, counting allocations in `budget`.
+    fn try_deserialize_slice(buffer : &'a [u8], limits : &Limits, budget : &mut Budget) -> Result<(Vec<Self::Value>, usize), Error> {
+        let (slice_size, mut bytes_read) = Self::try_deserialize_len(buffer)?;
+
+        // Validate length prefix against limits and remaining bytes before allocating
+        let available = buffer.len() - bytes_read;
+        limits.check_slice(0, slice_size, Self::min_bytes_size(), core::mem::size_of::<Self::Value>(), available, budget)?;
+
+        // Retrieve each element. Error will be returned as soon as buffer ends.
+        let mut vec = Vec::with_capacity(slice_capacity(slice_size, Self::min_bytes_size(), available));
+        for _ in 0..slice_size {
+            let offset = bytes_read;
+            let (elem, size) = Self::try_deserialize_with(&buffer[offset..], limits, budget).map_err(|err| err.offset_by(offset))?;
+            vec.push(elem);
+            bytes_read += size;
+        }
+        Ok((vec, bytes_read))
+    }
+}
+
+/// Implementors of Tampon are written as themselves.
+impl<T: Tampon + ?Sized> Encode for T {
+    type Value = T;
+
+    #[inline]
+    fn bytes_size(value : &T) -> usize {
+        value.bytes_size()
+    }
+
+    #[inline]
+    fn serialize(value : &T, buffer : &mut [u8]) -> usize {
+        value.serialize(buffer)
+    }
+
+    #[inline]
+    fn try_serialize(value : &T, buffer : &mut [u8]) -> Result<usize, Error> {
+        value.try_serialize(buffer)
+    }
+
+    #[inline]
+    fn slice_bytes_size(slice : &[T]) -> usize where T: Sized {
+        slice.bytes_size()
+    }
+
+    #[inline]
+    fn serialize_slice(slice : &[T], buffer : &mut [u8]) -> usize where T: Sized {
+        slice.serialize(buffer)
+    }
+
+    #[inline]
+    fn try_serialize_slice(slice : &[T], buffer : &mut [u8]) -> Result<usize, Error> where T: Sized {
+        slice.try_serialize(buffer)
+    }
+
+    #[inline]
+    fn serialize_stream(value : &T, stream : &mut StreamWriter<'_>) -> Result<(), Error> {
+        value.serialize_stream(stream)
+    }
+
+    #[inline]
+    fn serialize_slice_stream(slice : &[T], stream : &mut StreamWriter<'_>) -> Result<(), Error> where T: Sized {
+        slice.serialize_stream(stream)
+    }
+}
+
+/// Hidden wrapper used by bytes_size!, serialize!, try_serialize! and serialize_to_writer! to write a slice of elements `E`
+/// as values of `M`. Not meant to be used directly.
+///
+/// Slices of values are written with the slice functions of [`Encode`], so numerics are copied in bulk. Slices of
+/// elements borrowing values, like `Vec<&str>` written as `[v]:str`, are written one element after the other.
+#[doc(hidden)]
+pub struct Elements<'s, M: ?Sized, E> {
+    /// Elements to write.
+    slice : &'s [E],
+
+    /// Encoding of elements.
+    marker : PhantomData<fn(&M)>,
+}
+
+impl<'s, M: ?Sized, E> Elements<'s, M, E> {
+    /// Wrap `slice` to write it as values of `M`.
+    #[inline]
+    pub fn new(slice : &'s [E]) -> Self {
+        Elements { slice, marker: PhantomData }
+    }
+}
+
+/// Hidden trait writing slices of values with the slice functions of [`Encode`]. Not meant to be used directly.
+///
+/// Implemented for `Elements` itself so that it is selected before [`EncodeBorrowed`] by method calls on `&Elements`.
+#[doc(hidden)]
+pub trait EncodeValues {
+    /// Size in bytes of slice with its length prefix.
+    fn slice_bytes_size(&self) -> usize;
+
+    /// Write length prefix followed by elements of slice.
+    fn serialize_slice(&self, buffer : &mut [u8]) -> usize;
+
+    /// Write length prefix followed by elements of slice without panicking.
+    fn try_serialize_slice(&self, buffer : &mut [u8]) -> Result<usize, Error>;
+
+    /// Write length prefix followed by elements of slice into `stream`.
+    fn serialize_slice_stream(&self, stream : &mut StreamWriter<'_>) -> Result<(), Error>;
+}
+
+impl<M: Encode + ?Sized> EncodeValues for Elements<'_, M, M::Value> where M::Value: Sized {
+    #[inline]
+    fn slice_bytes_size(&self) -> usize {
+        M::slice_bytes_size(self.slice)
+    }
+
+    #[inline]
+    fn serialize_slice(&self, buffer : &mut [u8]) -> usize {
+        M::serialize_slice(self.slice, buffer)
+    }
+
+    #[inline]
+    fn try_serialize_slice(&self, buffer : &mut [u8]) -> Result<usize, Error> {
+        M::try_serialize_slice(self.slice, buffer)
+    }
+
+    #[inline]
+    fn serialize_slice_stream(&self, stream : &mut StreamWriter<'_>) -> Result<(), Error> {
+        M::serialize_slice_stream(self.slice, stream)
+    }
+}
+
+/// Hidden trait writing slices of elements borrowing values, including values that aren't [`Sized`] like [`str`].
+/// Not meant to be used directly.
+#[doc(hidden)]
+pub trait EncodeBorrowed {
+    /// Size in bytes of slice with its length prefix.
+    fn slice_bytes_size(&self) -> usize;
+
+    /// Write length prefix followed by elements of slice.
+    fn serialize_slice(&self, buffer : &mut [u8]) -> usize;
+
+    /// Write length prefix followed by elements of slice without panicking.
+    fn try_serialize_slice(&self, buffer : &mut [u8]) -> Result<usize, Error>;
+
+    /// Write length prefix followed by elements of slice into `stream`.
+    fn serialize_slice_stream(&self, stream : &mut StreamWriter<'_>) -> Result<(), Error>;
+}
+
+impl<M: Encode + ?Sized, E: Borrow<M::Value>> EncodeBorrowed for &Elements<'_, M, E> {
+    fn slice_bytes_size(&self) -> usize {
+        M::len_bytes_size(self.slice.len()) + self.slice.iter().map(|elem| M::bytes_size(elem.borrow())).sum::<usize>()
+    }
+
+    fn serialize_slice(&self, buffer : &mut [u8]) -> usize {
+        let mut bytes_copied = M::serialize_len(self.slice.len(), buffer);
+        for elem in self.slice.iter() {
+            bytes_copied += M::serialize(elem.borrow(), &mut buffer[bytes_copied..]);
+        }
+        bytes_copied
+    }
+
+    fn try_serialize_slice(&self, buffer : &mut [u8]) -> Result<usize, Error> {
+        // Verify size before writing anything
+        let required = self.slice_bytes_size();
+        if required > buffer.len() {
+            return Err(Error::BufferTooSmall { required, available: buffer.len() });
+        }
+
+        // Errors of elements are relative to their own buffer and need to be offset
+        let mut bytes_copied = M::try_serialize_len(self.slice.len(), buffer)?;
+        for elem in self.slice.iter() {
+            let offset = bytes_copied;
+            bytes_copied += M::try_serialize(elem.borrow(), &mut buffer[offset..]).map_err(|err| err.offset_by(offset))?;
+        }
+        Ok(bytes_copied)
+    }
+
+    fn serialize_slice_stream(&self, stream : &mut StreamWriter<'_>) -> Result<(), Error> {
+        let len = self.slice.len();
+        stream.write_with(M::len_bytes_size(len), |buffer| M::try_serialize_len(len, buffer))?;
+        for elem in self.slice.iter() {
+            M::serialize_stream(elem.borrow(), stream)?;
+        }
+        Ok(())
+    }
+}
+
+/// Implementors of TamponBorrow are read as themselves.
+impl<'a, T: TamponBorrow<'a>> Decode<'a> for T {
+    type Value = T;
+
+    #[inline]
+    fn deserialize(buffer : &'a [u8]) -> (T, usize) {
+        T::deserialize_borrowed(buffer)
+    }
+
+    #[inline]
+    fn try_deserialize_with(buffer : &'a [u8], limits : &Limits, budget : &mut Budget) -> Result<(T, usize), Error> {
+        T::try_deserialize_borrowed_with(buffer, limits, budget)
+    }
+
+    #[inline]
+    fn min_bytes_size() -> usize {
+        <T as TamponBorrow>::min_bytes_size()
+    }
+
+    fn deserialize_slice(buffer : &'a [u8]) -> (Vec<T>, usize) {
+        // Numerics are copied in bulk
+        let (slice_size, size) = Self::deserialize_len(buffer);
+        let (vec, bytes_read) = T::deserialize_borrowed_slice(&buffer[size..], slice_size, LengthPrefix::default());
+        (vec, size + bytes_read)
+    }
+
+    fn try_deserialize_slice(buffer : &'a [u8], limits : &Limits, budget : &mut Budget) -> Result<(Vec<T>, usize), Error> {
+        let (slice_size, size) = Self::try_deserialize_len(buffer)?;
+
+        // Validate length prefix against limits and remaining bytes before allocating
+        limits.check_slice(0, slice_size, <T as TamponBorrow>::min_bytes_size(), core::mem::size_of::<T>(), buffer.len() - size, budget)?;
+
+        // Errors of elements are relative to the buffer of elements and need to be offset
+        let (vec, bytes_read) = T::try_deserialize_borrowed_slice(&buffer[size..], slice_size, limits, budget, LengthPrefix::default())
+            .map_err(|err| err.offset_by(size))?;
+        Ok((vec, size + bytes_read))
+    }
+}
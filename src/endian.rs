@@ -0,0 +1,526 @@
+/*
+ * @file tampon/endian.rs
+ *
+ * @module tampon
+ *
+ * @brief Contain BigEndian marker writing numerics and length prefixes as big endian.
+ *
+ * @details
+ * Contain BigEndian marker writing numerics and length prefixes as big endian, also called network
+ * byte order. BigEndian<T> is used as type of macros fields and reads and writes values of type T,
+ * so variables don't need to be wrapped.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ * https://en.wikipedia.org/wiki/Endianness
+ *
+ * @todo
+ */
+
+use core::marker::PhantomData;
+
+use crate::{ Error, Limits, Budget, LengthPrefix, SLICE_SIZE_IN_BYTES };
+use crate::encoding::{ Encode, Decode };
+use crate::error::read_bytes;
+
+/// ##### Marker used as type of macros fields to write values of type `T` as [`big endian`](https://en.wikipedia.org/wiki/Endianness).
+///
+/// Network protocols and many file formats use big endian, also called network byte order. `BigEndian<T>` writes
+/// values of type `T` with their most significant byte first, including the length prefix of slices and strings.
+/// Variables keep their type, only the field type of macros changes. Aliases like [`u16be`] are given for numerics.
+///
+/// `BigEndian<T>` is implemented for numeric types, [`usize`], [`isize`], [`char`], [`String`], [`str`], [`&str`](str)
+/// and `Vec<T>` of those. It is never constructed.
+///
+/// # Example(s)
+/// ```
+/// use tampon::{ BigEndian, u16be, u32be, buffer, deserialize };
+///
+/// let port:u16 = 8080;
+/// let address:u32 = 0x7F000001;
+/// let host = String::from("localhost");
+/// let ports:Vec<u16> = vec![80, 443];
+///
+/// let buffer = buffer!((port):u16be, (address):u32be, (host):BigEndian<String>, [ports]:u16be);
+/// assert_eq!(buffer[0..6], [0x1F, 0x90, 0x7F, 0, 0, 1]);
+/// assert_eq!(buffer[6..10], [0, 0, 0, 9]);
+///
+/// deserialize!(buffer, (a):u16be, (b):u32be, (c):BigEndian<&str>, [d]:u16be);
+/// assert_eq!((a, b, c, d), (port, address, "localhost", ports));
+/// ```
+pub struct BigEndian<T: ?Sized>(PhantomData<T>);
+
+/**********
+* HELPERS *
+**********/
+/// Write length prefix of `len` elements as big endian.
+///
+/// # Panic(s)
+/// * Will panic! if `buffer` is too small or if `len` doesn't fit in [`u32`].
+#[inline]
+fn serialize_len(len : usize, buffer : &mut [u8]) -> usize {
+    match try_serialize_len(len, buffer) {
+        Ok(bytes_copied) => bytes_copied,
+        Err(err) => panic!("{}", err),
+    }
+}
+
+/// Write length prefix of `len` elements as big endian without panicking.
+fn try_serialize_len(len : usize, buffer : &mut [u8]) -> Result<usize, Error> {
+    let bytes_len = match u32::try_from(len) {
+        Ok(len) => len.to_be_bytes(),
+        Err(_) => return Err(Error::OutOfRange { offset: 0, value: len as i128 }),
+    };
+
+    if bytes_len.len() > buffer.len() {
+        return Err(Error::BufferTooSmall { required: bytes_len.len(), available: buffer.len() });
+    }
+
+    buffer[0..bytes_len.len()].copy_from_slice(&bytes_len);
+    Ok(bytes_len.len())
+}
+
+/// Read length prefix written as big endian.
+#[inline]
+fn deserialize_len(buffer : &[u8]) -> (usize, usize) {
+    (<u32>::from_be_bytes(buffer[0..SLICE_SIZE_IN_BYTES].try_into().expect("Incorrect length!")) as usize, SLICE_SIZE_IN_BYTES)
+}
+
+/// Read length prefix written as big endian without panicking.
+#[inline]
+fn try_deserialize_len(buffer : &[u8]) -> Result<(usize, usize), Error> {
+    Ok((<u32>::from_be_bytes(read_bytes(buffer, 0)?) as usize, SLICE_SIZE_IN_BYTES))
+}
+
+/// Implement length prefix functions of Encode and Decode as big endian.
+macro_rules! big_endian_len {
+    (Encode) => {
+        #[inline]
+        fn serialize_len(len : usize, buffer : &mut [u8]) -> usize {
+            serialize_len(len, buffer)
+        }
+
+        #[inline]
+        fn try_serialize_len(len : usize, buffer : &mut [u8]) -> Result<usize, Error> {
+            try_serialize_len(len, buffer)
+        }
+    };
+    (Decode) => {
+        #[inline]
+        fn deserialize_len(buffer : &[u8]) -> (usize, usize) {
+            deserialize_len(buffer)
+        }
+
+        #[inline]
+        fn try_deserialize_len(buffer : &[u8]) -> Result<(usize, usize), Error> {
+            try_deserialize_len(buffer)
+        }
+    };
+}
+
+/***********
+* NUMERICS *
+***********/
+/// Implement BigEndian for numeric types, slices are copied in bulk.
+macro_rules! big_endian_numeric {
+    ($($type:ident),*) => {
+        $(
+            impl Encode for BigEndian<$type> {
+                type Value = $type;
+
+                #[inline]
+                fn bytes_size(_value : &$type) -> usize {
+                    core::mem::size_of::<$type>()
+                }
+
+                #[inline]
+                fn serialize(value : &$type, buffer : &mut [u8]) -> usize {
+                    let bytes = value.to_be_bytes();
+                    buffer[0..bytes.len()].copy_from_slice(&bytes);
+                    bytes.len()
+                }
+
+                big_endian_len!(Encode);
+
+                #[inline]
+                fn slice_bytes_size(slice : &[$type]) -> usize {
+                    SLICE_SIZE_IN_BYTES + slice.len() * core::mem::size_of::<$type>()
+                }
+
+                fn serialize_slice(slice : &[$type], buffer : &mut [u8]) -> usize {
+                    let size = core::mem::size_of::<$type>();
+                    let bytes_len = serialize_len(slice.len(), buffer);
+                    for (chunk, value) in buffer[bytes_len..bytes_len + slice.len() * size].chunks_exact_mut(size).zip(slice.iter()) {
+                        chunk.copy_from_slice(&value.to_be_bytes());
+                    }
+                    bytes_len + slice.len() * size
+                }
+
+                fn try_serialize_slice(slice : &[$type], buffer : &mut [u8]) -> Result<usize, Error> {
+                    let required = Self::slice_bytes_size(slice);
+                    if required > buffer.len() {
+                        return Err(Error::BufferTooSmall { required, available: buffer.len() });
+                    }
+
+                    // Length prefix is verified before elements are written
+                    LengthPrefix::default().check(slice.len())?;
+                    Ok(Self::serialize_slice(slice, buffer))
+                }
+            }
+
+            impl<'a> Decode<'a> for BigEndian<$type> {
+                type Value = $type;
+
+                #[inline]
+                fn deserialize(buffer : &'a [u8]) -> ($type, usize) {
+                    let size = core::mem::size_of::<$type>();
+                    (<$type>::from_be_bytes(buffer[0..size].try_into().expect("Incorrect length!")), size)
+                }
+
+                #[inline]
+                fn try_deserialize_with(buffer : &'a [u8], _limits : &Limits, _budget : &mut Budget) -> Result<($type, usize), Error> {
+                    Ok((<$type>::from_be_bytes(read_bytes(buffer, 0)?), core::mem::size_of::<$type>()))
+                }
+
+                #[inline]
+                fn min_bytes_size() -> usize {
+                    core::mem::size_of::<$type>()
+                }
+
+                big_endian_len!(Decode);
+
+                fn deserialize_slice(buffer : &'a [u8]) -> (Vec<$type>, usize) {
+                    // Vector is allocated once from the exact size iterator
+                    let size = core::mem::size_of::<$type>();
+                    let (slice_size, bytes_len) = deserialize_len(buffer);
+                    let bytes_size = slice_size.saturating_mul(size);
+                    let vec = buffer[bytes_len..bytes_len + bytes_size].chunks_exact(size)
+                        .map(|chunk| <$type>::from_be_bytes(chunk.try_into().expect("Incorrect length!"))).collect();
+                    (vec, bytes_len + bytes_size)
+                }
+
+                fn try_deserialize_slice(buffer : &'a [u8], limits : &Limits, budget : &mut Budget) -> Result<(Vec<$type>, usize), Error> {
+                    let size = core::mem::size_of::<$type>();
+                    let (slice_size, bytes_len) = try_deserialize_len(buffer)?;
+
+                    // Validate length prefix against limits and remaining bytes before copy
+                    limits.check_slice(0, slice_size, size, size, buffer.len() - bytes_len, budget)?;
+                    Ok(Self::deserialize_slice(buffer))
+                }
+            }
+        )*
+    };
+}
+
+big_endian_numeric!(u8, u16, u32, u64, u128, f32, f64, i8, i16, i32, i64, i128);
+
+/**************************
+* POINTER-SIZED INTEGERS *
+**************************/
+/// Implement BigEndian for pointer-sized integers written as a fixed width type.
+macro_rules! big_endian_pointer_sized {
+    ($($type:ident as $fixed:ident),*) => {
+        $(
+            impl Encode for BigEndian<$type> {
+                type Value = $type;
+
+                #[inline]
+                fn bytes_size(_value : &$type) -> usize {
+                    core::mem::size_of::<$fixed>()
+                }
+
+                #[inline]
+                fn serialize(value : &$type, buffer : &mut [u8]) -> usize {
+                    <BigEndian<$fixed> as Encode>::serialize(&(*value as $fixed), buffer)
+                }
+
+                big_endian_len!(Encode);
+            }
+
+            impl<'a> Decode<'a> for BigEndian<$type> {
+                type Value = $type;
+
+                #[inline]
+                fn deserialize(buffer : &'a [u8]) -> ($type, usize) {
+                    let (value, bytes_read) = <BigEndian<$fixed> as Decode>::deserialize(buffer);
+                    match <$type>::try_from(value) {
+                        Ok(value) => (value, bytes_read),
+                        Err(_) => panic!("{}", Error::OutOfRange { offset: 0, value: value as i128 }),
+                    }
+                }
+
+                #[inline]
+                fn try_deserialize_with(buffer : &'a [u8], limits : &Limits, budget : &mut Budget) -> Result<($type, usize), Error> {
+                    let (value, bytes_read) = <BigEndian<$fixed> as Decode>::try_deserialize_with(buffer, limits, budget)?;
+                    match <$type>::try_from(value) {
+                        Ok(value) => Ok((value, bytes_read)),
+                        Err(_) => Err(Error::OutOfRange { offset: 0, value: value as i128 }),
+                    }
+                }
+
+                #[inline]
+                fn min_bytes_size() -> usize {
+                    core::mem::size_of::<$fixed>()
+                }
+
+                big_endian_len!(Decode);
+            }
+        )*
+    };
+}
+
+big_endian_pointer_sized!(usize as u64, isize as i64);
+
+/*******
+* CHAR *
+*******/
+impl Encode for BigEndian<char> {
+    type Value = char;
+
+    #[inline]
+    fn bytes_size(_value : &char) -> usize {
+        core::mem::size_of::<u32>()
+    }
+
+    #[inline]
+    fn serialize(value : &char, buffer : &mut [u8]) -> usize {
+        <BigEndian<u32> as Encode>::serialize(&(*value as u32), buffer)
+    }
+
+    big_endian_len!(Encode);
+}
+
+impl<'a> Decode<'a> for BigEndian<char> {
+    type Value = char;
+
+    #[inline]
+    fn deserialize(buffer : &'a [u8]) -> (char, usize) {
+        // Surrogates and values over char::MAX are rejected
+        let (value, bytes_read) = <BigEndian<u32> as Decode>::deserialize(buffer);
+        match char::from_u32(value) {
+            Some(value) => (value, bytes_read),
+            None => panic!("{}", Error::OutOfRange { offset: 0, value: value as i128 }),
+        }
+    }
+
+    #[inline]
+    fn try_deserialize_with(buffer : &'a [u8], limits : &Limits, budget : &mut Budget) -> Result<(char, usize), Error> {
+        let (value, bytes_read) = <BigEndian<u32> as Decode>::try_deserialize_with(buffer, limits, budget)?;
+        match char::from_u32(value) {
+            Some(value) => Ok((value, bytes_read)),
+            None => Err(Error::OutOfRange { offset: 0, value: value as i128 }),
+        }
+    }
+
+    #[inline]
+    fn min_bytes_size() -> usize {
+        core::mem::size_of::<u32>()
+    }
+
+    big_endian_len!(Decode);
+}
+
+/******
+* STR *
+******/
+/// str is written like String with a big endian length prefix.
+impl Encode for BigEndian<str> {
+    type Value = str;
+
+    #[inline]
+    fn bytes_size(value : &str) -> usize {
+        SLICE_SIZE_IN_BYTES + value.len()
+    }
+
+    fn serialize(value : &str, buffer : &mut [u8]) -> usize {
+        let bytes_len = serialize_len(value.len(), buffer);
+        buffer[bytes_len..bytes_len + value.len()].copy_from_slice(value.as_bytes());
+        bytes_len + value.len()
+    }
+
+    fn try_serialize(value : &str, buffer : &mut [u8]) -> Result<usize, Error> {
+        let required = Self::bytes_size(value);
+        if required > buffer.len() {
+            return Err(Error::BufferTooSmall { required, available: buffer.len() });
+        }
+
+        let bytes_len = try_serialize_len(value.len(), buffer)?;
+        buffer[bytes_len..bytes_len + value.len()].copy_from_slice(value.as_bytes());
+        Ok(bytes_len + value.len())
+    }
+
+    big_endian_len!(Encode);
+}
+
+/// &str is read like String with a big endian length prefix and borrowed from buffer.
+impl<'a> Decode<'a> for BigEndian<&'a str> {
+    type Value = &'a str;
+
+    fn deserialize(buffer : &'a [u8]) -> (&'a str, usize) {
+        let (string_size, bytes_len) = deserialize_len(buffer);
+        let string = core::str::from_utf8(&buffer[bytes_len..bytes_len + string_size]).expect("UTF8 String incorrect!");
+        (string, bytes_len + string_size)
+    }
+
+    fn try_deserialize_with(buffer : &'a [u8], limits : &Limits, _budget : &mut Budget) -> Result<(&'a str, usize), Error> {
+        let (string_size, bytes_len) = try_deserialize_len(buffer)?;
+
+        // Validate length prefix against limits and remaining bytes of buffer, nothing is allocated
+        limits.check_str(0, string_size, buffer.len() - bytes_len)?;
+
+        match core::str::from_utf8(&buffer[bytes_len..bytes_len + string_size]) {
+            Ok(string) => Ok((string, bytes_len + string_size)),
+            Err(err) => Err(Error::InvalidUtf8 { offset: bytes_len + err.valid_up_to() }),
+        }
+    }
+
+    #[inline]
+    fn min_bytes_size() -> usize {
+        SLICE_SIZE_IN_BYTES
+    }
+
+    big_endian_len!(Decode);
+}
+
+/*********
+* STRING *
+*********/
+impl Encode for BigEndian<String> {
+    type Value = String;
+
+    #[inline]
+    fn bytes_size(value : &String) -> usize {
+        <BigEndian<str> as Encode>::bytes_size(value)
+    }
+
+    #[inline]
+    fn serialize(value : &String, buffer : &mut [u8]) -> usize {
+        <BigEndian<str> as Encode>::serialize(value, buffer)
+    }
+
+    #[inline]
+    fn try_serialize(value : &String, buffer : &mut [u8]) -> Result<usize, Error> {
+        <BigEndian<str> as Encode>::try_serialize(value, buffer)
+    }
+
+    big_endian_len!(Encode);
+}
+
+impl<'a> Decode<'a> for BigEndian<String> {
+    type Value = String;
+
+    #[inline]
+    fn deserialize(buffer : &'a [u8]) -> (String, usize) {
+        let (string, bytes_read) = <BigEndian<&str> as Decode>::deserialize(buffer);
+        (String::from(string), bytes_read)
+    }
+
+    #[inline]
+    fn try_deserialize_with(buffer : &'a [u8], limits : &Limits, budget : &mut Budget) -> Result<(String, usize), Error> {
+        let (string, bytes_read) = <BigEndian<&str> as Decode>::try_deserialize_with(buffer, limits, budget)?;
+        Ok((String::from(string), bytes_read))
+    }
+
+    #[inline]
+    fn min_bytes_size() -> usize {
+        SLICE_SIZE_IN_BYTES
+    }
+
+    big_endian_len!(Decode);
+}
+
+/******
+* VEC *
+******/
+/// Vec is written like `[v]:BigEndian<T>` in macros, so nested containers keep big endian.
+impl<T> Encode for BigEndian<Vec<T>> where BigEndian<T>: Encode<Value = T> {
+    type Value = Vec<T>;
+
+    #[inline]
+    fn bytes_size(value : &Vec<T>) -> usize {
+        <BigEndian<T> as Encode>::slice_bytes_size(value)
+    }
+
+    #[inline]
+    fn serialize(value : &Vec<T>, buffer : &mut [u8]) -> usize {
+        <BigEndian<T> as Encode>::serialize_slice(value, buffer)
+    }
+
+    #[inline]
+    fn try_serialize(value : &Vec<T>, buffer : &mut [u8]) -> Result<usize, Error> {
+        <BigEndian<T> as Encode>::try_serialize_slice(value, buffer)
+    }
+
+    big_endian_len!(Encode);
+}
+
+impl<'a, T> Decode<'a> for BigEndian<Vec<T>> where BigEndian<T>: Decode<'a, Value = T> {
+    type Value = Vec<T>;
+
+    #[inline]
+    fn deserialize(buffer : &'a [u8]) -> (Vec<T>, usize) {
+        <BigEndian<T> as Decode>::deserialize_slice(buffer)
+    }
+
+    #[inline]
+    fn try_deserialize_with(buffer : &'a [u8], limits : &Limits, budget : &mut Budget) -> Result<(Vec<T>, usize), Error> {
+        <BigEndian<T> as Decode>::try_deserialize_slice(buffer, limits, budget)
+    }
+
+    #[inline]
+    fn min_bytes_size() -> usize {
+        SLICE_SIZE_IN_BYTES
+    }
+
+    big_endian_len!(Decode);
+}
+
+/**********
+* ALIASES *
+**********/
+/// [`u16`] written as big endian.
+#[allow(non_camel_case_types)]
+pub type u16be = BigEndian<u16>;
+
+/// [`u32`] written as big endian.
+#[allow(non_camel_case_types)]
+pub type u32be = BigEndian<u32>;
+
+/// [`u64`] written as big endian.
+#[allow(non_camel_case_types)]
+pub type u64be = BigEndian<u64>;
+
+/// [`u128`] written as big endian.
+#[allow(non_camel_case_types)]
+pub type u128be = BigEndian<u128>;
+
+/// [`i16`] written as big endian.
+#[allow(non_camel_case_types)]
+pub type i16be = BigEndian<i16>;
+
+/// [`i32`] written as big endian.
+#[allow(non_camel_case_types)]
+pub type i32be = BigEndian<i32>;
+
+/// [`i64`] written as big endian.
+#[allow(non_camel_case_types)]
+pub type i64be = BigEndian<i64>;
+
+/// [`i128`] written as big endian.
+#[allow(non_camel_case_types)]
+pub type i128be = BigEndian<i128>;
+
+/// [`f32`] written as big endian.
+#[allow(non_camel_case_types)]
+pub type f32be = BigEndian<f32>;
+
+/// [`f64`] written as big endian.
+#[allow(non_camel_case_types)]
+pub type f64be = BigEndian<f64>;
@@ -0,0 +1,168 @@
+/*
+ * @file tampon/error.rs
+ *
+ * @module tampon
+ *
+ * @brief Contain Error enum returned by fallible macros and functions.
+ *
+ * @details
+ * Contain Error enum returned by fallible macros and functions.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+/// ##### Error returned by fallible macros like [`try_deserialize!`](macro.try_deserialize.html) and [`try_serialize!`](macro.try_serialize.html).
+///
+/// Each variant carries the `offset` in [`bytes`](https://en.wikipedia.org/wiki/Byte) from the start of the
+/// [`buffer`](https://en.wikipedia.org/wiki/Data_buffer) where decoding failed.
+///
+/// # Example(s)
+/// ```
+/// // Import macro try_deserialize and Error
+/// use tampon::{ try_deserialize, Error };
+///
+/// fn read(buffer : &[u8]) -> Result<u32, Error> {
+///     try_deserialize!(buffer, (a):u16, (b):u16);
+///     Ok(a as u32 + b as u32)
+/// }
+///
+/// // Buffer is missing the last byte of b
+/// assert_eq!(read(&[1, 0, 2]), Err(Error::UnexpectedEnd { offset: 2, needed: 2, available: 1 }));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Buffer ended before a value could be read.
+    ///
+    /// `needed` bytes were required at `offset` but only `available` bytes remained.
+    UnexpectedEnd { offset: usize, needed: usize, available: usize },
+
+    /// [`String`] bytes at `offset` are not valid [`UTF-8`](https://en.wikipedia.org/wiki/UTF-8).
+    InvalidUtf8 { offset: usize },
+
+    /// Length prefix read at `offset` announce `length` bytes while only `available` bytes remained.
+    InvalidLength { offset: usize, length: usize, available: usize },
+
+    /// Destination buffer of `available` bytes is too small to serialize the `required` bytes.
+    BufferTooSmall { required: usize, available: usize },
+
+    /// Value at `offset` was rejected by an implementor of [`Tampon`](trait.Tampon.html) for the given `reason`.
+    InvalidValue { offset: usize, reason: String },
+
+    /// Length prefix read at `offset` announce `length` which is over the `limit` of [`Limits`](struct.Limits.html).
+    ///
+    /// When the total allocation budget is exceeded, `length` is the total of bytes that would have been allocated.
+    LimitExceeded { offset: usize, length: usize, limit: usize },
+
+    /// Discriminant `tag` read at `offset` doesn't match any variant of enum.
+    UnknownTag { offset: usize, tag: u64 },
+
+    /// `value` read at `offset` doesn't fit in the target type, like a [`usize`] bigger than [`u32::MAX`] on 32-bit targets
+    /// or a surrogate read as [`char`].
+    OutOfRange { offset: usize, value: i128 },
+
+    /// Key or element read at `offset` is already in the map or set being deserialized.
+    DuplicateKey { offset: usize },
+
+    /// Reading from a [`Read`](std::io::Read) or writing to a [`Write`](std::io::Write) failed at `offset` with an I/O
+    /// error of `kind` described by `message`.
+    Io { offset: usize, kind: std::io::ErrorKind, message: String },
+}
+
+impl Error {
+    /// Offset in bytes from the start of the buffer where the error happened.
+    /// 
+    /// For [`Error::BufferTooSmall`], the offset is the end of the buffer where writing would have overflowed.
+    pub fn offset(&self) -> usize {
+        match self {
+            Error::UnexpectedEnd { offset, .. } => *offset,
+            Error::InvalidUtf8 { offset } => *offset,
+            Error::InvalidLength { offset, .. } => *offset,
+            Error::BufferTooSmall { available, .. } => *available,
+            Error::InvalidValue { offset, .. } => *offset,
+            Error::LimitExceeded { offset, .. } => *offset,
+            Error::UnknownTag { offset, .. } => *offset,
+            Error::OutOfRange { offset, .. } => *offset,
+            Error::DuplicateKey { offset } => *offset,
+            Error::Io { offset, .. } => *offset,
+        }
+    }
+
+    /// Hidden function used by macros to move an error from a nested [`Tampon`](trait.Tampon.html) buffer to its parent
+    /// buffer by adding `base` to its offset. Not meant to be used directly.
+    #[doc(hidden)]
+    pub fn offset_by(self, base : usize) -> Error {
+        match self {
+            Error::UnexpectedEnd { offset, needed, available } => Error::UnexpectedEnd { offset: base + offset, needed, available },
+            Error::InvalidUtf8 { offset } => Error::InvalidUtf8 { offset: base + offset },
+            Error::InvalidLength { offset, length, available } => Error::InvalidLength { offset: base + offset, length, available },
+            Error::BufferTooSmall { required, available } => Error::BufferTooSmall { required: base + required, available: base + available },
+            Error::InvalidValue { offset, reason } => Error::InvalidValue { offset: base + offset, reason },
+            Error::LimitExceeded { offset, length, limit } => Error::LimitExceeded { offset: base + offset, length, limit },
+            Error::UnknownTag { offset, tag } => Error::UnknownTag { offset: base + offset, tag },
+            Error::OutOfRange { offset, value } => Error::OutOfRange { offset: base + offset, value },
+            Error::DuplicateKey { offset } => Error::DuplicateKey { offset: base + offset },
+            Error::Io { offset, kind, message } => Error::Io { offset: base + offset, kind, message },
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::UnexpectedEnd { offset, needed, available } =>
+                write!(f, "unexpected end of buffer at offset {} ({} bytes needed, {} available)", offset, needed, available),
+            Error::InvalidUtf8 { offset } =>
+                write!(f, "invalid UTF-8 string at offset {}", offset),
+            Error::InvalidLength { offset, length, available } =>
+                write!(f, "invalid length prefix at offset {} ({} bytes announced, {} available)", offset, length, available),
+            Error::BufferTooSmall { required, available } =>
+                write!(f, "buffer too small ({} bytes required, {} available)", required, available),
+            Error::InvalidValue { offset, reason } =>
+                write!(f, "invalid value at offset {} ({})", offset, reason),
+            Error::LimitExceeded { offset, length, limit } =>
+                write!(f, "limit exceeded at offset {} ({} requested, limit is {})", offset, length, limit),
+            Error::UnknownTag { offset, tag } =>
+                write!(f, "unknown enum tag {} at offset {}", tag, offset),
+            Error::OutOfRange { offset, value } =>
+                write!(f, "value {} at offset {} is out of range of target type", value, offset),
+            Error::DuplicateKey { offset } =>
+                write!(f, "duplicate key at offset {}", offset),
+            Error::Io { offset, message, .. } =>
+                write!(f, "I/O error at offset {} ({})", offset, message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Hidden function used by fallible macros to read `N` bytes at `offset` of `buffer`. Not meant to be used directly.
+#[doc(hidden)]
+pub fn read_bytes<const N: usize>(buffer : &[u8], offset : usize) -> Result<[u8; N], Error> {
+
+    // Bytes remaining after offset
+    let available = buffer.len().saturating_sub(offset);
+
+    if available < N {
+        Err(Error::UnexpectedEnd { offset, needed: N, available })
+    } else {
+        let mut bytes = [0u8; N];
+        bytes.copy_from_slice(&buffer[offset..offset + N]);
+        Ok(bytes)
+    }
+}
+
+/// Hidden function used by streams to convert an I/O `error` that happened at `offset`. Not meant to be used directly.
+#[doc(hidden)]
+pub fn io_error(offset : usize, error : std::io::Error) -> Error {
+    Error::Io { offset, kind: error.kind(), message: error.to_string() }
+}
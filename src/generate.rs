@@ -55,6 +55,7 @@
 /// # Panic(s)
 /// * Will panic if no [`buffer_generator_charset`] matches.
 /// * Will panic if `size == 0`.
+#[allow(clippy::needless_range_loop)]
 pub fn generate_buffer(rng : &mut impl rand::Rng, size : usize, charset: u8) -> Vec<u8> {
 
     // Make sure size generated > 0
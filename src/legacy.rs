@@ -0,0 +1,144 @@
+/*
+ * @file tampon/legacy.rs
+ *
+ * @module tampon
+ *
+ * @brief Contain previous Tampon<T> trait kept for migration.
+ * 
+ * @details
+ * Contain previous Tampon<T> trait kept for migration. All implementors of legacy::Tampon<T>
+ * implement Tampon and can be used with macros.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ * 
+ * @todo
+ * 
+ */
+
+#![allow(deprecated)]
+
+use crate::{ Error, Limits, Budget };
+
+/// ##### Previous trait used to [`serialize / deserialize`](https://en.wikipedia.org/wiki/Serialization) object, kept for migration.
+/// 
+/// Existing implementations keep working by importing `tampon::legacy::Tampon` instead of `tampon::Tampon`.
+/// Implementors of `legacy::Tampon<T>` where `T` is `Self` also implement [`Tampon`](../trait.Tampon.html) and can be used with macros.
+/// 
+/// <b>When implementing `Tampon<T>`, `T` should be the object that implement the trait Tampon.</b>
+/// 
+/// # Example(s)
+/// ```
+/// # #![allow(deprecated)]
+/// // Import legacy trait Tampon, macro bytes_size, deserialize and serialize
+/// use tampon::{ bytes_size, deserialize, serialize, buffer, legacy::Tampon };
+/// 
+/// pub struct TamponExample {
+///     f1:u8,
+///     v1:Vec<u8>,
+/// }
+/// 
+/// impl Tampon<TamponExample> for TamponExample {
+///     fn bytes_size(&self) -> usize {
+///         bytes_size!((self.f1):u8, [self.v1]:u8)
+///     }
+/// 
+///     fn serialize(&self, buffer : &mut [u8]) -> usize {
+///         serialize!(buffer, bytes_copied, (self.f1):u8, [self.v1]:u8);
+///         bytes_copied
+///     }
+///
+///     fn deserialize(buffer : &[u8]) -> (TamponExample, usize) {
+///         deserialize!(buffer, bytes_read, (f1):u8, [v1]:u8);
+///         (TamponExample {f1,v1 }, bytes_read)
+///     }
+/// }
+/// 
+/// // Still usable with macros
+/// let a = TamponExample { f1: 1, v1: vec![2, 3] };
+/// let buffer = buffer!((a):TamponExample);
+/// deserialize!(buffer, (b):TamponExample);
+/// assert_eq!(b.v1, a.v1);
+/// ```
+#[deprecated(note = "implement tampon::Tampon which returns Self instead")]
+pub trait Tampon<T> {
+    /// Size of the trait implementation in [`bytes`](https://en.wikipedia.org/wiki/Byte).
+    fn bytes_size(&self) -> usize;
+
+    /// Serialize object variable into buffer and return bytes count written.
+    fn serialize(&self, buffer : &mut [u8]) -> usize;
+
+    /// Deserialize a new variable instance from buffer and return it with bytes read.
+    fn deserialize(buffer : &[u8]) -> (T, usize);
+
+    /// Serialize object variable into buffer without panicking.
+    /// 
+    /// Default implementation verify that `buffer` is big enough with [`Tampon::bytes_size`] before calling [`Tampon::serialize`].
+    fn try_serialize(&self, buffer : &mut [u8]) -> Result<usize, Error> {
+        let required = self.bytes_size();
+
+        if required > buffer.len() {
+            Err(Error::BufferTooSmall { required, available: buffer.len() })
+        } else {
+            Ok(self.serialize(buffer))
+        }
+    }
+
+    /// Deserialize a new variable instance from buffer without panicking and return it with bytes read.
+    /// 
+    /// <b>Default implementation calls [`Tampon::deserialize`] and will panic! on invalid buffer.</b>
+    fn try_deserialize(buffer : &[u8]) -> Result<(T, usize), Error> {
+        Ok(Self::deserialize(buffer))
+    }
+
+    /// Deserialize a new variable instance from buffer without panicking, bounded by [`Limits`], and return it with bytes read.
+    /// 
+    /// Default implementation calls [`Tampon::try_deserialize`] and ignores `limits` and `budget`.
+    fn try_deserialize_with(buffer : &[u8], limits : &Limits, budget : &mut Budget) -> Result<(T, usize), Error> {
+        let _ = (limits, budget);
+        Self::try_deserialize(buffer)
+    }
+
+    /// Return true if [`Tampon::try_deserialize`] or [`Tampon::try_deserialize_with`] is overridden, so implementors can be read from a reader.
+    fn is_fallible() -> bool {
+        false
+    }
+}
+
+/// Implementors of legacy trait are implementors of Tampon.
+impl<T: Tampon<T>> crate::Tampon for T {
+    fn bytes_size(&self) -> usize {
+        Tampon::bytes_size(self)
+    }
+
+    fn serialize(&self, buffer : &mut [u8]) -> usize {
+        Tampon::serialize(self, buffer)
+    }
+
+    fn deserialize(buffer : &[u8]) -> (Self, usize) {
+        <T as Tampon<T>>::deserialize(buffer)
+    }
+
+    fn try_serialize(&self, buffer : &mut [u8]) -> Result<usize, Error> {
+        Tampon::try_serialize(self, buffer)
+    }
+
+    fn try_deserialize(buffer : &[u8]) -> Result<(Self, usize), Error> {
+        <T as Tampon<T>>::try_deserialize(buffer)
+    }
+
+    fn try_deserialize_with(buffer : &[u8], limits : &Limits, budget : &mut Budget) -> Result<(Self, usize), Error> {
+        <T as Tampon<T>>::try_deserialize_with(buffer, limits, budget)
+    }
+
+    fn is_fallible() -> bool {
+        <T as Tampon<T>>::is_fallible()
+    }
+}
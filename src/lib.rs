@@ -16,6 +16,27 @@
  pub use compare::compare_buffers as compare_buffers;
  pub use crate::tampon::Tampon as Tampon;
  pub use bytes_size::SLICE_SIZE_IN_BYTES as SLICE_SIZE_IN_BYTES;
+ pub use error::Error as Error;
+ pub use limits::Limits as Limits;
+ pub use limits::Budget as Budget;
+ pub use tag::TagWidth as TagWidth;
+ pub use collection::Sorted as Sorted;
+ pub use borrow::TamponBorrow as TamponBorrow;
+ pub use endian::BigEndian as BigEndian;
+ pub use endian::{ u16be, u32be, u64be, u128be, i16be, i32be, i64be, i128be, f32be, f64be };
+ pub use prefix::LengthPrefix as LengthPrefix;
+ pub use prefix::Prefixed as Prefixed;
+ pub use prefix::Leb128 as Leb128;
+ pub use varint::Varint as Varint;
+ pub use varint::{ varu16, varu32, varu64, varusize, vari16, vari32, vari64, varisize };
+ pub use packed::Packed as Packed;
+ pub use bits::BitWriter as BitWriter;
+ pub use bits::BitReader as BitReader;
+ pub use bits::BitOrder as BitOrder;
+
+ /// Derive macro generating implementation of trait [`Tampon`](trait.Tampon.html) for structs and enums. Requires feature `derive`.
+ #[cfg(feature = "derive")]
+ pub use tampon_derive::Tampon as Tampon;
 
 /// Generate buffer
 #[doc(hidden)]
@@ -33,6 +54,53 @@ pub mod compare;
 #[doc(hidden)]
 pub mod tampon;
 
+/// TamponBorrow trait of values borrowed from buffer
+#[doc(hidden)]
+pub mod borrow;
+
+/// Tampon trait of bool, char, numerics and String
+#[doc(hidden)]
+pub mod primitive;
+
+/// Tampon trait of collections
+#[doc(hidden)]
+pub mod collection;
+
+/// Tampon trait of Option
+#[doc(hidden)]
+pub mod option;
+
+/// Tampon trait of tuples
+#[doc(hidden)]
+pub mod tuple;
+
+/// Encode and Decode traits dispatching macros types to their encoding
+#[doc(hidden)]
+pub mod encoding;
+
+/// BigEndian marker of big endian fields
+#[doc(hidden)]
+pub mod endian;
+
+/// Prefixed marker of length prefix width
+#[doc(hidden)]
+pub mod prefix;
+
+/// Packed marker of bool slices written as bits
+#[doc(hidden)]
+pub mod packed;
+
+/// BitWriter and BitReader of fields smaller than a byte
+#[doc(hidden)]
+pub mod bits;
+
+/// Streams of serialize_to_writer! and deserialize_from_reader!
+#[doc(hidden)]
+pub mod stream;
+
+/// Previous Tampon<T> trait kept for migration
+pub mod legacy;
+
 /// bytes_size macro
 #[doc(hidden)]
 pub mod bytes_size;
@@ -49,6 +117,46 @@ pub mod deserialize;
 #[doc(hidden)]
 pub mod buffer;
 
+/// Error returned by fallible macros
+#[doc(hidden)]
+pub mod error;
+
+/// Limits of try_deserialize! macro
+#[doc(hidden)]
+pub mod limits;
+
+/// Variable length integers and Varint marker
+#[doc(hidden)]
+pub mod varint;
+
+/// Discriminant tag of enums
+#[doc(hidden)]
+pub mod tag;
+
+/// tampon_enum! macro
+#[doc(hidden)]
+pub mod tampon_enum;
+
+/// try_deserialize! macro
+#[doc(hidden)]
+pub mod try_deserialize;
+
+/// try_serialize! macro
+#[doc(hidden)]
+pub mod try_serialize;
+
+/// try_buffer! macro
+#[doc(hidden)]
+pub mod try_buffer;
+
+/// serialize_to_writer! macro
+#[doc(hidden)]
+pub mod serialize_to_writer;
+
+/// deserialize_from_reader! macro
+#[doc(hidden)]
+pub mod deserialize_from_reader;
+
 // Tests module folder
 #[cfg(test)]
 mod test;
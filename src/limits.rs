@@ -0,0 +1,196 @@
+/*
+ * @file tampon/limits.rs
+ *
+ * @module tampon
+ *
+ * @brief Contain Limits and Budget structs used to bound allocations of try_deserialize! macro.
+ *
+ * @details
+ * Contain Limits and Budget structs used to bound allocations of try_deserialize! macro.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use crate::Error;
+
+/// ##### Decode configuration used by [`try_deserialize!`](macro.try_deserialize.html) to reject hostile length prefixes.
+///
+/// Length prefixes of [`slice`] and [`String`] are read from the [`buffer`](https://en.wikipedia.org/wiki/Data_buffer) and
+/// can't be trusted. Limits are verified <b>before</b> any allocation happens and an
+/// [`Error::LimitExceeded`](enum.Error.html#variant.LimitExceeded) is returned when one is exceeded.
+///
+/// Limits are immutable and can be shared between calls and threads. The total allocation is counted in a [`Budget`]
+/// created per call of [`try_deserialize!`](macro.try_deserialize.html) and passed to nested implementors of [`Tampon`](trait.Tampon.html).
+///
+/// # Example(s)
+/// ```
+/// // Import macro try_deserialize, Error and Limits
+/// use tampon::{ try_deserialize, Error, Limits };
+///
+/// fn read(buffer : &[u8], limits : &Limits) -> Result<Vec<u128>, Error> {
+///     try_deserialize!(buffer, limits = limits, [a]:u128);
+///     Ok(a)
+/// }
+///
+/// // Maximum of 16 elements per slice, 256 bytes per string and 1024 bytes allocated in total.
+/// let limits = Limits::new(16, 256, 1024);
+///
+/// // Malicious packet announcing 4 billions elements is rejected before allocating anything.
+/// let buffer: Vec<u8> = vec![255, 255, 255, 255, 0, 0, 0, 0, 0];
+/// assert_eq!(read(&buffer, &limits), Err(Error::LimitExceeded { offset: 0, length: 4294967295, limit: 16 }));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// Maximum count of elements in a slice.
+    max_slice_len : usize,
+
+    /// Maximum length in bytes of a string.
+    max_string_len : usize,
+
+    /// Maximum bytes allocated in total.
+    max_alloc : usize,
+}
+
+/// Maximum count of elements of unknown or zero size in a slice when it's bigger than the remaining bytes of buffer.
+const MAX_EMPTY_SLICE_LEN : usize = 65536;
+
+/// ##### Running allocation budget of a deserialization bounded by [`Limits`].
+///
+/// [`try_deserialize!`](macro.try_deserialize.html) creates a new budget for each call. Implementors of
+/// [`Tampon`](trait.Tampon.html) pass the budget they received to their nested values, so all allocations of the
+/// call are counted against [`Limits::max_alloc`] together.
+///
+/// # Example(s)
+/// ```
+/// use tampon::{ Tampon, Budget, Limits, Error };
+///
+/// // 4 elements of u64 allocate 32 bytes.
+/// let buffer: Vec<u8> = vec![4, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0];
+/// let limits = Limits::new(16, 16, 48);
+///
+/// let mut budget = Budget::new();
+/// assert!(Vec::<u64>::try_deserialize_with(&buffer, &limits, &mut budget).is_ok());
+/// assert_eq!(budget.allocated(), 32);
+///
+/// // Second value read with the same budget goes over the 48 bytes allowed.
+/// assert_eq!(Vec::<u64>::try_deserialize_with(&buffer, &limits, &mut budget), Err(Error::LimitExceeded { offset: 0, length: 64, limit: 48 }));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Budget {
+    /// Bytes allocated so far.
+    allocated : usize,
+}
+
+impl Budget {
+    /// Create a new budget with nothing allocated.
+    pub const fn new() -> Budget {
+        Budget { allocated: 0 }
+    }
+
+    /// Bytes allocated so far by slices and strings.
+    pub fn allocated(&self) -> usize {
+        self.allocated
+    }
+}
+
+impl Limits {
+    /// Create new limits for deserialization.
+    ///
+    /// # Argument(s)
+    /// * `max_slice_len` - Maximum count of elements in a slice.
+    /// * `max_string_len` - Maximum length in bytes of a string.
+    /// * `max_alloc` - Maximum bytes allocated in total by slices and strings.
+    pub const fn new(max_slice_len : usize, max_string_len : usize, max_alloc : usize) -> Limits {
+        Limits { max_slice_len, max_string_len, max_alloc }
+    }
+
+    /// Maximum count of elements in a slice.
+    pub fn max_slice_len(&self) -> usize {
+        self.max_slice_len
+    }
+
+    /// Maximum length in bytes of a string.
+    pub fn max_string_len(&self) -> usize {
+        self.max_string_len
+    }
+
+    /// Maximum bytes allocated in total by slices and strings.
+    pub fn max_alloc(&self) -> usize {
+        self.max_alloc
+    }
+
+    /// Hidden function used by try_deserialize! to verify a slice length prefix read at `offset` before allocating.
+    /// Not meant to be used directly.
+    #[doc(hidden)]
+    pub fn check_slice(&self, offset : usize, length : usize, min_element_size : usize, element_size : usize, available : usize, budget : &mut Budget) -> Result<(), Error> {
+        if length > self.max_slice_len {
+            return Err(Error::LimitExceeded { offset, length, limit: self.max_slice_len });
+        }
+
+        // Elements can't take less than their minimum size in buffer
+        let needed = length.saturating_mul(min_element_size);
+        if needed > available {
+            return Err(Error::InvalidLength { offset, length: needed, available });
+        }
+
+        // Elements of unknown or zero size can't be verified against buffer and would loop without reading anything,
+        // so their count is bounded by the remaining bytes or MAX_EMPTY_SLICE_LEN.
+        if min_element_size == 0 && length > available.max(MAX_EMPTY_SLICE_LEN) {
+            return Err(Error::InvalidLength { offset, length, available });
+        }
+
+        self.allocate(offset, length.saturating_mul(element_size), budget)
+    }
+
+    /// Hidden function used by try_deserialize! to verify a string length prefix read at `offset` before allocating.
+    /// Not meant to be used directly.
+    #[doc(hidden)]
+    pub fn check_string(&self, offset : usize, length : usize, available : usize, budget : &mut Budget) -> Result<(), Error> {
+        self.check_str(offset, length, available)?;
+        self.allocate(offset, length, budget)
+    }
+
+    /// Hidden function used by try_deserialize! to verify the length prefix of a string borrowed from buffer read at `offset`.
+    /// Borrowed strings aren't copied, so they don't count in the allocation budget. Not meant to be used directly.
+    #[doc(hidden)]
+    pub fn check_str(&self, offset : usize, length : usize, available : usize) -> Result<(), Error> {
+        if length > self.max_string_len {
+            return Err(Error::LimitExceeded { offset, length, limit: self.max_string_len });
+        }
+
+        if length > available {
+            return Err(Error::InvalidLength { offset, length, available });
+        }
+
+        Ok(())
+    }
+
+    /// Add bytes to allocation budget.
+    fn allocate(&self, offset : usize, bytes : usize, budget : &mut Budget) -> Result<(), Error> {
+        let allocated = budget.allocated.saturating_add(bytes);
+        if allocated > self.max_alloc {
+            return Err(Error::LimitExceeded { offset, length: allocated, limit: self.max_alloc });
+        }
+
+        budget.allocated = allocated;
+        Ok(())
+    }
+}
+
+impl Default for Limits {
+    /// Default limits only reject lengths bigger than the remaining bytes of buffer. Slices of elements of unknown or
+    /// zero size, like empty structs, are limited to the remaining bytes of buffer or 65536 elements.
+    fn default() -> Self {
+        Limits::new(usize::MAX, usize::MAX, usize::MAX)
+    }
+}
@@ -0,0 +1,164 @@
+/*
+ * @file tampon/option.rs
+ *
+ * @module tampon
+ *
+ * @brief Contain implementation of Tampon trait for Option.
+ *
+ * @details
+ * Contain implementation of Tampon trait for Option. Option is written as a presence byte of
+ * PRESENCE_SIZE_IN_BYTES followed by the value when Some.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use crate::{ Tampon, Error, Limits, Budget, LengthPrefix };
+use crate::stream::{ StreamWriter, StreamReader };
+
+/// Size of the presence byte written before Option value.
+pub const PRESENCE_SIZE_IN_BYTES : usize = 1;
+
+/*********
+* OPTION *
+*********/
+/// Option is written as a presence byte, 0 for None and 1 for Some followed by the value.
+impl<T: Tampon> Tampon for Option<T> {
+    #[inline]
+    fn bytes_size(&self) -> usize {
+        self.bytes_size_prefixed(LengthPrefix::default())
+    }
+
+    #[inline]
+    fn serialize(&self, buffer : &mut [u8]) -> usize {
+        self.serialize_prefixed(buffer, LengthPrefix::default())
+    }
+
+    #[inline]
+    fn deserialize(buffer : &[u8]) -> (Self, usize) {
+        Self::deserialize_prefixed(buffer, LengthPrefix::default())
+    }
+
+    #[inline]
+    fn try_serialize(&self, buffer : &mut [u8]) -> Result<usize, Error> {
+        self.try_serialize_prefixed(buffer, LengthPrefix::default())
+    }
+
+    fn try_deserialize(buffer : &[u8]) -> Result<(Self, usize), Error> {
+        Self::try_deserialize_with(buffer, &Limits::default(), &mut Budget::new())
+    }
+
+    fn try_deserialize_with(buffer : &[u8], limits : &Limits, budget : &mut Budget) -> Result<(Self, usize), Error> {
+        Self::try_deserialize_prefixed(buffer, limits, budget, LengthPrefix::default())
+    }
+
+    #[inline]
+    fn is_fallible() -> bool {
+        T::is_fallible()
+    }
+
+    #[inline]
+    fn min_bytes_size() -> usize {
+        // Presence byte of None
+        PRESENCE_SIZE_IN_BYTES
+    }
+
+    #[inline]
+    fn bytes_size_prefixed(&self, prefix : LengthPrefix) -> usize {
+        // None is only the presence byte
+        PRESENCE_SIZE_IN_BYTES + match self {
+            Some(value) => value.bytes_size_prefixed(prefix),
+            None => 0,
+        }
+    }
+
+    fn serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> usize {
+        match self {
+            Some(value) => {
+                buffer[0] = 1;
+                PRESENCE_SIZE_IN_BYTES + value.serialize_prefixed(&mut buffer[PRESENCE_SIZE_IN_BYTES..], prefix)
+            },
+            None => {
+                buffer[0] = 0;
+                PRESENCE_SIZE_IN_BYTES
+            },
+        }
+    }
+
+    fn try_serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> Result<usize, Error> {
+        // Verify size before writing anything
+        let required = self.bytes_size_prefixed(prefix);
+        if required > buffer.len() {
+            return Err(Error::BufferTooSmall { required, available: buffer.len() });
+        }
+
+        match self {
+            Some(value) => {
+                buffer[0] = 1;
+                let size = value.try_serialize_prefixed(&mut buffer[PRESENCE_SIZE_IN_BYTES..], prefix).map_err(|err| err.offset_by(PRESENCE_SIZE_IN_BYTES))?;
+                Ok(PRESENCE_SIZE_IN_BYTES + size)
+            },
+            None => {
+                buffer[0] = 0;
+                Ok(PRESENCE_SIZE_IN_BYTES)
+            },
+        }
+    }
+
+    fn deserialize_prefixed(buffer : &[u8], prefix : LengthPrefix) -> (Self, usize) {
+        // Any presence byte other than 0 is Some, like bool
+        let (present, bytes_read) = <bool as Tampon>::deserialize(buffer);
+        if present {
+            let (value, size) = T::deserialize_prefixed(&buffer[bytes_read..], prefix);
+            (Some(value), bytes_read + size)
+        } else {
+            (None, bytes_read)
+        }
+    }
+
+    fn try_deserialize_prefixed(buffer : &[u8], limits : &Limits, budget : &mut Budget, prefix : LengthPrefix) -> Result<(Self, usize), Error> {
+        let (present, bytes_read) = <bool as Tampon>::try_deserialize(buffer)?;
+        if present {
+            let (value, size) = T::try_deserialize_prefixed(&buffer[bytes_read..], limits, budget, prefix).map_err(|err| err.offset_by(bytes_read))?;
+            Ok((Some(value), bytes_read + size))
+        } else {
+            Ok((None, bytes_read))
+        }
+    }
+
+    fn serialize_stream(&self, stream : &mut StreamWriter<'_>) -> Result<(), Error> {
+        let size = self.bytes_size();
+        if stream.fits(size) {
+            return stream.write_with(size, |buffer| self.try_serialize(buffer));
+        }
+
+        // Only Some can be bigger than buffer of stream
+        stream.write_with(PRESENCE_SIZE_IN_BYTES, |buffer| true.try_serialize(buffer))?;
+        match self {
+            Some(value) => value.serialize_stream(stream),
+            None => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn try_deserialize_stream(stream : &mut StreamReader<'_>, limits : &Limits) -> Result<Self, Error> {
+        Self::try_deserialize_stream_prefixed(stream, limits, LengthPrefix::default())
+    }
+
+    fn try_deserialize_stream_prefixed(stream : &mut StreamReader<'_>, limits : &Limits, prefix : LengthPrefix) -> Result<Self, Error> {
+        if stream.read_with(<bool as Tampon>::try_deserialize)? {
+            Ok(Some(T::try_deserialize_stream_prefixed(stream, limits, prefix)?))
+        } else {
+            Ok(None)
+        }
+    }
+}
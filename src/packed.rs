@@ -0,0 +1,207 @@
+/*
+ * @file tampon/packed.rs
+ *
+ * @module tampon
+ *
+ * @brief Contain Packed marker writing slices of bool as bits.
+ *
+ * @details
+ * Contain Packed marker writing slices of bool with 8 booleans per byte after the length prefix.
+ * Packed<bool> is used as type of macros fields and reads and writes values of type bool, so
+ * variables don't need to be wrapped.
+ *
+ * Booleans are packed least significant bit first and the padding bits of the last byte are 0.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ * https://en.wikipedia.org/wiki/Bit_array
+ *
+ * @todo
+ */
+
+use core::marker::PhantomData;
+
+use crate::{ Tampon, Error, Limits, Budget, LengthPrefix, SLICE_SIZE_IN_BYTES };
+use crate::encoding::{ Encode, Decode };
+
+/// ##### Marker used as type of macros fields to write slices of [`bool`] with 8 booleans per byte.
+///
+/// A [`bool`] takes 1 byte, so large flag vectors and occupancy grids are 8 times bigger than needed. `[v]:Packed<bool>`
+/// writes the length prefix of `v` followed by its booleans packed into bytes, least significant bit first. The last byte
+/// is padded with 0 bits when the length isn't a multiple of 8. Variables keep their type, only the field type of macros changes.
+///
+/// `Packed<T>` is implemented for [`bool`] and `Vec<bool>`. A single `(b):Packed<bool>` is written like a [`bool`].
+/// It is never constructed.
+///
+/// Decoding rejects padding bits set to 1.
+///
+/// # Example(s)
+/// ```
+/// use tampon::{ Packed, buffer, bytes_size, deserialize };
+///
+/// let flags = vec![true, false, true, true, false, false, false, false, true, true];
+///
+/// let buffer = buffer!([flags]:Packed<bool>);
+/// assert_eq!(buffer, vec![10, 0, 0, 0, 0b0000_1101, 0b0000_0011]);
+/// assert_eq!(bytes_size!([flags]:Packed<bool>), 6);
+///
+/// deserialize!(buffer, [a]:Packed<bool>);
+/// assert_eq!(a, flags);
+/// ```
+pub struct Packed<T: ?Sized>(PhantomData<T>);
+
+/**********
+* HELPERS *
+**********/
+/// Size in bytes of `len` packed booleans.
+#[inline]
+fn packed_size(len : usize) -> usize {
+    len.div_ceil(8)
+}
+
+/// Read `len` booleans packed in `bytes`.
+fn unpack(bytes : &[u8], len : usize) -> Result<Vec<bool>, Error> {
+    // Padding bits of last byte must be 0, so each slice has a single encoding
+    if !len.is_multiple_of(8) && bytes[bytes.len() - 1] >> (len % 8) != 0 {
+        return Err(Error::InvalidValue { offset: bytes.len() - 1, reason: String::from("padding bits of packed bool are set") });
+    }
+
+    Ok((0..len).map(|index| bytes[index / 8] & (1 << (index % 8)) != 0).collect())
+}
+
+/*******
+* BOOL *
+*******/
+impl Encode for Packed<bool> {
+    type Value = bool;
+
+    #[inline]
+    fn bytes_size(value : &bool) -> usize {
+        value.bytes_size()
+    }
+
+    #[inline]
+    fn serialize(value : &bool, buffer : &mut [u8]) -> usize {
+        value.serialize(buffer)
+    }
+
+    #[inline]
+    fn slice_bytes_size(slice : &[bool]) -> usize {
+        SLICE_SIZE_IN_BYTES + packed_size(slice.len())
+    }
+
+    fn serialize_slice(slice : &[bool], buffer : &mut [u8]) -> usize {
+        let bytes_len = Self::serialize_len(slice.len(), buffer);
+        let size = packed_size(slice.len());
+
+        // Each chunk of 8 booleans becomes a byte
+        for (byte, chunk) in buffer[bytes_len..bytes_len + size].iter_mut().zip(slice.chunks(8)) {
+            *byte = chunk.iter().enumerate().fold(0, |byte, (index, value)| byte | ((*value as u8) << index));
+        }
+        bytes_len + size
+    }
+
+    fn try_serialize_slice(slice : &[bool], buffer : &mut [u8]) -> Result<usize, Error> {
+        let required = Self::slice_bytes_size(slice);
+        if required > buffer.len() {
+            return Err(Error::BufferTooSmall { required, available: buffer.len() });
+        }
+
+        // Length prefix is verified before booleans are packed
+        LengthPrefix::default().check(slice.len())?;
+        Ok(Self::serialize_slice(slice, buffer))
+    }
+}
+
+impl<'a> Decode<'a> for Packed<bool> {
+    type Value = bool;
+
+    #[inline]
+    fn deserialize(buffer : &'a [u8]) -> (bool, usize) {
+        <bool as Tampon>::deserialize(buffer)
+    }
+
+    #[inline]
+    fn try_deserialize_with(buffer : &'a [u8], _limits : &Limits, _budget : &mut Budget) -> Result<(bool, usize), Error> {
+        <bool as Tampon>::try_deserialize(buffer)
+    }
+
+    #[inline]
+    fn min_bytes_size() -> usize {
+        <bool as Tampon>::min_bytes_size()
+    }
+
+    fn deserialize_slice(buffer : &'a [u8]) -> (Vec<bool>, usize) {
+        let (slice_size, bytes_len) = Self::deserialize_len(buffer);
+        let size = packed_size(slice_size);
+
+        match unpack(&buffer[bytes_len..bytes_len + size], slice_size) {
+            Ok(vec) => (vec, bytes_len + size),
+            Err(err) => panic!("{}", err.offset_by(bytes_len)),
+        }
+    }
+
+    fn try_deserialize_slice(buffer : &'a [u8], limits : &Limits, budget : &mut Budget) -> Result<(Vec<bool>, usize), Error> {
+        let (slice_size, bytes_len) = Self::try_deserialize_len(buffer)?;
+
+        // Validate length prefix against limits before allocating, remaining bytes are verified with packed size
+        let available = buffer.len() - bytes_len;
+        limits.check_slice(0, slice_size, 0, core::mem::size_of::<bool>(), usize::MAX, budget)?;
+        let size = packed_size(slice_size);
+        if size > available {
+            return Err(Error::InvalidLength { offset: 0, length: size, available });
+        }
+
+        let vec = unpack(&buffer[bytes_len..bytes_len + size], slice_size).map_err(|err| err.offset_by(bytes_len))?;
+        Ok((vec, bytes_len + size))
+    }
+}
+
+/******
+* VEC *
+******/
+/// Vec is written like `[v]:Packed<bool>` in macros.
+impl Encode for Packed<Vec<bool>> {
+    type Value = Vec<bool>;
+
+    #[inline]
+    fn bytes_size(value : &Vec<bool>) -> usize {
+        <Packed<bool> as Encode>::slice_bytes_size(value)
+    }
+
+    #[inline]
+    fn serialize(value : &Vec<bool>, buffer : &mut [u8]) -> usize {
+        <Packed<bool> as Encode>::serialize_slice(value, buffer)
+    }
+
+    #[inline]
+    fn try_serialize(value : &Vec<bool>, buffer : &mut [u8]) -> Result<usize, Error> {
+        <Packed<bool> as Encode>::try_serialize_slice(value, buffer)
+    }
+}
+
+impl<'a> Decode<'a> for Packed<Vec<bool>> {
+    type Value = Vec<bool>;
+
+    #[inline]
+    fn deserialize(buffer : &'a [u8]) -> (Vec<bool>, usize) {
+        <Packed<bool> as Decode>::deserialize_slice(buffer)
+    }
+
+    #[inline]
+    fn try_deserialize_with(buffer : &'a [u8], limits : &Limits, budget : &mut Budget) -> Result<(Vec<bool>, usize), Error> {
+        <Packed<bool> as Decode>::try_deserialize_slice(buffer, limits, budget)
+    }
+
+    #[inline]
+    fn min_bytes_size() -> usize {
+        SLICE_SIZE_IN_BYTES
+    }
+}
@@ -0,0 +1,332 @@
+/*
+ * @file tampon/prefix.rs
+ *
+ * @module tampon
+ *
+ * @brief Contain LengthPrefix encodings and Prefixed marker selecting the length prefix of strings and slices.
+ *
+ * @details
+ * Contain LengthPrefix encodings and Prefixed marker selecting the length prefix of strings and slices.
+ * Length prefix is written as u32 by default. Prefixed<P, T> is used as type of macros fields and writes
+ * values of type T with a length prefix of width P, which is u8, u16, u32, u64 or Leb128. Nested strings
+ * and slices of T use the same width.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ * https://en.wikipedia.org/wiki/LEB128
+ *
+ * @todo
+ */
+
+use core::marker::PhantomData;
+
+use crate::{ Tampon, TamponBorrow, Error, Limits, Budget };
+use crate::encoding::{ Encode, Decode };
+use crate::error::read_bytes;
+use crate::varint::{ varint_size, write_varint, read_varint, try_read_varint };
+
+/// ##### Encoding of the length prefix written before strings and slices.
+///
+/// Selected with marker [`Prefixed<P, T>`](struct.Prefixed.html). Default is [`LengthPrefix::U32`], the
+/// encoding of macros when no prefix is given.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LengthPrefix {
+    /// Length written as [`u8`], up to 255 elements.
+    U8,
+
+    /// Length written as [`u16`] in little endian.
+    U16,
+
+    /// Length written as [`u32`] in little endian.
+    #[default]
+    U32,
+
+    /// Length written as [`u64`] in little endian.
+    U64,
+
+    /// Length written as [`LEB128`](https://en.wikipedia.org/wiki/LEB128) variable length integer.
+    Varint,
+}
+
+impl LengthPrefix {
+    /// Size in bytes of the length prefix of `len` elements.
+    pub fn size(self, len : usize) -> usize {
+        match self {
+            LengthPrefix::U8 => core::mem::size_of::<u8>(),
+            LengthPrefix::U16 => core::mem::size_of::<u16>(),
+            LengthPrefix::U32 => core::mem::size_of::<u32>(),
+            LengthPrefix::U64 => core::mem::size_of::<u64>(),
+            LengthPrefix::Varint => varint_size(len as u64),
+        }
+    }
+
+    /// Minimum size in bytes of a length prefix.
+    pub fn min_size(self) -> usize {
+        match self {
+            LengthPrefix::Varint => 1,
+            _ => self.size(0),
+        }
+    }
+
+    /// Verify that `len` can be written with this prefix.
+    ///
+    /// # Error(s)
+    /// * [`Error::OutOfRange`] if `len` is bigger than the maximum of the prefix.
+    pub fn check(self, len : usize) -> Result<(), Error> {
+        let max = match self {
+            LengthPrefix::U8 => u8::MAX as u64,
+            LengthPrefix::U16 => u16::MAX as u64,
+            LengthPrefix::U32 => u32::MAX as u64,
+            LengthPrefix::U64 | LengthPrefix::Varint => u64::MAX,
+        };
+
+        if len as u64 > max {
+            Err(Error::OutOfRange { offset: 0, value: len as i128 })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Serialize length prefix of `len` elements into `buffer` and return the bytes count written.
+    ///
+    /// # Panic(s)
+    /// * Will panic! if `buffer` is too small or if `len` doesn't fit in the prefix.
+    pub fn serialize(self, len : usize, buffer : &mut [u8]) -> usize {
+        if let Err(err) = self.check(len) {
+            panic!("{}", err);
+        }
+
+        match self {
+            LengthPrefix::U8 => { buffer[0] = len as u8; 1 },
+            LengthPrefix::U16 => { buffer[0..2].copy_from_slice(&(len as u16).to_le_bytes()); 2 },
+            LengthPrefix::U32 => { buffer[0..4].copy_from_slice(&(len as u32).to_le_bytes()); 4 },
+            LengthPrefix::U64 => { buffer[0..8].copy_from_slice(&(len as u64).to_le_bytes()); 8 },
+            LengthPrefix::Varint => write_varint(buffer, len as u64),
+        }
+    }
+
+    /// Serialize length prefix of `len` elements into `buffer` without panicking and return the bytes count written.
+    ///
+    /// # Error(s)
+    /// * [`Error::OutOfRange`] if `len` doesn't fit in the prefix.
+    /// * [`Error::BufferTooSmall`] if `buffer` is too small.
+    pub fn try_serialize(self, len : usize, buffer : &mut [u8]) -> Result<usize, Error> {
+        self.check(len)?;
+
+        let required = self.size(len);
+        if required > buffer.len() {
+            return Err(Error::BufferTooSmall { required, available: buffer.len() });
+        }
+
+        Ok(self.serialize(len, buffer))
+    }
+
+    /// Deserialize length prefix from `buffer` and return it with the bytes count read.
+    ///
+    /// # Panic(s)
+    /// * Will panic! if `buffer` is too small or if length is bigger than [`usize`].
+    pub fn deserialize(self, buffer : &[u8]) -> (usize, usize) {
+        let (len, bytes_read) = match self {
+            LengthPrefix::U8 => (buffer[0] as u64, 1),
+            LengthPrefix::U16 => (u16::from_le_bytes([buffer[0], buffer[1]]) as u64, 2),
+            LengthPrefix::U32 => (u32::from_le_bytes(buffer[0..4].try_into().expect("Incorrect length!")) as u64, 4),
+            LengthPrefix::U64 => (u64::from_le_bytes(buffer[0..8].try_into().expect("Incorrect length!")), 8),
+            LengthPrefix::Varint => read_varint(buffer),
+        };
+
+        match usize::try_from(len) {
+            Ok(len) => (len, bytes_read),
+            Err(_) => panic!("{}", Error::OutOfRange { offset: 0, value: len as i128 }),
+        }
+    }
+
+    /// Deserialize length prefix from `buffer` without panicking and return it with the bytes count read.
+    ///
+    /// # Error(s)
+    /// * [`Error::UnexpectedEnd`] if `buffer` ends before the prefix.
+    /// * [`Error::InvalidValue`] if a varint prefix is bigger than [`u64`].
+    /// * [`Error::OutOfRange`] if length is bigger than [`usize`].
+    pub fn try_deserialize(self, buffer : &[u8]) -> Result<(usize, usize), Error> {
+        let (len, bytes_read) = match self {
+            LengthPrefix::U8 => (u8::from_le_bytes(read_bytes(buffer, 0)?) as u64, 1),
+            LengthPrefix::U16 => (u16::from_le_bytes(read_bytes(buffer, 0)?) as u64, 2),
+            LengthPrefix::U32 => (u32::from_le_bytes(read_bytes(buffer, 0)?) as u64, 4),
+            LengthPrefix::U64 => (u64::from_le_bytes(read_bytes(buffer, 0)?), 8),
+            LengthPrefix::Varint => try_read_varint(buffer, 0)?,
+        };
+
+        match usize::try_from(len) {
+            Ok(len) => Ok((len, bytes_read)),
+            Err(_) => Err(Error::OutOfRange { offset: 0, value: len as i128 }),
+        }
+    }
+}
+
+/// Hidden trait giving the [`LengthPrefix`] of width types of [`Prefixed`]. Not meant to be implemented directly.
+#[doc(hidden)]
+pub trait PrefixWidth {
+    /// Encoding of length prefix.
+    const PREFIX : LengthPrefix;
+}
+
+/// Width of [`Prefixed`] writing length prefix as [`LEB128`](https://en.wikipedia.org/wiki/LEB128) variable length integer.
+pub struct Leb128;
+
+impl PrefixWidth for u8 {
+    const PREFIX : LengthPrefix = LengthPrefix::U8;
+}
+
+impl PrefixWidth for u16 {
+    const PREFIX : LengthPrefix = LengthPrefix::U16;
+}
+
+impl PrefixWidth for u32 {
+    const PREFIX : LengthPrefix = LengthPrefix::U32;
+}
+
+impl PrefixWidth for u64 {
+    const PREFIX : LengthPrefix = LengthPrefix::U64;
+}
+
+impl PrefixWidth for Leb128 {
+    const PREFIX : LengthPrefix = LengthPrefix::Varint;
+}
+
+/// ##### Marker used as type of macros fields to write values of type `T` with a length prefix of width `P`.
+///
+/// Strings and slices are written after a [`u32`] length prefix by default. Small strings of chatty protocols
+/// can use a [`u8`] or [`u16`] prefix, blobs over 4 GiB a [`u64`] prefix and [`Leb128`] gives 1 byte to lengths
+/// under 128. Width `P` is one of [`u8`], [`u16`], [`u32`], [`u64`] or [`Leb128`].
+///
+/// Strings and slices nested in `T`, like elements of `Vec<String>`, use the same width. Slices `[v]:Prefixed<P, T>`
+/// write their own length prefix with width `P`. Implementors of [`Tampon`](trait.Tampon.html) are written with their
+/// own encoding. Variables keep their type, only the field type of macros changes. It is never constructed.
+///
+/// Serializing a length bigger than the prefix can hold panics, or returns [`Error::OutOfRange`] with `try_` macros.
+///
+/// # Example(s)
+/// ```
+/// use tampon::{ Prefixed, Leb128, buffer, bytes_size, deserialize };
+///
+/// let name = String::from("tampon");
+/// let tags = vec![String::from("a"), String::from("b")];
+/// let values:Vec<u16> = vec![1, 2, 3];
+///
+/// let buffer = buffer!((name):Prefixed<u8, String>, (tags):Prefixed<u16, Vec<String>>, [values]:Prefixed<Leb128, u16>);
+/// assert_eq!(buffer.len(), bytes_size!((name):Prefixed<u8, String>, (tags):Prefixed<u16, Vec<String>>, [values]:Prefixed<Leb128, u16>));
+/// assert_eq!(buffer[0..7], [6, b't', b'a', b'm', b'p', b'o', b'n']);
+/// assert_eq!(buffer[7..13], [2, 0, 1, 0, b'a', 1]);
+///
+/// deserialize!(buffer, (a):Prefixed<u8, &str>, (b):Prefixed<u16, Vec<String>>, [c]:Prefixed<Leb128, u16>);
+/// assert_eq!((a, b, c), ("tampon", tags, values));
+/// ```
+pub struct Prefixed<P, T: ?Sized>(PhantomData<P>, PhantomData<T>);
+
+impl<P: PrefixWidth, T: Tampon + ?Sized> Encode for Prefixed<P, T> {
+    type Value = T;
+
+    #[inline]
+    fn bytes_size(value : &T) -> usize {
+        value.bytes_size_prefixed(P::PREFIX)
+    }
+
+    #[inline]
+    fn serialize(value : &T, buffer : &mut [u8]) -> usize {
+        value.serialize_prefixed(buffer, P::PREFIX)
+    }
+
+    #[inline]
+    fn try_serialize(value : &T, buffer : &mut [u8]) -> Result<usize, Error> {
+        value.try_serialize_prefixed(buffer, P::PREFIX)
+    }
+
+    #[inline]
+    fn len_bytes_size(len : usize) -> usize {
+        P::PREFIX.size(len)
+    }
+
+    #[inline]
+    fn serialize_len(len : usize, buffer : &mut [u8]) -> usize {
+        P::PREFIX.serialize(len, buffer)
+    }
+
+    #[inline]
+    fn try_serialize_len(len : usize, buffer : &mut [u8]) -> Result<usize, Error> {
+        P::PREFIX.try_serialize(len, buffer)
+    }
+
+    fn slice_bytes_size(slice : &[T]) -> usize where T: Sized {
+        P::PREFIX.size(slice.len()) + T::slice_bytes_size(slice, P::PREFIX)
+    }
+
+    fn serialize_slice(slice : &[T], buffer : &mut [u8]) -> usize where T: Sized {
+        let size = P::PREFIX.serialize(slice.len(), buffer);
+        size + T::serialize_slice(slice, &mut buffer[size..], P::PREFIX)
+    }
+
+    fn try_serialize_slice(slice : &[T], buffer : &mut [u8]) -> Result<usize, Error> where T: Sized {
+        // Verify size before writing anything
+        let required = Self::slice_bytes_size(slice);
+        if required > buffer.len() {
+            return Err(Error::BufferTooSmall { required, available: buffer.len() });
+        }
+
+        // Errors of elements are relative to the buffer of elements and need to be offset
+        let size = P::PREFIX.try_serialize(slice.len(), buffer)?;
+        Ok(size + T::try_serialize_slice(slice, &mut buffer[size..], P::PREFIX).map_err(|err| err.offset_by(size))?)
+    }
+}
+
+impl<'a, P: PrefixWidth, T: TamponBorrow<'a>> Decode<'a> for Prefixed<P, T> {
+    type Value = T;
+
+    #[inline]
+    fn deserialize(buffer : &'a [u8]) -> (T, usize) {
+        T::deserialize_borrowed_prefixed(buffer, P::PREFIX)
+    }
+
+    #[inline]
+    fn try_deserialize_with(buffer : &'a [u8], limits : &Limits, budget : &mut Budget) -> Result<(T, usize), Error> {
+        T::try_deserialize_borrowed_prefixed(buffer, limits, budget, P::PREFIX)
+    }
+
+    #[inline]
+    fn min_bytes_size() -> usize {
+        T::min_bytes_size_prefixed(P::PREFIX)
+    }
+
+    #[inline]
+    fn deserialize_len(buffer : &[u8]) -> (usize, usize) {
+        P::PREFIX.deserialize(buffer)
+    }
+
+    #[inline]
+    fn try_deserialize_len(buffer : &[u8]) -> Result<(usize, usize), Error> {
+        P::PREFIX.try_deserialize(buffer)
+    }
+
+    fn deserialize_slice(buffer : &'a [u8]) -> (Vec<T>, usize) {
+        let (slice_size, size) = P::PREFIX.deserialize(buffer);
+        let (vec, bytes_read) = T::deserialize_borrowed_slice(&buffer[size..], slice_size, P::PREFIX);
+        (vec, size + bytes_read)
+    }
+
+    fn try_deserialize_slice(buffer : &'a [u8], limits : &Limits, budget : &mut Budget) -> Result<(Vec<T>, usize), Error> {
+        let (slice_size, size) = P::PREFIX.try_deserialize(buffer)?;
+
+        // Validate length prefix against limits and remaining bytes before allocating
+        limits.check_slice(0, slice_size, T::min_bytes_size_prefixed(P::PREFIX), core::mem::size_of::<T>(), buffer.len() - size, budget)?;
+
+        // Errors of elements are relative to the buffer of elements and need to be offset
+        let (vec, bytes_read) = T::try_deserialize_borrowed_slice(&buffer[size..], slice_size, limits, budget, P::PREFIX)
+            .map_err(|err| err.offset_by(size))?;
+        Ok((vec, size + bytes_read))
+    }
+}
@@ -0,0 +1,455 @@
+/*
+ * @file tampon/primitive.rs
+ *
+ * @module tampon
+ *
+ * @brief Contain implementation of Tampon trait for bool, char, numeric types, str and String.
+ *
+ * @details
+ * Contain implementation of Tampon trait for bool, char, numeric types, str and String. Macros dispatch
+ * every type through Tampon, so type aliases and path-qualified types of primitives are supported.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use crate::{ Tampon, Error, Limits, Budget, LengthPrefix, SLICE_SIZE_IN_BYTES };
+use crate::error::read_bytes;
+use crate::stream::{ StreamWriter, StreamReader };
+
+/**********
+* BOOLEAN *
+**********/
+impl Tampon for bool {
+    #[inline]
+    fn bytes_size(&self) -> usize {
+        // bool use 1 byte (even if 1 bit), slices use 1 bit with Packed<bool>
+        core::mem::size_of::<u8>()
+    }
+
+    #[inline]
+    fn serialize(&self, buffer : &mut [u8]) -> usize {
+        // Translate bool into u8
+        <u8 as Tampon>::serialize(&(*self as u8), buffer)
+    }
+
+    #[inline]
+    fn deserialize(buffer : &[u8]) -> (Self, usize) {
+        // Any value other than 0 is true
+        let (value, bytes_read) = <u8 as Tampon>::deserialize(buffer);
+        (value != 0, bytes_read)
+    }
+
+    #[inline]
+    fn try_deserialize(buffer : &[u8]) -> Result<(Self, usize), Error> {
+        let (value, bytes_read) = <u8 as Tampon>::try_deserialize(buffer)?;
+        Ok((value != 0, bytes_read))
+    }
+
+    #[inline]
+    fn is_fallible() -> bool {
+        true
+    }
+
+    #[inline]
+    fn try_deserialize_stream(stream : &mut StreamReader<'_>, _limits : &Limits) -> Result<Self, Error> {
+        // Size is known, so only the bytes of value are read
+        stream.read_with(Self::try_deserialize)
+    }
+
+    #[inline]
+    fn min_bytes_size() -> usize {
+        core::mem::size_of::<u8>()
+    }
+}
+
+/*******
+* CHAR *
+*******/
+/// char is written as its Unicode scalar value in a u32.
+impl Tampon for char {
+    #[inline]
+    fn bytes_size(&self) -> usize {
+        core::mem::size_of::<u32>()
+    }
+
+    #[inline]
+    fn serialize(&self, buffer : &mut [u8]) -> usize {
+        <u32 as Tampon>::serialize(&(*self as u32), buffer)
+    }
+
+    #[inline]
+    fn deserialize(buffer : &[u8]) -> (Self, usize) {
+        // Surrogates and values over char::MAX are rejected
+        let (value, bytes_read) = <u32 as Tampon>::deserialize(buffer);
+        match char::from_u32(value) {
+            Some(value) => (value, bytes_read),
+            None => panic!("{}", Error::OutOfRange { offset: 0, value: value as i128 }),
+        }
+    }
+
+    #[inline]
+    fn try_deserialize(buffer : &[u8]) -> Result<(Self, usize), Error> {
+        let (value, bytes_read) = <u32 as Tampon>::try_deserialize(buffer)?;
+        match char::from_u32(value) {
+            Some(value) => Ok((value, bytes_read)),
+            None => Err(Error::OutOfRange { offset: 0, value: value as i128 }),
+        }
+    }
+
+    #[inline]
+    fn is_fallible() -> bool {
+        true
+    }
+
+    #[inline]
+    fn try_deserialize_stream(stream : &mut StreamReader<'_>, _limits : &Limits) -> Result<Self, Error> {
+        // Size is known, so only the bytes of value are read
+        stream.read_with(Self::try_deserialize)
+    }
+
+    #[inline]
+    fn min_bytes_size() -> usize {
+        core::mem::size_of::<u32>()
+    }
+}
+
+/***********
+* NUMERICS *
+***********/
+/// Implement Tampon for numeric types written as little endian bytes.
+macro_rules! tampon_numeric {
+    ($($type:ident),*) => {
+        $(
+            impl Tampon for $type {
+                #[inline]
+                fn bytes_size(&self) -> usize {
+                    // Size of type in bytes
+                    core::mem::size_of::<$type>()
+                }
+
+                #[inline]
+                fn serialize(&self, buffer : &mut [u8]) -> usize {
+                    let bytes = self.to_le_bytes();
+                    buffer[0..bytes.len()].copy_from_slice(&bytes);
+                    bytes.len()
+                }
+
+                #[inline]
+                fn deserialize(buffer : &[u8]) -> (Self, usize) {
+                    let size = core::mem::size_of::<$type>();
+                    (<$type>::from_le_bytes(buffer[0..size].try_into().expect("Incorrect length!")), size)
+                }
+
+                #[inline]
+                fn try_deserialize(buffer : &[u8]) -> Result<(Self, usize), Error> {
+                    // Type of read_bytes array is inferred from from_le_bytes
+                    Ok((<$type>::from_le_bytes(read_bytes(buffer, 0)?), core::mem::size_of::<$type>()))
+                }
+
+                #[inline]
+                fn is_fallible() -> bool {
+                    true
+                }
+
+                #[inline]
+                fn try_deserialize_stream(stream : &mut StreamReader<'_>, _limits : &Limits) -> Result<Self, Error> {
+                    // Size is known, so only the bytes of value are read
+                    stream.read_with(Self::try_deserialize)
+                }
+
+                #[inline]
+                fn min_bytes_size() -> usize {
+                    core::mem::size_of::<$type>()
+                }
+
+                #[inline]
+                fn slice_bytes_size(slice : &[Self], _prefix : LengthPrefix) -> usize {
+                    slice.len() * core::mem::size_of::<$type>()
+                }
+
+                fn serialize_slice(slice : &[Self], buffer : &mut [u8], _prefix : LengthPrefix) -> usize {
+                    let size = core::mem::size_of::<$type>();
+                    let bytes_size = slice.len() * size;
+                    if cfg!(target_endian = "little") || size == 1 {
+                        // Memory of slice is already in wire order and copied at once
+                        buffer[0..bytes_size].copy_from_slice(bytemuck::cast_slice(slice));
+                    } else {
+                        for (chunk, value) in buffer[0..bytes_size].chunks_exact_mut(size).zip(slice.iter()) {
+                            chunk.copy_from_slice(&value.to_le_bytes());
+                        }
+                    }
+                    bytes_size
+                }
+
+                fn try_serialize_slice(slice : &[Self], buffer : &mut [u8], prefix : LengthPrefix) -> Result<usize, Error> {
+                    let required = slice.len() * core::mem::size_of::<$type>();
+                    if required > buffer.len() {
+                        return Err(Error::BufferTooSmall { required, available: buffer.len() });
+                    }
+                    Ok(Self::serialize_slice(slice, buffer, prefix))
+                }
+
+                fn deserialize_slice(buffer : &[u8], len : usize, _prefix : LengthPrefix) -> (Vec<Self>, usize) {
+                    // Vector is allocated once, then bytes in wire order are copied at once
+                    let size = core::mem::size_of::<$type>();
+                    let bytes_size = len.saturating_mul(size);
+                    let vec = if cfg!(target_endian = "little") || size == 1 {
+                        bytemuck::pod_collect_to_vec(&buffer[0..bytes_size])
+                    } else {
+                        buffer[0..bytes_size].chunks_exact(size)
+                            .map(|chunk| <$type>::from_le_bytes(chunk.try_into().expect("Incorrect length!"))).collect()
+                    };
+                    (vec, bytes_size)
+                }
+
+                fn try_deserialize_slice(buffer : &[u8], len : usize, _limits : &Limits, _budget : &mut Budget, prefix : LengthPrefix) -> Result<(Vec<Self>, usize), Error> {
+                    // Error is at the first element that doesn't fit in buffer
+                    let size = core::mem::size_of::<$type>();
+                    if len.saturating_mul(size) > buffer.len() {
+                        return Err(Error::UnexpectedEnd { offset: buffer.len() / size * size, needed: size, available: buffer.len() % size });
+                    }
+                    Ok(Self::deserialize_slice(buffer, len, prefix))
+                }
+            }
+        )*
+    };
+}
+
+tampon_numeric!(u8, u16, u32, u64, u128, f32, f64, i8, i16, i32, i64, i128);
+
+/**************************
+* POINTER-SIZED INTEGERS *
+**************************/
+/// Implement Tampon for pointer-sized integers written as a fixed width type, so buffers are
+/// portable between 32-bit and 64-bit targets.
+macro_rules! tampon_pointer_sized {
+    ($($type:ident as $fixed:ident),*) => {
+        $(
+            impl Tampon for $type {
+                #[inline]
+                fn bytes_size(&self) -> usize {
+                    // Always the size of fixed type, whatever the target
+                    core::mem::size_of::<$fixed>()
+                }
+
+                #[inline]
+                fn serialize(&self, buffer : &mut [u8]) -> usize {
+                    // Widening to fixed type never loses value
+                    <$fixed as Tampon>::serialize(&(*self as $fixed), buffer)
+                }
+
+                #[inline]
+                fn deserialize(buffer : &[u8]) -> (Self, usize) {
+                    let (value, bytes_read) = <$fixed as Tampon>::deserialize(buffer);
+                    match <$type>::try_from(value) {
+                        Ok(value) => (value, bytes_read),
+                        Err(_) => panic!("{}", Error::OutOfRange { offset: 0, value: value as i128 }),
+                    }
+                }
+
+                #[inline]
+                fn try_deserialize(buffer : &[u8]) -> Result<(Self, usize), Error> {
+                    let (value, bytes_read) = <$fixed as Tampon>::try_deserialize(buffer)?;
+                    match <$type>::try_from(value) {
+                        Ok(value) => Ok((value, bytes_read)),
+                        Err(_) => Err(Error::OutOfRange { offset: 0, value: value as i128 }),
+                    }
+                }
+
+                #[inline]
+                fn is_fallible() -> bool {
+                    true
+                }
+
+                #[inline]
+                fn try_deserialize_stream(stream : &mut StreamReader<'_>, _limits : &Limits) -> Result<Self, Error> {
+                    // Size is known, so only the bytes of value are read
+                    stream.read_with(Self::try_deserialize)
+                }
+
+                #[inline]
+                fn min_bytes_size() -> usize {
+                    core::mem::size_of::<$fixed>()
+                }
+            }
+        )*
+    };
+}
+
+tampon_pointer_sized!(usize as u64, isize as i64);
+
+/******
+* STR *
+******/
+/// str is written like String and is deserialized with TamponBorrow as &str.
+impl Tampon for str {
+    #[inline]
+    fn bytes_size(&self) -> usize {
+        self.bytes_size_prefixed(LengthPrefix::default())
+    }
+
+    #[inline]
+    fn serialize(&self, buffer : &mut [u8]) -> usize {
+        self.serialize_prefixed(buffer, LengthPrefix::default())
+    }
+
+    #[inline]
+    fn try_serialize(&self, buffer : &mut [u8]) -> Result<usize, Error> {
+        self.try_serialize_prefixed(buffer, LengthPrefix::default())
+    }
+
+    #[inline]
+    fn bytes_size_prefixed(&self, prefix : LengthPrefix) -> usize {
+        // String is a slice of char and need to pad the size
+        // str::len() gives size of string in bytes (https://doc.rust-lang.org/std/primitive.str.html#method.len)
+        prefix.size(self.len()) + self.len()
+    }
+
+    fn serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> usize {
+        // Write size of String
+        let size = prefix.serialize(self.len(), buffer);
+
+        // Copy String bytes to buffer
+        let bytes = self.as_bytes();
+        buffer[size..(size + bytes.len())].copy_from_slice(bytes);
+
+        size + bytes.len()
+    }
+
+    fn try_serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> Result<usize, Error> {
+        // Verify size before writing anything
+        let required = self.bytes_size_prefixed(prefix);
+        if required > buffer.len() {
+            return Err(Error::BufferTooSmall { required, available: buffer.len() });
+        }
+
+        let size = prefix.try_serialize(self.len(), buffer)?;
+        buffer[size..required].copy_from_slice(self.as_bytes());
+        Ok(required)
+    }
+
+    fn serialize_stream(&self, stream : &mut StreamWriter<'_>) -> Result<(), Error> {
+        let size = self.bytes_size();
+        if stream.fits(size) {
+            return stream.write_with(size, |buffer| self.try_serialize(buffer));
+        }
+
+        // Bytes of big strings are written without copy after their size
+        let prefix = LengthPrefix::default();
+        stream.write_with(prefix.size(self.len()), |buffer| prefix.try_serialize(self.len(), buffer))?;
+        stream.write_bytes(self.as_bytes())
+    }
+}
+
+/*********
+* STRING *
+*********/
+impl Tampon for String {
+    #[inline]
+    fn bytes_size(&self) -> usize {
+        self.as_str().bytes_size()
+    }
+
+    #[inline]
+    fn serialize(&self, buffer : &mut [u8]) -> usize {
+        self.as_str().serialize(buffer)
+    }
+
+    #[inline]
+    fn deserialize(buffer : &[u8]) -> (Self, usize) {
+        Self::deserialize_prefixed(buffer, LengthPrefix::default())
+    }
+
+    #[inline]
+    fn try_serialize(&self, buffer : &mut [u8]) -> Result<usize, Error> {
+        self.as_str().try_serialize(buffer)
+    }
+
+    fn try_deserialize(buffer : &[u8]) -> Result<(Self, usize), Error> {
+        Self::try_deserialize_with(buffer, &Limits::default(), &mut Budget::new())
+    }
+
+    #[inline]
+    fn try_deserialize_with(buffer : &[u8], limits : &Limits, budget : &mut Budget) -> Result<(Self, usize), Error> {
+        Self::try_deserialize_prefixed(buffer, limits, budget, LengthPrefix::default())
+    }
+
+    #[inline]
+    fn is_fallible() -> bool {
+        true
+    }
+
+    #[inline]
+    fn try_deserialize_stream(stream : &mut StreamReader<'_>, limits : &Limits) -> Result<Self, Error> {
+        Self::try_deserialize_stream_prefixed(stream, limits, LengthPrefix::default())
+    }
+
+    #[inline]
+    fn min_bytes_size() -> usize {
+        // Length prefix of string
+        SLICE_SIZE_IN_BYTES
+    }
+
+    #[inline]
+    fn min_bytes_size_prefixed(prefix : LengthPrefix) -> usize {
+        prefix.min_size()
+    }
+
+    #[inline]
+    fn bytes_size_prefixed(&self, prefix : LengthPrefix) -> usize {
+        self.as_str().bytes_size_prefixed(prefix)
+    }
+
+    #[inline]
+    fn serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> usize {
+        self.as_str().serialize_prefixed(buffer, prefix)
+    }
+
+    #[inline]
+    fn try_serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> Result<usize, Error> {
+        self.as_str().try_serialize_prefixed(buffer, prefix)
+    }
+
+    #[inline]
+    fn serialize_stream(&self, stream : &mut StreamWriter<'_>) -> Result<(), Error> {
+        self.as_str().serialize_stream(stream)
+    }
+
+    #[inline]
+    fn try_deserialize_stream_prefixed(stream : &mut StreamReader<'_>, limits : &Limits, prefix : LengthPrefix) -> Result<Self, Error> {
+        stream.read_with_budget(|buffer, budget| Self::try_deserialize_prefixed(buffer, limits, budget, prefix))
+    }
+
+    fn deserialize_prefixed(buffer : &[u8], prefix : LengthPrefix) -> (Self, usize) {
+        // Get size of string to retrieve
+        let (string_size, size) = prefix.deserialize(buffer);
+
+        // Use String::from_utf8 which is SAFE https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf8
+        let string = String::from_utf8(buffer[size..size + string_size].to_vec()).expect("UTF8 String incorrect!");
+
+        (string, size + string_size)
+    }
+
+    fn try_deserialize_prefixed(buffer : &[u8], limits : &Limits, budget : &mut Budget, prefix : LengthPrefix) -> Result<(Self, usize), Error> {
+        // Get size of string to retrieve
+        let (string_size, size) = prefix.try_deserialize(buffer)?;
+
+        // Validate length prefix against limits and remaining bytes of buffer before allocating
+        limits.check_string(0, string_size, buffer.len() - size, budget)?;
+
+        match String::from_utf8(buffer[size..size + string_size].to_vec()) {
+            Ok(string) => Ok((string, size + string_size)),
+            Err(err) => Err(Error::InvalidUtf8 { offset: size + err.utf8_error().valid_up_to() }),
+        }
+    }
+}
@@ -24,7 +24,7 @@
 /// ##### Variadic macro used to [`serialize`](https://en.wikipedia.org/wiki/Serialization) [`compatible variables`](macro.serialize.html#compatible-variabless) into a [`buffer`](https://en.wikipedia.org/wiki/Data_buffer). 
 /// 
 /// # Description
-/// Variadic macro used to [`serialize`](https://en.wikipedia.org/wiki/Serialization) [`bool`], [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html) (except usize, isize), [`String`] and implementors of trait [`Tampon`](trait.Tampon.html).
+/// Variadic macro used to [`serialize`](https://en.wikipedia.org/wiki/Serialization) [`bool`], [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html), [`String`] and implementors of trait [`Tampon`](trait.Tampon.html).
 /// Also work with [`slice`] by using brackets `[]` instead of parenthesis `()`.
 /// 
 /// # Usage
@@ -88,65 +88,80 @@
 /// 
 /// # Compatible variables(s)
 /// * [`bool`]
-/// * All [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html) except [`usize`] and [`isize`]
+/// * [`char`] written as its 4 bytes [`Unicode scalar value`](https://www.unicode.org/glossary/#unicode_scalar_value)
+/// * All [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html), [`usize`] and [`isize`] are written as [`u64`] and [`i64`]
 /// * [`String`] 
 /// * Implementors of trait [`Tampon`](trait.Tampon.html)
-/// * [`slice`] of the above types
+/// * Type aliases, generic and path-qualified types of the above like `type UserId = u32`, `Wrapper<u32>` or `std::string::String`
+/// * [`Option`] of the above types written as a presence byte followed by the value when [`Some`]
+/// * [`tuple`] of up to 12 of the above types written as the concatenation of their members
+/// * [`array`] `[T; N]` of the above types written as its `N` elements without length prefix
+/// * [`slice`] of the above types, including nested containers like `[v]:Vec<f32>` for a `Vec<Vec<f32>>`
+/// * [`str`] and `[T]` written like [`String`] and slices, to serialize borrowed values
+/// * [`VecDeque`](std::collections::VecDeque), [`HashSet`](std::collections::HashSet), [`BTreeSet`](std::collections::BTreeSet), [`HashMap`](std::collections::HashMap) and [`BTreeMap`](std::collections::BTreeMap) of the above types written like slices, maps write each key followed by its value
+/// * Integers of fields typed [`Varint<T>`](struct.Varint.html) or its aliases like [`varu64`](type.varu64.html) are written as variable length [`LEB128`](https://en.wikipedia.org/wiki/LEB128), signed integers with [`ZigZag`](https://protobuf.dev/programming-guides/encoding/#signed-ints)
+/// * Slices of [`bool`] of fields typed [`Packed<bool>`](struct.Packed.html) are written with 8 booleans per byte after the length prefix
 /// 
 /// # Endianness
 /// * [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html) bytes are written as [`little endian`](https://en.wikipedia.org/wiki/Endianness).
+/// * [`usize`] and [`isize`] are always written as 8 bytes [`u64`] and [`i64`], whatever the target pointer width.
+/// * Fields typed [`BigEndian<T>`](struct.BigEndian.html) or its aliases like [`u16be`](type.u16be.html) are written as [`big endian`](https://en.wikipedia.org/wiki/Endianness), including the length prefix of strings and slices.
+/// 
+/// # Length prefix
+/// * Length of [`String`] and slices is written as a [`u32`] prefix by default.
+/// * Fields typed [`Prefixed<P, T>`](struct.Prefixed.html) write the length prefix of `T` and of its nested strings and slices as `P`, one of [`u8`], [`u16`], [`u32`], [`u64`] or [`Leb128`](struct.Leb128.html).
 /// 
 /// # Panic(s)
 /// * Will panic! if `buffer` length is smaller than all sources length combined.
+/// * Will panic! if a length doesn't fit in the prefix of a [`Prefixed<P, T>`](struct.Prefixed.html) field.
 #[macro_export]
 macro_rules! serialize {
     
     // Expression without tail without bytes_read
-    ($buffer:expr,($expr:expr $(,$extra:expr)*):$type:ident) => { {
+    ($buffer:expr,($expr:expr $(,$extra:expr)*):$type:ty) => { {
         let mut temporary_bytes_written = $crate::serialize_parser!($buffer, 0, ($expr $(,$extra)*):$type);
     }};
 
     // Expression with tail without bytes_read
-    ($buffer:expr, ($expr:expr $(,$extra:expr)*):$type:ident, $($tail:tt)*) => {{
+    ($buffer:expr, ($expr:expr $(,$extra:expr)*):$type:ty, $($tail:tt)*) => {{
         let mut temporary_bytes_written = $crate::serialize_parser!($buffer, 0, ($expr $(,$extra)*):$type, $($tail)*);
     }};
 
     // Expression without tail with bytes_written
-    ($buffer:expr, $bytes_written:ident, ($expr:expr $(,$extra:expr)*):$type:ident) => {
+    ($buffer:expr, $bytes_written:ident, ($expr:expr $(,$extra:expr)*):$type:ty) => {
         // Dispatch to parser and get bytes_written
         let mut $bytes_written = $crate::serialize_parser!($buffer, 0, ($expr $(,$extra)*):$type);
     };
 
     // Expression with tail with bytes_written
-    ($buffer:expr, $bytes_written:ident, ($expr:expr $(,$extra:expr)*):$type:ident, $($tail:tt)*) => {
+    ($buffer:expr, $bytes_written:ident, ($expr:expr $(,$extra:expr)*):$type:ty, $($tail:tt)*) => {
         // Dispatch to parser and get bytes_written
         let mut $bytes_written = $crate::serialize_parser!($buffer, 0, ($expr $(,$extra)*):$type, $($tail)*);
     };
 
 
     // Slice without tail without bytes_read
-    ($buffer:expr, [$expr:expr $(,$extra:expr)*]:$type:ident) => { {
+    ($buffer:expr, [$expr:expr $(,$extra:expr)*]:$type:ty) => { {
         let mut temporary_bytes_written = $crate::serialize_parser!($buffer, 0, [$expr $(,$extra)*]:$type);
     }};
 
     // Slice with tail without bytes_read
-    ($buffer:expr, [$expr:expr $(,$extra:expr)*]:$type:ident, $($tail:tt)*) => {{
+    ($buffer:expr, [$expr:expr $(,$extra:expr)*]:$type:ty, $($tail:tt)*) => {{
         let mut temporary_bytes_written = $crate::serialize_parser!($buffer, 0, [$expr $(,$extra)*]:$type, $($tail)*);
     }};
 
     // Slice without tail with bytes_written
-    ($buffer:expr, $bytes_written:ident, [$expr:expr $(,$extra:expr)*]:$type:ident) => {
+    ($buffer:expr, $bytes_written:ident, [$expr:expr $(,$extra:expr)*]:$type:ty) => {
         // Dispatch to parser and get bytes_written
         let mut $bytes_written = $crate::serialize_parser!($buffer, 0, [$expr $(,$extra)*]:$type);
     };
 
     // Slice with tail with bytes_written
-    ($buffer:expr, $bytes_written:ident, [$expr:expr $(,$extra:expr)*]:$type:ident, $($tail:tt)*) => {
+    ($buffer:expr, $bytes_written:ident, [$expr:expr $(,$extra:expr)*]:$type:ty, $($tail:tt)*) => {
         // Dispatch to parser and get bytes_written
         let mut $bytes_written = $crate::serialize_parser!($buffer, 0, [$expr $(,$extra)*]:$type, $($tail)*);
     };
 
-
 }
 
 /// Hidden extension of the serialize! macro. Not meant to be used directly (although it will still work).
@@ -156,7 +171,7 @@ macro_rules! serialize_parser {
     // Macro built with Incremental TT munchers pattern : https://danielkeep.github.io/tlborm/book/pat-incremental-tt-munchers.html
 
     // Expression without tail
-    ($buffer:expr, $index:expr, ($expr:expr $(,$extra:expr)*):$type:ident) => {{
+    ($buffer:expr, $index:expr, ($expr:expr $(,$extra:expr)*):$type:ty) => {{
         let buffer_size = $buffer.len();
         // Init bytes_copied with the expression
         let mut bytes_copied = $crate::serialize_retriever!($buffer[$index..buffer_size], $expr => $type);
@@ -168,7 +183,7 @@ macro_rules! serialize_parser {
     } as usize };
 
     // Expression with tail
-    ($buffer:expr, $index:expr, ($expr:expr $(,$extra:expr)*):$type:ident, $($tail:tt)*) => {{
+    ($buffer:expr, $index:expr, ($expr:expr $(,$extra:expr)*):$type:ty, $($tail:tt)*) => {{
         let buffer_size = $buffer.len();
         // Init bytes_copied with the expression
         let mut bytes_copied = $crate::serialize_retriever!($buffer[$index..buffer_size], $expr => $type);
@@ -184,7 +199,7 @@ macro_rules! serialize_parser {
     } as usize };
 
     // Slice without tail
-    ($buffer:expr, $index:expr, [$expr:expr $(,$extra:expr)*]:$type:ident) => {{
+    ($buffer:expr, $index:expr, [$expr:expr $(,$extra:expr)*]:$type:ty) => {{
         let buffer_size = $buffer.len();
         let mut bytes_copied = 0;
 
@@ -199,7 +214,7 @@ macro_rules! serialize_parser {
     } as usize };
 
     // Slice with tail
-    ($buffer:expr, $index:expr, [$expr:expr $(,$extra:expr)*]:$type:ident, $($tail:tt)*) => {{
+    ($buffer:expr, $index:expr, [$expr:expr $(,$extra:expr)*]:$type:ty, $($tail:tt)*) => {{
         let buffer_size = $buffer.len();
         let mut bytes_copied = 0;
 
@@ -223,157 +238,17 @@ macro_rules! serialize_parser {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! serialize_retriever {
+    // Types are dispatched through trait Encode, implemented for implementors of Tampon and markers like BigEndian
 
     // Slice affectator
-    ($buffer:expr, $expr:expr => [$type:ident]) => {{
-        let buffer_size = $buffer.len();
-
-        // Write size of slice
-        let bytes_len = ($expr.len() as u32).to_le_bytes();
-        $buffer[0..bytes_len.len()].copy_from_slice(&bytes_len);
-
-        // Init bytes_copied at bytes_len.len() since we will loop slice
-        let mut bytes_copied = bytes_len.len();
-
-        // Loop and accumulate and element of slice
-        for elem in $expr.iter() {
-            bytes_copied += $crate::serialize_retriever!($buffer[bytes_copied..buffer_size], *elem => $type);
-        } 
-
-        // Return bytes_copied
-        bytes_copied
-
+    ($buffer:expr, $expr:expr => [$type:ty]) => {{
+        // Write size of slice followed by elements. Numerics are copied in bulk, other types are written one after the other
+        #[allow(unused_imports)]
+        use $crate::encoding::{ EncodeValues as _, EncodeBorrowed as _ };
+        (&$crate::encoding::Elements::<$type, _>::new(&$expr[..])).serialize_slice(&mut $buffer)
     } as usize} ;
 
-
-    /**********
-    * BOOLEAN *
-    **********/
-    ($buffer:expr, $expr:expr => bool) => {{ 
-        // Translate bytes into u8
-        if($expr) {
-            $crate::serialize_retriever!($buffer, 1 => u8)
-        } else {
-            $crate::serialize_retriever!($buffer, 0 => u8)
-        }
-        
-    } as usize };
-
-
-    /***********
-    * NUMERICS * 
-    ***********/
-    ($buffer:expr, $expr:expr => u8) => {{ 
-        // Transform the expression into bytes
-        let bytes = <u8>::to_le_bytes($expr);
-
-        // Copy to buffer via copy from slice
-        $buffer[0..bytes.len()].copy_from_slice(&bytes);
-
-        // Return size used
-        bytes.len()
-    } as usize };
-
-
-    ($buffer:expr, $expr:expr => u16) => {{ 
-        let bytes = <u16>::to_le_bytes($expr);
-        $buffer[0..bytes.len()].copy_from_slice(&bytes);
-        bytes.len()
-    } as usize };
-
-
-    ($buffer:expr, $expr:expr => u32) => {{ 
-        let bytes = <u32>::to_le_bytes($expr);
-        $buffer[0..bytes.len()].copy_from_slice(&bytes);
-        bytes.len()
-    } as usize };
-
-
-    ($buffer:expr, $expr:expr => u64) => {{ 
-        let bytes = <u64>::to_le_bytes($expr);
-        $buffer[0..bytes.len()].copy_from_slice(&bytes);
-        bytes.len()
-    } as usize };
-
-
-    ($buffer:expr, $expr:expr => u128) => {{ 
-        let bytes = <u128>::to_le_bytes($expr);
-        $buffer[0..bytes.len()].copy_from_slice(&bytes);
-        bytes.len()
-    } as usize };
-
-
-    ($buffer:expr, $expr:expr => f32) => {{ 
-        let bytes = <f32>::to_le_bytes($expr);
-        $buffer[0..bytes.len()].copy_from_slice(&bytes);
-        bytes.len()
-    } as usize };
-
-    ($buffer:expr, $expr:expr => f64) => {{ 
-        let bytes = <f64>::to_le_bytes($expr);
-        $buffer[0..bytes.len()].copy_from_slice(&bytes);
-        bytes.len()
-    } as usize };
-
-
-    ($buffer:expr, $expr:expr => i8) => {{ 
-        let bytes = <i8>::to_le_bytes($expr);
-        $buffer[0..bytes.len()].copy_from_slice(&bytes);
-        bytes.len()
-    } as usize };
-
-
-    ($buffer:expr, $expr:expr => i16) => {{ 
-        let bytes = <i16>::to_le_bytes($expr);
-        $buffer[0..bytes.len()].copy_from_slice(&bytes);
-        bytes.len()
-    } as usize };
-
-
-    ($buffer:expr, $expr:expr => i32) => {{ 
-        let bytes = <i32>::to_le_bytes($expr);
-        $buffer[0..bytes.len()].copy_from_slice(&bytes);
-        bytes.len()
-    } as usize };
-
-
-    ($buffer:expr, $expr:expr => i64) => {{ 
-        let bytes = <i64>::to_le_bytes($expr);
-        $buffer[0..bytes.len()].copy_from_slice(&bytes);
-        bytes.len()
-    } as usize };
-
-
-    ($buffer:expr, $expr:expr => i128) => {{ 
-        let bytes = <i128>::to_le_bytes($expr);
-        $buffer[0..bytes.len()].copy_from_slice(&bytes);
-        bytes.len()
-    } as usize };
-
-    /*********
-    * STRING * 
-    *********/
-    ($buffer:expr, $expr:expr => String) => {{ 
-        
-        // Write size of String
-        let bytes_size = ($expr.len() as u32).to_le_bytes();
-        $buffer[0..bytes_size.len()].copy_from_slice(&bytes_size);
-
-        // Transform String as bytes slice
-        let bytes = $expr.as_bytes();
-
-        // Copy to buffer via copy from slice
-        $buffer[bytes_size.len()..(bytes_size.len() + bytes.len())].copy_from_slice(&bytes);
-
-        // Return size used
-        bytes_size.len() + bytes.len()
-
-    } as usize };
-
-    /***************
-    * TAMPON TRAIT * 
-    ***************/
-    ($buffer:expr, $expr:expr => $tampon:ident) => {{
-        $expr.serialize(&mut $buffer)
+    ($buffer:expr, $expr:expr => $type:ty) => {{
+        <$type as $crate::encoding::Encode>::serialize(&$expr, &mut $buffer)
     } as usize };
 }
\ No newline at end of file
@@ -0,0 +1,131 @@
+/*
+ * @file tampon/serialize_to_writer.rs
+ *
+ * @module tampon
+ *
+ * @brief Macro used to serialize values into a Write through a small internal buffer.
+ *
+ * @details
+ * Macro used to serialize values into a Write through a small internal buffer, without
+ * allocating a buffer of the full size.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ * https://doc.rust-lang.org/std/io/trait.Write.html
+ *
+ * @todo
+ */
+
+/// ##### Variadic macro used to [`serialize`](https://en.wikipedia.org/wiki/Serialization) [`compatible variables`](macro.try_serialize.html#compatible-variabless) into a [`Write`](std::io::Write).
+///
+/// # Description
+/// Streaming counterpart of [`try_serialize!`](macro.try_serialize.html). Values are serialized into an internal buffer of 8 KiB
+/// written into `writer` each time it is full, so writing a big snapshot to a file doesn't need the entire snapshot in memory.
+/// [`String`] and slices bigger than the internal buffer are written piece by piece.
+///
+/// Bytes written are exactly the bytes of [`buffer!`](macro.buffer.html) with the same arguments. `writer` is flushed before returning.
+///
+/// # Usage
+/// `let result = serialize_to_writer!(writer, [0..n](v1, ..., vn):type, [0..n][s1, ..., sn]:type);`
+/// * `writer` - Mutable reference to implementor of [`Write`](std::io::Write) like [`File`](std::fs::File), [`TcpStream`](std::net::TcpStream) or `Vec<u8>`.
+/// * One-to-many `(v1, ..., vn):type` where elements in `parenthesis()` are the variables to be written.
+/// * One-to-many `[s1, ..., sn]:type` where elements in `brackets[]` are the slices to be written.
+///
+/// # Return
+/// `Result<usize, tampon::Error>` with the count of bytes written into writer.
+///
+/// # Example(s)
+/// ```
+/// // Import macro serialize_to_writer and buffer
+/// use tampon::{ serialize_to_writer, buffer };
+///
+/// let a:u32 = 12545566;
+/// let b:String = String::from("Example string");
+/// let c:Vec<u64> = (0..10000).collect();
+///
+/// // Any implementor of Write, like a File
+/// let mut writer:Vec<u8> = Vec::new();
+/// assert_eq!(serialize_to_writer!(&mut writer, (a):u32, (b):String, [c]:u64), Ok(80026));
+///
+/// // Same bytes as buffer!
+/// assert_eq!(writer, buffer!((a):u32, (b):String, [c]:u64));
+/// ```
+///
+/// # Compatible variables(s)
+/// * Same as [`try_serialize!`](macro.try_serialize.html#compatible-variabless).
+///
+/// # Error(s)
+/// * [`Error::Io`](enum.Error.html#variant.Io) if `writer` fails, with the count of bytes written before.
+/// * Any error returned by [`Tampon::try_serialize`](trait.Tampon.html#method.try_serialize) of nested implementors, offset from the start of the stream.
+/// * [`Error::OutOfRange`](enum.Error.html#variant.OutOfRange) if a length doesn't fit in the prefix of a [`Prefixed<P, T>`](struct.Prefixed.html) field.
+#[macro_export]
+macro_rules! serialize_to_writer {
+    ($writer:expr, $($tail:tt)+) => {{
+        let writer:&mut dyn std::io::Write = $writer;
+        let mut stream = $crate::stream::StreamWriter::new(writer);
+
+        // Dispatch to parser inside a closure so errors can use `?`
+        let result:Result<(), $crate::Error> = (|| {
+            $crate::serialize_to_writer_parser!(stream, $($tail)+);
+            Ok(())
+        })();
+
+        result.and_then(|_| stream.finish())
+    }};
+}
+
+/// Hidden extension of the serialize_to_writer! macro. Parse tokens. Not meant to be used directly (although it will still work).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! serialize_to_writer_parser {
+    // Macro built with Incremental TT munchers pattern : https://danielkeep.github.io/tlborm/book/pat-incremental-tt-munchers.html
+    // Types are dispatched through trait Encode, implemented for implementors of Tampon and markers like BigEndian
+
+    // Expression without tail
+    ($stream:expr, ($expr:expr $(,$extra:expr)*):$type:ty) => {
+        $stream.write::<$type>(&$expr)?;
+        $( $stream.write::<$type>(&$extra)?; )*
+    };
+
+    // Expression with tail
+    ($stream:expr, ($expr:expr $(,$extra:expr)*):$type:ty, $($tail:tt)*) => {
+        $stream.write::<$type>(&$expr)?;
+        $( $stream.write::<$type>(&$extra)?; )*
+        $crate::serialize_to_writer_parser!($stream, $($tail)*);
+    };
+
+    // Slice without tail
+    ($stream:expr, [$expr:expr $(,$extra:expr)*]:$type:ty) => {
+        $crate::serialize_to_writer_slice!($stream, $expr, $type);
+        $( $crate::serialize_to_writer_slice!($stream, $extra, $type); )*
+    };
+
+    // Slice with tail
+    ($stream:expr, [$expr:expr $(,$extra:expr)*]:$type:ty, $($tail:tt)*) => {
+        $crate::serialize_to_writer_slice!($stream, $expr, $type);
+        $( $crate::serialize_to_writer_slice!($stream, $extra, $type); )*
+        $crate::serialize_to_writer_parser!($stream, $($tail)*);
+    };
+
+}
+
+/// Hidden extension of the serialize_to_writer! macro. Write a slice. Not meant to be used directly (although it will still work).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! serialize_to_writer_slice {
+    ($stream:expr, $expr:expr, $type:ty) => {
+        $stream.write_slice(|stream| {
+            // Slices of values are written in bulk, slices of references one element after the other
+            #[allow(unused_imports)]
+            use $crate::encoding::{ EncodeValues as _, EncodeBorrowed as _ };
+            (&$crate::encoding::Elements::<$type, _>::new(&$expr[..])).serialize_slice_stream(stream)
+        })?;
+    };
+}
@@ -0,0 +1,429 @@
+/*
+ * @file tampon/stream.rs
+ *
+ * @module tampon
+ *
+ * @brief Hidden streams used to serialize into a Write and deserialize from a Read.
+ *
+ * @details
+ * Hidden streams used by serialize_to_writer!, deserialize_from_reader! and the streaming functions of
+ * Tampon to serialize into a Write and deserialize from a Read through a small internal buffer.
+ *
+ * StreamWriter serializes values in its buffer and writes it when full. Strings and collections bigger
+ * than the buffer are written piece by piece, other values bigger than the buffer are serialized in a
+ * temporary buffer of their size.
+ *
+ * StreamReader reads exactly the bytes needed by values, so nothing after them is consumed from the
+ * Read. Values are decoded from the bytes read so far and missing bytes announced by Error::UnexpectedEnd
+ * and Error::InvalidLength are read before decoding again. Collections, prefixed or not, read their elements one by one,
+ * reading ahead the minimum size of the remaining elements.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use std::io::{ ErrorKind, Read, Write };
+
+use crate::{ Tampon, Error, Limits, Budget, BigEndian, Prefixed, Varint, Packed };
+use crate::encoding::{ Encode, Decode };
+use crate::error::io_error;
+use crate::prefix::PrefixWidth;
+use crate::tampon::slice_capacity;
+
+/// Size in bytes of the internal buffer of streams.
+pub const STREAM_BUFFER_SIZE : usize = 8 * 1024;
+
+/****************
+* STREAM WRITER *
+****************/
+/// Hidden writer serializing values into a [`Write`] through an internal buffer. Not meant to be used directly.
+pub struct StreamWriter<'a> {
+    /// Destination of bytes.
+    writer : &'a mut dyn Write,
+
+    /// Bytes serialized but not written yet.
+    buffer : Vec<u8>,
+
+    /// Count of bytes written into writer.
+    position : usize,
+}
+
+impl<'a> StreamWriter<'a> {
+    /// Create a stream writing into `writer`.
+    pub fn new(writer : &'a mut dyn Write) -> StreamWriter<'a> {
+        StreamWriter { writer, buffer: Vec::with_capacity(STREAM_BUFFER_SIZE), position: 0 }
+    }
+
+    /// Count of bytes serialized, including bytes still in buffer.
+    #[inline]
+    pub fn bytes_written(&self) -> usize {
+        self.position + self.buffer.len()
+    }
+
+    /// Return true if `size` bytes fit in the internal buffer.
+    #[inline]
+    pub fn fits(&self, size : usize) -> bool {
+        size <= STREAM_BUFFER_SIZE
+    }
+
+    /// Serialize value of type `E`.
+    #[inline]
+    pub fn write<E: Encode + ?Sized>(&mut self, value : &E::Value) -> Result<(), Error> {
+        E::serialize_stream(value, self)
+    }
+
+    /// Serialize length prefix followed by elements of a slice with `write`, called with this stream.
+    ///
+    /// Used by serialize_to_writer! to write slices of values and slices of references the same way.
+    #[inline]
+    pub fn write_slice(&mut self, write : impl FnOnce(&mut Self) -> Result<(), Error>) -> Result<(), Error> {
+        write(self)
+    }
+
+    /// Serialize `size` bytes with `serialize`, which returns the bytes count written.
+    pub fn write_with(&mut self, size : usize, serialize : impl FnOnce(&mut [u8]) -> Result<usize, Error>) -> Result<(), Error> {
+        if self.buffer.len() + size > STREAM_BUFFER_SIZE {
+            self.flush()?;
+        }
+
+        let offset = self.bytes_written();
+        if self.fits(size) {
+            // Serialize at the end of internal buffer and remove what wasn't written
+            let start = self.buffer.len();
+            self.buffer.resize(start + size, 0);
+            match serialize(&mut self.buffer[start..]) {
+                Ok(bytes_copied) => self.buffer.truncate(start + bytes_copied),
+                Err(err) => {
+                    self.buffer.truncate(start);
+                    return Err(err.offset_by(offset));
+                },
+            }
+        } else {
+            // Values bigger than internal buffer are serialized in a temporary buffer
+            let mut buffer = vec![0u8; size];
+            let bytes_copied = serialize(&mut buffer).map_err(|err| err.offset_by(offset))?;
+            self.write_bytes(&buffer[..bytes_copied])?;
+        }
+        Ok(())
+    }
+
+    /// Write `bytes` as is.
+    pub fn write_bytes(&mut self, bytes : &[u8]) -> Result<(), Error> {
+        if self.buffer.len() + bytes.len() > STREAM_BUFFER_SIZE {
+            self.flush()?;
+        }
+
+        if self.fits(bytes.len()) {
+            self.buffer.extend_from_slice(bytes);
+        } else {
+            // Big bytes are written without copy
+            self.writer.write_all(bytes).map_err(|err| io_error(self.position, err))?;
+            self.position += bytes.len();
+        }
+        Ok(())
+    }
+
+    /// Write internal buffer into writer.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        self.writer.write_all(&self.buffer).map_err(|err| io_error(self.position, err))?;
+        self.position += self.buffer.len();
+        self.buffer.clear();
+        Ok(())
+    }
+
+    /// Write internal buffer, flush writer and return the bytes count written.
+    pub fn finish(mut self) -> Result<usize, Error> {
+        self.flush()?;
+        self.writer.flush().map_err(|err| io_error(self.position, err))?;
+        Ok(self.position)
+    }
+}
+
+/****************
+* STREAM READER *
+****************/
+/// Hidden reader deserializing values from a [`Read`] without reading past them. Not meant to be used directly.
+pub struct StreamReader<'a> {
+    /// Source of bytes.
+    reader : &'a mut dyn Read,
+
+    /// Bytes read but not deserialized yet.
+    buffer : Vec<u8>,
+
+    /// Count of bytes deserialized.
+    position : usize,
+
+    /// Allocations of values deserialized, counted against limits.
+    budget : Budget,
+}
+
+impl<'a> StreamReader<'a> {
+    /// Create a stream reading from `reader`.
+    pub fn new(reader : &'a mut dyn Read) -> StreamReader<'a> {
+        StreamReader { reader, buffer: Vec::new(), position: 0, budget: Budget::new() }
+    }
+
+    /// Allocation budget shared by all values read from this stream.
+    #[inline]
+    pub fn budget(&mut self) -> &mut Budget {
+        &mut self.budget
+    }
+
+    /// Count of bytes deserialized.
+    #[inline]
+    pub fn bytes_read(&self) -> usize {
+        self.position
+    }
+
+    /// Deserialize value of type `D`.
+    #[inline]
+    pub fn read<D: DecodeStream + ?Sized>(&mut self, limits : &Limits) -> Result<D::Value, Error> {
+        D::try_deserialize_stream(self, limits)
+    }
+
+    /// Deserialize length prefix followed by elements of type `D`.
+    #[inline]
+    pub fn read_slice<D: DecodeStream + ?Sized>(&mut self, limits : &Limits) -> Result<Vec<D::Value>, Error> {
+        D::try_deserialize_slice_stream(self, limits)
+    }
+
+    /// Deserialize a value with `deserialize`, reading missing bytes from reader until it succeeds.
+    ///
+    /// Each attempt decodes the value from its start, so containers read their elements with a call each instead of
+    /// decoding them all again.
+    ///
+    /// # Error(s)
+    /// * Error returned by `deserialize` when reader ends or when it isn't caused by missing bytes, offset by bytes count read.
+    /// * [`Error::Io`] if reader fails.
+    #[inline]
+    pub fn read_with<V>(&mut self, mut deserialize : impl FnMut(&[u8]) -> Result<(V, usize), Error>) -> Result<V, Error> {
+        self.read_with_budget(|buffer, _| deserialize(buffer))
+    }
+
+    /// Deserialize a value allocating with `deserialize`, given the budget of this stream, reading missing bytes from
+    /// reader until it succeeds. Allocations of failed attempts aren't counted in the budget.
+    ///
+    /// # Error(s)
+    /// * Error returned by `deserialize` when reader ends or when it isn't caused by missing bytes, offset by bytes count read.
+    /// * [`Error::Io`] if reader fails.
+    #[inline]
+    pub fn read_with_budget<V>(&mut self, deserialize : impl FnMut(&[u8], &mut Budget) -> Result<(V, usize), Error>) -> Result<V, Error> {
+        self.read_attempts(deserialize, false)
+    }
+
+    /// Deserialize a value of unknown layout with `deserialize`, given the budget of this stream, reading ahead from
+    /// reader until it succeeds.
+    ///
+    /// Unlike [`StreamReader::read_with_budget`], reads after a failed attempt fill the internal buffer size or the
+    /// size of bytes already buffered, so a value isn't decoded again for each of its elements. Reads stop as soon as
+    /// the missing bytes are read, but bytes already available past the value may be read with them.
+    ///
+    /// # Error(s)
+    /// * Error returned by `deserialize` when reader ends or when it isn't caused by missing bytes, offset by bytes count read.
+    /// * [`Error::Io`] if reader fails.
+    #[inline]
+    pub fn read_ahead_with<V>(&mut self, deserialize : impl FnMut(&[u8], &mut Budget) -> Result<(V, usize), Error>) -> Result<V, Error> {
+        self.read_attempts(deserialize, true)
+    }
+
+    /// Decode value with `deserialize` until it succeeds, reading the missing bytes or ahead between attempts.
+    fn read_attempts<V>(&mut self, mut deserialize : impl FnMut(&[u8], &mut Budget) -> Result<(V, usize), Error>, read_ahead : bool) -> Result<V, Error> {
+        // Allocations of failed attempts were dropped and aren't counted again
+        let budget = self.budget.clone();
+        let mut ended = false;
+        loop {
+            self.budget.clone_from(&budget);
+            let err = match deserialize(&self.buffer, &mut self.budget) {
+                Ok((value, bytes_read)) => {
+                    self.buffer.drain(..bytes_read);
+                    self.position += bytes_read;
+                    return Ok(value);
+                },
+                Err(err) => err,
+            };
+
+            let missing = match err {
+                Error::UnexpectedEnd { needed, available, .. } if !ended => needed.saturating_sub(available),
+                Error::InvalidLength { length, available, .. } if !ended => length.saturating_sub(available),
+                _ => return Err(err.offset_by(self.position)),
+            };
+
+            // Error is given by a last decoding of the bytes read before reader ended
+            ended = !if read_ahead { self.fill_ahead(missing.max(1))? } else { self.fill(missing.max(1))? };
+        }
+    }
+
+    /// Read ahead up to `size` bytes, bounded by the internal buffer size, so small elements aren't read one by one.
+    /// `size` must not go past the value being read. Reader ending early is reported by the next read.
+    pub fn prefetch(&mut self, size : usize) -> Result<(), Error> {
+        let size = size.min(STREAM_BUFFER_SIZE);
+        if self.buffer.len() < size {
+            self.fill(size - self.buffer.len())?;
+        }
+        Ok(())
+    }
+
+    /// Read `size` bytes from reader into buffer and return false if reader ended before.
+    fn fill(&mut self, size : usize) -> Result<bool, Error> {
+        // Buffer grows with bytes really read, so a corrupted length can't allocate more than reader holds
+        let offset = self.position + self.buffer.len();
+        let bytes_read = (&mut *self.reader).take(size as u64).read_to_end(&mut self.buffer).map_err(|err| io_error(offset, err))?;
+        Ok(bytes_read == size)
+    }
+
+    /// Read at least `size` bytes from reader into buffer, with reads as big as the internal buffer size or the bytes
+    /// already buffered, and return false if reader ended before.
+    fn fill_ahead(&mut self, size : usize) -> Result<bool, Error> {
+        let mut bytes_read = 0;
+        while bytes_read < size {
+            // Buffer at most doubles with each read, so a corrupted length can't allocate more than reader holds
+            let start = self.buffer.len();
+            self.buffer.resize(start + start.max(STREAM_BUFFER_SIZE), 0);
+            let result = self.reader.read(&mut self.buffer[start..]);
+            self.buffer.truncate(start + result.as_ref().map_or(0, |count| *count));
+
+            match result {
+                Ok(0) => return Ok(false),
+                Ok(count) => bytes_read += count,
+                Err(err) if err.kind() == ErrorKind::Interrupted => {},
+                Err(err) => return Err(io_error(self.position + start, err)),
+            }
+        }
+        Ok(true)
+    }
+}
+
+/****************
+* DECODE STREAM *
+****************/
+/// Hidden trait used by deserialize_from_reader! to read values of type `Value` from a [`StreamReader`].
+/// Values read from a stream can't borrow it, so borrowed types like `&str` don't implement it.
+/// Not meant to be used directly.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be deserialized from a reader by tampon",
+    label = "`{Self}` doesn't implement trait `Tampon`",
+    note = "compatible types are implementors of trait `Tampon`, BigEndian<T>, Prefixed<P, T>, Varint<T> and Packed<T> of types not borrowing the buffer"
+)]
+pub trait DecodeStream {
+    /// Type of values read.
+    type Value;
+
+    /// Read a value from `stream` while enforcing `limits`.
+    fn try_deserialize_stream(stream : &mut StreamReader<'_>, limits : &Limits) -> Result<Self::Value, Error>;
+
+    /// Read length prefix followed by elements from `stream` while enforcing `limits`.
+    fn try_deserialize_slice_stream(stream : &mut StreamReader<'_>, limits : &Limits) -> Result<Vec<Self::Value>, Error>;
+}
+
+/// Implementors of Tampon are read as themselves, collections one element at a time.
+impl<T: Tampon> DecodeStream for T {
+    type Value = T;
+
+    #[inline]
+    fn try_deserialize_stream(stream : &mut StreamReader<'_>, limits : &Limits) -> Result<T, Error> {
+        <T as Tampon>::try_deserialize_stream(stream, limits)
+    }
+
+    #[inline]
+    fn try_deserialize_slice_stream(stream : &mut StreamReader<'_>, limits : &Limits) -> Result<Vec<T>, Error> {
+        <Vec<T> as Tampon>::try_deserialize_stream(stream, limits)
+    }
+}
+
+/// Read length prefix of marker `M` followed by its elements one by one with `element`.
+fn try_deserialize_marker_elements<M, V>(stream : &mut StreamReader<'_>, limits : &Limits,
+    mut element : impl FnMut(&mut StreamReader<'_>, &Limits) -> Result<V, Error>) -> Result<Vec<V>, Error> where M: for<'a> Decode<'a> + ?Sized {
+    let offset = stream.bytes_read();
+    let len = stream.read_with(|buffer| M::try_deserialize_len(buffer))?;
+
+    // Remaining bytes of reader are unknown, only limits can reject the length
+    limits.check_slice(offset, len, M::min_bytes_size(), core::mem::size_of::<V>(), usize::MAX, stream.budget())?;
+    let mut vec = Vec::with_capacity(slice_capacity(len, M::min_bytes_size(), STREAM_BUFFER_SIZE));
+    for index in 0..len {
+        stream.prefetch((len - index).saturating_mul(M::min_bytes_size()))?;
+        vec.push(element(stream, limits)?);
+    }
+    Ok(vec)
+}
+
+/// Implement DecodeStream for markers by decoding each value from the bytes read with Decode.
+/// Slices of markers whose elements aren't written one after the other, like Packed, are decoded at once.
+macro_rules! decode_stream_marker {
+    ($($marker:ty => [$($bounds:tt)*] $slice:ident),*) => {
+        $(
+            impl<V, $($bounds)*> DecodeStream for $marker where $marker: for<'a> Decode<'a, Value = V> {
+                type Value = V;
+
+                #[inline]
+                fn try_deserialize_stream(stream : &mut StreamReader<'_>, limits : &Limits) -> Result<V, Error> {
+                    stream.read_with_budget(|buffer, budget| <$marker as Decode>::try_deserialize_with(buffer, limits, budget))
+                }
+
+                #[inline]
+                fn try_deserialize_slice_stream(stream : &mut StreamReader<'_>, limits : &Limits) -> Result<Vec<V>, Error> {
+                    decode_stream_marker!(@$slice $marker, stream, limits)
+                }
+            }
+        )*
+    };
+
+    (@elements $marker:ty, $stream:expr, $limits:expr) => {
+        try_deserialize_marker_elements::<$marker, V>($stream, $limits, <Self as DecodeStream>::try_deserialize_stream)
+    };
+
+    (@whole $marker:ty, $stream:expr, $limits:expr) => {
+        $stream.read_with_budget(|buffer, budget| <$marker as Decode>::try_deserialize_slice(buffer, $limits, budget))
+    };
+}
+
+decode_stream_marker!(BigEndian<T> => [T: ?Sized] elements, Packed<T> => [T: ?Sized] whole);
+
+/// Prefixed implementors are read like themselves, so their elements are read one by one.
+impl<P: PrefixWidth, T: Tampon> DecodeStream for Prefixed<P, T> {
+    type Value = T;
+
+    #[inline]
+    fn try_deserialize_stream(stream : &mut StreamReader<'_>, limits : &Limits) -> Result<T, Error> {
+        T::try_deserialize_stream_prefixed(stream, limits, P::PREFIX)
+    }
+
+    #[inline]
+    fn try_deserialize_slice_stream(stream : &mut StreamReader<'_>, limits : &Limits) -> Result<Vec<T>, Error> {
+        try_deserialize_marker_elements::<Self, T>(stream, limits, Self::try_deserialize_stream)
+    }
+}
+
+/// Implement DecodeStream for Varint of integers.
+macro_rules! decode_stream_varint {
+    ($($type:ty),*) => {
+        $(
+            decode_stream_marker!(Varint<$type> => [] elements);
+        )*
+    };
+}
+
+decode_stream_varint!(u16, u32, u64, usize, i16, i32, i64, isize);
+
+/// Vec of Varint is read element by element, like `[v]:Varint<T>`.
+impl<T> DecodeStream for Varint<Vec<T>> where Varint<T>: DecodeStream<Value = T> + for<'a> Decode<'a, Value = T> {
+    type Value = Vec<T>;
+
+    #[inline]
+    fn try_deserialize_stream(stream : &mut StreamReader<'_>, limits : &Limits) -> Result<Vec<T>, Error> {
+        <Varint<T> as DecodeStream>::try_deserialize_slice_stream(stream, limits)
+    }
+
+    #[inline]
+    fn try_deserialize_slice_stream(stream : &mut StreamReader<'_>, limits : &Limits) -> Result<Vec<Vec<T>>, Error> {
+        try_deserialize_marker_elements::<Self, Vec<T>>(stream, limits, Self::try_deserialize_stream)
+    }
+}
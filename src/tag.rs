@@ -0,0 +1,140 @@
+/*
+ * @file tampon/tag.rs
+ *
+ * @module tampon
+ *
+ * @brief Contain discriminant tag of enums written before variant fields.
+ *
+ * @details
+ * Contain discriminant tag of enums written before variant fields.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use crate::Error;
+use crate::varint::{ varint_size, write_varint, read_varint, try_read_varint };
+
+/// ##### Width of the discriminant tag written before the fields of an enum variant.
+///
+/// Used by [`tampon_enum!`](macro.tampon_enum.html) and `#[derive(Tampon)]` with `tag = u8|u16|u32|varint`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagWidth {
+    /// Tag written as [`u8`].
+    U8,
+
+    /// Tag written as [`u16`] in little endian.
+    U16,
+
+    /// Tag written as [`u32`] in little endian.
+    U32,
+
+    /// Tag written as [`LEB128`](https://en.wikipedia.org/wiki/LEB128) variable length integer.
+    Varint,
+}
+
+impl TagWidth {
+    /// Maximum tag that fits in width.
+    pub const fn max(self) -> u64 {
+        match self {
+            TagWidth::U8 => u8::MAX as u64,
+            TagWidth::U16 => u16::MAX as u64,
+            TagWidth::U32 => u32::MAX as u64,
+            TagWidth::Varint => u64::MAX,
+        }
+    }
+
+    /// Size in bytes of `tag`.
+    pub fn size(self, tag : u64) -> usize {
+        match self {
+            TagWidth::U8 => core::mem::size_of::<u8>(),
+            TagWidth::U16 => core::mem::size_of::<u16>(),
+            TagWidth::U32 => core::mem::size_of::<u32>(),
+            TagWidth::Varint => varint_size(tag),
+        }
+    }
+
+    /// Serialize `tag` into `buffer` and return the bytes count written.
+    ///
+    /// # Panic(s)
+    /// * Will panic! if `buffer` is too small.
+    /// * Will panic! if `tag` doesn't fit in width.
+    pub fn serialize(self, tag : u64, buffer : &mut [u8]) -> usize {
+        match self.try_serialize(tag, buffer) {
+            Ok(bytes_copied) => bytes_copied,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Serialize `tag` into `buffer` without panicking and return the bytes count written.
+    ///
+    /// # Error(s)
+    /// * [`Error::OutOfRange`] if `tag` doesn't fit in width.
+    /// * [`Error::BufferTooSmall`] if `buffer` is too small.
+    pub fn try_serialize(self, tag : u64, buffer : &mut [u8]) -> Result<usize, Error> {
+        if tag > self.max() {
+            return Err(Error::OutOfRange { offset: 0, value: tag as i128 });
+        }
+
+        let required = self.size(tag);
+        if required > buffer.len() {
+            return Err(Error::BufferTooSmall { required, available: buffer.len() });
+        }
+
+        Ok(match self {
+            TagWidth::U8 => { buffer[0] = tag as u8; 1 },
+            TagWidth::U16 => { buffer[0..2].copy_from_slice(&(tag as u16).to_le_bytes()); 2 },
+            TagWidth::U32 => { buffer[0..4].copy_from_slice(&(tag as u32).to_le_bytes()); 4 },
+            TagWidth::Varint => write_varint(buffer, tag),
+        })
+    }
+
+    /// Deserialize tag from `buffer` and return it with the bytes count read.
+    ///
+    /// # Panic(s)
+    /// * Will panic! if `buffer` is too small.
+    pub fn deserialize(self, buffer : &[u8]) -> (u64, usize) {
+        match self {
+            TagWidth::U8 => (buffer[0] as u64, 1),
+            TagWidth::U16 => (u16::from_le_bytes([buffer[0], buffer[1]]) as u64, 2),
+            TagWidth::U32 => (u32::from_le_bytes([buffer[0], buffer[1], buffer[2], buffer[3]]) as u64, 4),
+            TagWidth::Varint => read_varint(buffer),
+        }
+    }
+
+    /// Deserialize tag from `buffer` without panicking and return it with the bytes count read.
+    pub fn try_deserialize(self, buffer : &[u8]) -> Result<(u64, usize), Error> {
+        match self {
+            TagWidth::U8 => Ok((u8::from_le_bytes(crate::error::read_bytes(buffer, 0)?) as u64, 1)),
+            TagWidth::U16 => Ok((u16::from_le_bytes(crate::error::read_bytes(buffer, 0)?) as u64, 2)),
+            TagWidth::U32 => Ok((u32::from_le_bytes(crate::error::read_bytes(buffer, 0)?) as u64, 4)),
+            TagWidth::Varint => try_read_varint(buffer, 0),
+        }
+    }
+}
+
+/// Hidden function used by tampon_enum! to reject duplicated tags at compile time. Not meant to be used directly.
+#[doc(hidden)]
+pub const fn unique_tags(tags : &[u64]) -> bool {
+    let mut index = 0;
+    while index < tags.len() {
+        let mut other = index + 1;
+        while other < tags.len() {
+            if tags[index] == tags[other] {
+                return false;
+            }
+            other += 1;
+        }
+        index += 1;
+    }
+    true
+}
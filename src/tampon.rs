@@ -22,12 +22,17 @@
  * 
  */
 
+use std::io::{ Read, Write };
+
+use crate::{ Error, Limits, Budget, LengthPrefix };
+use crate::stream::{ StreamWriter, StreamReader };
+
 /// ##### Trait used to [`serialize / deserialize`](https://en.wikipedia.org/wiki/Serialization) object.
 /// This trait must be implemented by object that needs to be [`serialize / deserialize`](https://en.wikipedia.org/wiki/Serialization).
 /// 
 /// Functions can easily be implemented using macros of the same name (see example below).
 /// 
-/// <b>When implementing `Tampon<T>`, `T` should be the object that implement the trait Tampon.</b>
+/// Functions returning a new instance require `Self: Sized`, so `&dyn Tampon` can still be used to get the size and serialize.
 /// 
 /// # Example(s)
 /// ```
@@ -44,10 +49,10 @@
 /// }
 /// 
 /// // Implement Tampon for struct
-/// impl Tampon<TamponExample> for TamponExample {
+/// impl Tampon for TamponExample {
 ///     fn bytes_size(&self) -> usize {
 ///         // Use bytes_size! macro to easily return the size in bytes
-///         bytes_size!((self.f1):u8, (self.f1):u32, (self.f3):f64, [self.v1]:u8, [self.v2]:f64)
+///         bytes_size!((self.f1):u8, (self.f2):u32, (self.f3):f64, [self.v1]:u8, [self.v2]:f64)
 ///     }
 /// 
 ///     fn serialize(&self, buffer : &mut [u8]) -> usize {
@@ -56,7 +61,7 @@
 ///         bytes_copied
 ///     }
 ///
-///     fn deserialize(buffer : &[u8]) -> (TamponExample, usize) {
+///     fn deserialize(buffer : &[u8]) -> (Self, usize) {
 ///         // Use deserialize! macro to deserialize data, get the size with optional parameter
 ///         deserialize!(buffer, bytes_read, (f1):u8, (f2):u32, (f3):f64, [v1]:u8, [v2]:f64);
 ///         // From buffer must return a pair of object + bytes read
@@ -64,7 +69,152 @@
 ///     }
 /// }
 /// ```
-pub trait Tampon<T> {
+/// 
+/// # Derive
+/// With feature `derive`, the implementation can be generated for structs with `#[derive(Tampon)]`. Fields are serialized
+/// in declaration order with the same format as the macros, so derived and hand-written implementations interoperate.
+/// ```ignore
+/// use tampon::Tampon;
+/// 
+/// #[derive(Tampon)]
+/// pub struct TamponExample {
+///     f1:u8,
+///     f2:u32,
+///     f3:f64,
+///     v1:Vec<u8>,
+///     v2:Vec<f64>,
+/// }
+/// ```
+/// 
+/// # Generic example(s)
+/// Macros accept generic and path-qualified implementors like `Packet<Ping>` or `protocol::Header`.
+/// ```
+/// use tampon::{ Tampon, bytes_size, serialize, deserialize, buffer };
+/// 
+/// // Packet with a generic payload
+/// pub struct Packet<P: Tampon> {
+///     id:u16,
+///     payload:P,
+/// }
+/// 
+/// impl<P: Tampon> Tampon for Packet<P> {
+///     fn bytes_size(&self) -> usize {
+///         bytes_size!((self.id):u16, (self.payload):P)
+///     }
+/// 
+///     fn serialize(&self, buffer : &mut [u8]) -> usize {
+///         serialize!(buffer, bytes_copied, (self.id):u16, (self.payload):P);
+///         bytes_copied
+///     }
+///
+///     fn deserialize(buffer : &[u8]) -> (Self, usize) {
+///         deserialize!(buffer, bytes_read, (id):u16, (payload):P);
+///         (Packet { id, payload }, bytes_read)
+///     }
+/// }
+/// 
+/// # #[derive(Debug, PartialEq)] pub struct Ping(u32);
+/// # impl Tampon for Ping {
+/// #     fn bytes_size(&self) -> usize { bytes_size!((self.0):u32) }
+/// #     fn serialize(&self, buffer : &mut [u8]) -> usize { serialize!(buffer, bytes_copied, (self.0):u32); bytes_copied }
+/// #     fn deserialize(buffer : &[u8]) -> (Self, usize) { deserialize!(buffer, bytes_read, (value):u32); (Ping(value), bytes_read) }
+/// # }
+/// let packets = vec![Packet { id: 1, payload: Ping(10) }, Packet { id: 2, payload: Ping(20) }];
+/// let buffer = buffer!([packets]:Packet<Ping>);
+/// 
+/// deserialize!(buffer, [copy]:Packet<Ping>);
+/// assert_eq!(copy[1].payload, Ping(20));
+/// ```
+/// 
+/// # Implementor(s)
+/// Trait is implemented for [`bool`], [`char`], all [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html),
+/// [`String`], [`Option<T>`], tuples, arrays `[T; N]`, [`Vec<T>`], standard maps and sets and [`Sorted`](struct.Sorted.html) of implementors, with the same encoding as macros. Generic code can accept them directly.
+/// [`str`] and slices `[T]` only serialize, they are deserialized by [`TamponBorrow`](trait.TamponBorrow.html) as `&str` and `&[u8]`.
+/// ```
+/// use tampon::{ Tampon, buffer };
+/// 
+/// // Serialize any implementor into a new buffer
+/// fn send<M: Tampon>(message : &M) -> Vec<u8> {
+///     let mut buffer = vec![0u8; message.bytes_size()];
+///     message.serialize(&mut buffer);
+///     buffer
+/// }
+/// 
+/// let a:u64 = 42;
+/// let b = String::from("tampon");
+/// let c:Vec<u16> = vec![1, 2, 3];
+/// 
+/// assert_eq!(send(&a), buffer!((a):u64));
+/// assert_eq!(send(&b), buffer!((b):String));
+/// assert_eq!(send(&c), buffer!([c]:u16));
+/// assert_eq!(Vec::<u16>::deserialize(&send(&c)), (c, 10));
+/// ```
+/// 
+/// # Unsupported type(s)
+/// Macros dispatch every type through trait `Tampon`, so using a type that doesn't implement it fails to compile
+/// with an error naming the type.
+/// ```compile_fail
+/// use tampon::bytes_size;
+/// 
+/// pub struct NotTampon;
+/// 
+/// // error: `NotTampon` can't be serialized by tampon
+/// let size = bytes_size!((NotTampon):NotTampon);
+/// ```
+/// 
+/// # Migration
+/// Implementations of the previous `Tampon<T>` trait keep working by importing [`legacy::Tampon`](legacy/trait.Tampon.html)
+/// instead, since all of its implementors also implement [`Tampon`]. To migrate, remove `<T>` from `impl Tampon<T> for T`
+/// and replace `T` by `Self` in return types.
+/// 
+/// # Fallible example(s)
+/// Functions [`Tampon::try_serialize`] and [`Tampon::try_deserialize`] can be implemented to validate fields
+/// and return an [`Error`] instead of panicking. Errors of nested implementors are propagated by the `try_` macros.
+/// ```
+/// // Import trait Tampon, Error and macros
+/// use tampon::{ Tampon, Error, bytes_size, serialize, deserialize, try_deserialize };
+/// 
+/// // Percentage that must be between 0 and 100
+/// pub struct Percent(u8);
+/// 
+/// impl Tampon for Percent {
+///     fn bytes_size(&self) -> usize {
+///         bytes_size!((self.0):u8)
+///     }
+/// 
+///     fn serialize(&self, buffer : &mut [u8]) -> usize {
+///         serialize!(buffer, bytes_copied, (self.0):u8);
+///         bytes_copied
+///     }
+///
+///     fn deserialize(buffer : &[u8]) -> (Self, usize) {
+///         deserialize!(buffer, bytes_read, (value):u8);
+///         (Percent(value), bytes_read)
+///     }
+/// 
+///     fn try_deserialize(buffer : &[u8]) -> Result<(Self, usize), Error> {
+///         try_deserialize!(buffer, bytes_read, (value):u8);
+///         if value > 100 {
+///             return Err(Error::InvalidValue { offset: 0, reason: format!("{} is not a percentage", value) });
+///         }
+///         Ok((Percent(value), bytes_read))
+///     }
+/// }
+/// 
+/// // Error of 3rd percentage is returned with its offset
+/// let buffer:Vec<u8> = vec![3, 0, 0, 0, 50, 100, 150];
+/// let result = (|| -> Result<Vec<Percent>, Error> {
+///     try_deserialize!(buffer, [percents]:Percent);
+///     Ok(percents)
+/// })();
+/// assert_eq!(result.err(), Some(Error::InvalidValue { offset: 6, reason: String::from("150 is not a percentage") }));
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be serialized by tampon",
+    label = "`{Self}` doesn't implement trait `Tampon`",
+    note = "compatible types are bool, char, numeric types, String, Option, tuples, arrays, Vec, maps, sets and implementors of trait `Tampon`"
+)]
+pub trait Tampon {
     /// Size of the trait implementation in [`bytes`](https://en.wikipedia.org/wiki/Byte).
     /// 
     /// Use macro [`bytes_size!`] to easily return the size in bytes.
@@ -72,7 +222,7 @@ pub trait Tampon<T> {
     /// # Example(s)
     /// ```ignore
     /// fn bytes_size(&self) -> usize {
-    ///     bytes_size!((self.f1):u8, (self.f1):u32, (self.f3):f64, [self.v1]:u8, [self.v2]:f64)
+    ///     bytes_size!((self.f1):u8, (self.f2):u32, (self.f3):f64, [self.v1]:u8, [self.v2]:f64)
     /// }
     /// ```
     fn bytes_size(&self) -> usize;
@@ -104,7 +254,7 @@ pub trait Tampon<T> {
     /// 
     /// # Example(s)
     /// ```ignore
-    /// fn deserialize(buffer : &[u8]) -> (TamponExample, usize) {
+    /// fn deserialize(buffer : &[u8]) -> (Self, usize) {
     ///     deserialize!(buffer, bytes_read, (f1):u8, (f2):u32, (f3):f64, [v1]:u8, [v2]:f64);
     ///     (TamponExample{f1,f2,f3,v1,v2}, bytes_read)
     /// }
@@ -112,5 +262,317 @@ pub trait Tampon<T> {
     /// 
     /// # Return
     /// Tuple of new object and bytes read from buffer.
-    fn deserialize(buffer : &[u8]) -> (T, usize);
-}
\ No newline at end of file
+    fn deserialize(buffer : &[u8]) -> (Self, usize) where Self: Sized;
+
+    /// Serialize object variable into buffer without panicking.
+    /// 
+    /// Use macro [`try_serialize!`] to easily serialize and propagate errors of nested [`Tampon`] implementors.
+    /// 
+    /// Default implementation verify that `buffer` is big enough with [`Tampon::bytes_size`] before calling [`Tampon::serialize`].
+    /// 
+    /// # Argument(s)
+    /// * `buffer` - Mutable buffer slice reference to serialize into. 
+    /// 
+    /// # Example(s)
+    /// ```ignore
+    /// fn try_serialize(&self, buffer : &mut [u8]) -> Result<usize, Error> {
+    ///     try_serialize!(buffer, (self.f1):u8, (self.f2):u32, (self.f3):f64, [self.v1]:u8, [self.v2]:f64)
+    /// }
+    /// ```
+    /// 
+    /// # Return
+    /// Bytes count written into buffer or [`Error`] if serialization failed.
+    fn try_serialize(&self, buffer : &mut [u8]) -> Result<usize, Error> {
+        let required = self.bytes_size();
+
+        if required > buffer.len() {
+            Err(Error::BufferTooSmall { required, available: buffer.len() })
+        } else {
+            Ok(self.serialize(buffer))
+        }
+    }
+
+    /// Deserialize a new variable instance from buffer without panicking and return it with bytes read.
+    /// 
+    /// Use macro [`try_deserialize!`] to easily deserialize and propagate errors of nested [`Tampon`] implementors.
+    /// Validation of fields can be done here by returning [`Error::InvalidValue`].
+    /// 
+    /// <b>Default implementation calls [`Tampon::deserialize`] and will panic! on invalid buffer.</b> It should be overridden
+    /// by implementors that deserialize untrusted buffers.
+    /// 
+    /// # Argument(s)
+    /// * `buffer` - Non-mutable buffer slice reference to deserialize from. 
+    /// 
+    /// # Example(s)
+    /// ```ignore
+    /// fn try_deserialize(buffer : &[u8]) -> Result<(Self, usize), Error> {
+    ///     try_deserialize!(buffer, bytes_read, (f1):u8, (f2):u32, (f3):f64, [v1]:u8, [v2]:f64);
+    ///     Ok((TamponExample{f1,f2,f3,v1,v2}, bytes_read))
+    /// }
+    /// ```
+    /// 
+    /// # Return
+    /// Tuple of new object and bytes read from buffer or [`Error`] if deserialization failed.
+    fn try_deserialize(buffer : &[u8]) -> Result<(Self, usize), Error> where Self: Sized {
+        Ok(Self::deserialize(buffer))
+    }
+
+    /// Deserialize a new variable instance from buffer without panicking, bounded by [`Limits`], and return it with bytes read.
+    /// 
+    /// Called by [`try_deserialize!`] for nested implementors so that all values share the same [`Limits`] and [`Budget`].
+    /// Implementors containing slices or strings should override it and forward `limits` and `budget` to [`try_deserialize!`].
+    /// 
+    /// Default implementation calls [`Tampon::try_deserialize`] and ignores `limits` and `budget`.
+    /// 
+    /// # Argument(s)
+    /// * `buffer` - Non-mutable buffer slice reference to deserialize from. 
+    /// * `limits` - Limits of slices, strings and total allocation.
+    /// * `budget` - Bytes allocated so far by the deserialization, counted against [`Limits::max_alloc`].
+    /// 
+    /// # Example(s)
+    /// ```ignore
+    /// fn try_deserialize_with(buffer : &[u8], limits : &Limits, budget : &mut Budget) -> Result<(Self, usize), Error> {
+    ///     try_deserialize!(buffer, bytes_read, limits = limits, budget = budget, (f1):u8, (f2):u32, (f3):f64, [v1]:u8, [v2]:f64);
+    ///     Ok((TamponExample{f1,f2,f3,v1,v2}, bytes_read))
+    /// }
+    /// ```
+    /// 
+    /// # Return
+    /// Tuple of new object and bytes read from buffer or [`Error`] if deserialization failed.
+    fn try_deserialize_with(buffer : &[u8], limits : &Limits, budget : &mut Budget) -> Result<(Self, usize), Error> where Self: Sized {
+        let _ = (limits, budget);
+        Self::try_deserialize(buffer)
+    }
+
+    /// Return true if [`Tampon::try_deserialize`] or [`Tampon::try_deserialize_with`] is overridden and doesn't panic
+    /// on a truncated buffer.
+    /// 
+    /// Implementors are read from a reader by decoding the bytes read so far until enough are available, so
+    /// [`Tampon::deserialize_from_reader`] returns an [`Error::InvalidValue`] instead of reading implementors returning
+    /// false. Implementors overriding [`Tampon::try_deserialize`] or [`Tampon::try_deserialize_with`] should override it
+    /// to return true. Derived implementors return true, containers return true if their elements do.
+    /// 
+    /// # Example(s)
+    /// ```ignore
+    /// fn is_fallible() -> bool {
+    ///     true
+    /// }
+    /// ```
+    fn is_fallible() -> bool where Self: Sized {
+        false
+    }
+
+    /// Serialize object into `writer` through a small internal buffer, without creating a buffer of [`Tampon::bytes_size`].
+    /// 
+    /// Strings and collections bigger than the internal buffer are written piece by piece. Other implementors are
+    /// serialized with [`Tampon::try_serialize`], in a temporary buffer of their size when bigger than the internal buffer.
+    /// 
+    /// # Argument(s)
+    /// * `writer` - Destination implementing [`Write`], like a [`File`](std::fs::File) or a [`TcpStream`](std::net::TcpStream).
+    /// 
+    /// # Example(s)
+    /// ```ignore
+    /// let mut file = std::fs::File::create("snapshot.bin")?;
+    /// let bytes_written = snapshot.serialize_to_writer(&mut file)?;
+    /// ```
+    /// 
+    /// # Return
+    /// Bytes count written into writer or [`Error`] if serialization failed, [`Error::Io`] if writer failed.
+    fn serialize_to_writer(&self, writer : &mut dyn Write) -> Result<usize, Error> {
+        let mut stream = StreamWriter::new(writer);
+        self.serialize_stream(&mut stream)?;
+        stream.finish()
+    }
+
+    /// Deserialize a new variable instance from `reader` and return it with bytes read.
+    /// 
+    /// Only the bytes of the instance are read, so other values written after it can be read from the same reader.
+    /// Hand-written implementors are the exception: their layout is unknown until decoded, so they are decoded as a
+    /// whole and bytes already available from reader may be read past them.
+    /// 
+    /// Implementors must override [`Tampon::try_deserialize`] or [`Tampon::try_deserialize_with`] and [`Tampon::is_fallible`]
+    /// to be read from a reader, since bytes are decoded as they are read.
+    /// 
+    /// # Argument(s)
+    /// * `reader` - Source implementing [`Read`], like a [`File`](std::fs::File) or a [`TcpStream`](std::net::TcpStream).
+    /// 
+    /// # Example(s)
+    /// ```ignore
+    /// let mut file = std::fs::File::open("snapshot.bin")?;
+    /// let (snapshot, bytes_read) = Snapshot::deserialize_from_reader(&mut file)?;
+    /// ```
+    /// 
+    /// # Return
+    /// Tuple of new object and bytes read from reader or [`Error`] if deserialization failed, [`Error::Io`] if reader failed.
+    fn deserialize_from_reader(reader : &mut dyn Read) -> Result<(Self, usize), Error> where Self: Sized {
+        Self::deserialize_from_reader_with(reader, &Limits::default())
+    }
+
+    /// Deserialize a new variable instance from `reader`, bounded by [`Limits`], and return it with bytes read.
+    /// 
+    /// Lengths can't be verified against the remaining bytes of a reader, so [`Limits`] should be given when reader is untrusted.
+    /// 
+    /// # Argument(s)
+    /// * `reader` - Source implementing [`Read`].
+    /// * `limits` - Limits of slices, strings and total allocation.
+    /// 
+    /// # Return
+    /// Tuple of new object and bytes read from reader or [`Error`] if deserialization failed, [`Error::Io`] if reader failed.
+    fn deserialize_from_reader_with(reader : &mut dyn Read, limits : &Limits) -> Result<(Self, usize), Error> where Self: Sized {
+        let mut stream = StreamReader::new(reader);
+        let value = Self::try_deserialize_stream(&mut stream, limits)?;
+        Ok((value, stream.bytes_read()))
+    }
+
+    /// Hidden function giving the minimum size in bytes of an implementor in buffer, used by [`try_deserialize!`]
+    /// to reject slice length prefixes bigger than the remaining bytes. Not meant to be implemented directly.
+    #[doc(hidden)]
+    fn min_bytes_size() -> usize where Self: Sized {
+        // Size of implementors is unknown until deserialized
+        0
+    }
+
+    /// Hidden function giving the minimum size in bytes with strings and slices written after a length prefix
+    /// encoded as `prefix`. Not meant to be implemented directly.
+    #[doc(hidden)]
+    fn min_bytes_size_prefixed(prefix : LengthPrefix) -> usize where Self: Sized {
+        let _ = prefix;
+        Self::min_bytes_size()
+    }
+
+    /// Hidden function giving the size in bytes with strings and slices written after a length prefix encoded as `prefix`.
+    /// Overridden by strings and containers. Not meant to be implemented directly.
+    #[doc(hidden)]
+    fn bytes_size_prefixed(&self, prefix : LengthPrefix) -> usize {
+        let _ = prefix;
+        self.bytes_size()
+    }
+
+    /// Hidden function writing strings and slices after a length prefix encoded as `prefix`.
+    /// Overridden by strings and containers. Not meant to be implemented directly.
+    #[doc(hidden)]
+    fn serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> usize {
+        let _ = prefix;
+        self.serialize(buffer)
+    }
+
+    /// Hidden function writing strings and slices after a length prefix encoded as `prefix` without panicking.
+    /// Overridden by strings and containers. Not meant to be implemented directly.
+    #[doc(hidden)]
+    fn try_serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> Result<usize, Error> {
+        let _ = prefix;
+        self.try_serialize(buffer)
+    }
+
+    /// Hidden function reading strings and slices after a length prefix encoded as `prefix`.
+    /// Overridden by strings and containers. Not meant to be implemented directly.
+    #[doc(hidden)]
+    fn deserialize_prefixed(buffer : &[u8], prefix : LengthPrefix) -> (Self, usize) where Self: Sized {
+        let _ = prefix;
+        Self::deserialize(buffer)
+    }
+
+    /// Hidden function reading strings and slices after a length prefix encoded as `prefix` without panicking.
+    /// Overridden by strings and containers. Not meant to be implemented directly.
+    #[doc(hidden)]
+    fn try_deserialize_prefixed(buffer : &[u8], limits : &Limits, budget : &mut Budget, prefix : LengthPrefix) -> Result<(Self, usize), Error> where Self: Sized {
+        let _ = prefix;
+        Self::try_deserialize_with(buffer, limits, budget)
+    }
+
+    /// Hidden function giving the size in bytes of the elements of `slice`, without length prefix.
+    /// Overridden by numeric types. Not meant to be implemented directly.
+    #[doc(hidden)]
+    fn slice_bytes_size(slice : &[Self], prefix : LengthPrefix) -> usize where Self: Sized {
+        slice.iter().map(|elem| elem.bytes_size_prefixed(prefix)).sum::<usize>()
+    }
+
+    /// Hidden function writing the elements of `slice` one after the other, without length prefix.
+    /// Overridden by numeric types to copy in bulk. Not meant to be implemented directly.
+    #[doc(hidden)]
+    fn serialize_slice(slice : &[Self], buffer : &mut [u8], prefix : LengthPrefix) -> usize where Self: Sized {
+        let mut bytes_copied = 0;
+        for elem in slice.iter() {
+            bytes_copied += elem.serialize_prefixed(&mut buffer[bytes_copied..], prefix);
+        }
+        bytes_copied
+    }
+
+    /// Hidden function writing the elements of `slice` without length prefix and without panicking.
+    /// Overridden by numeric types to copy in bulk. Not meant to be implemented directly.
+    #[doc(hidden)]
+    fn try_serialize_slice(slice : &[Self], buffer : &mut [u8], prefix : LengthPrefix) -> Result<usize, Error> where Self: Sized {
+        // Errors of elements are relative to their own buffer and need to be offset
+        let mut bytes_copied = 0;
+        for elem in slice.iter() {
+            let offset = bytes_copied;
+            bytes_copied += elem.try_serialize_prefixed(&mut buffer[offset..], prefix).map_err(|err| err.offset_by(offset))?;
+        }
+        Ok(bytes_copied)
+    }
+
+    /// Hidden function reading `len` elements written one after the other into a vector allocated once.
+    /// Overridden by numeric types to copy in bulk. Not meant to be implemented directly.
+    #[doc(hidden)]
+    fn deserialize_slice(buffer : &[u8], len : usize, prefix : LengthPrefix) -> (Vec<Self>, usize) where Self: Sized {
+        let mut vec = Vec::with_capacity(slice_capacity(len, Self::min_bytes_size_prefixed(prefix), buffer.len()));
+        let mut bytes_read = 0;
+        for _ in 0..len {
+            let (elem, size) = Self::deserialize_prefixed(&buffer[bytes_read..], prefix);
+            vec.push(elem);
+            bytes_read += size;
+        }
+        (vec, bytes_read)
+    }
+
+    /// Hidden function reading `len` elements written one after the other without panicking.
+    /// Overridden by numeric types to copy in bulk. Not meant to be implemented directly.
+    #[doc(hidden)]
+    fn try_deserialize_slice(buffer : &[u8], len : usize, limits : &Limits, budget : &mut Budget, prefix : LengthPrefix) -> Result<(Vec<Self>, usize), Error> where Self: Sized {
+        // Error will be returned as soon as buffer ends.
+        let mut vec = Vec::with_capacity(slice_capacity(len, Self::min_bytes_size_prefixed(prefix), buffer.len()));
+        let mut bytes_read = 0;
+        for _ in 0..len {
+            let offset = bytes_read;
+            let (elem, size) = Self::try_deserialize_prefixed(&buffer[offset..], limits, budget, prefix).map_err(|err| err.offset_by(offset))?;
+            vec.push(elem);
+            bytes_read += size;
+        }
+        Ok((vec, bytes_read))
+    }
+
+    /// Hidden function writing object into `stream`. Overridden by strings and collections to write elements one by one
+    /// when bigger than the internal buffer. Not meant to be implemented directly.
+    #[doc(hidden)]
+    fn serialize_stream(&self, stream : &mut StreamWriter<'_>) -> Result<(), Error> {
+        stream.write_with(self.bytes_size(), |buffer| self.try_serialize(buffer))
+    }
+
+    /// Hidden function reading object from `stream`. Overridden by primitives to read their bytes only and by collections
+    /// to read elements one by one. Not meant to be implemented directly.
+    #[doc(hidden)]
+    fn try_deserialize_stream(stream : &mut StreamReader<'_>, limits : &Limits) -> Result<Self, Error> where Self: Sized {
+        // Default try_deserialize calls deserialize, which panics on the bytes read so far
+        if !Self::is_fallible() {
+            return Err(Error::InvalidValue { offset: stream.bytes_read(), reason: String::from("implementor of Tampon without try_deserialize can't be read from a reader") });
+        }
+        // Layout is unknown until decoded, so bytes are read ahead instead of decoding again for each missing element
+        stream.read_ahead_with(|buffer, budget| Self::try_deserialize_with(buffer, limits, budget))
+    }
+
+    /// Hidden function reading strings and slices after a length prefix encoded as `prefix` from `stream`.
+    /// Overridden by strings and containers to read elements one by one. Not meant to be implemented directly.
+    #[doc(hidden)]
+    fn try_deserialize_stream_prefixed(stream : &mut StreamReader<'_>, limits : &Limits, prefix : LengthPrefix) -> Result<Self, Error> where Self: Sized {
+        let _ = prefix;
+        Self::try_deserialize_stream(stream, limits)
+    }
+}
+
+/// Hidden function giving the capacity to allocate for `len` elements of at least `min_element_size` bytes
+/// read from `available` bytes, so an untrusted length prefix can't allocate more than the buffer can hold.
+/// Not meant to be used directly.
+#[doc(hidden)]
+pub fn slice_capacity(len : usize, min_element_size : usize, available : usize) -> usize {
+    len.min(available / min_element_size.max(1))
+}
@@ -0,0 +1,327 @@
+/*
+ * @file tampon/tampon_enum.rs
+ *
+ * @module tampon
+ *
+ * @brief Macro used to declare an enum implementing Tampon trait with a discriminant tag.
+ *
+ * @details
+ * Macro used to declare an enum implementing Tampon trait with a discriminant tag.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+/// ##### Macro used to declare an enum implementing trait [`Tampon`](trait.Tampon.html) so it can be used with [`serialize!`](macro.serialize.html) and [`deserialize!`](macro.deserialize.html).
+///
+/// # Description
+/// Each variant is written as a discriminant `tag` followed by its fields in declaration order. Unit, tuple and struct
+/// variants are supported. Deserializing an unknown `tag` returns [`Error::UnknownTag`](enum.Error.html#variant.UnknownTag)
+/// with [`Tampon::try_deserialize`](trait.Tampon.html#method.try_deserialize) and panic! with [`Tampon::deserialize`](trait.Tampon.html#method.deserialize).
+///
+/// # Usage
+/// `tampon_enum!{ [attributes] [visibility] enum Name : width { Variant[(type, ...) | { field:type, ...}] = tag, ... } }`
+/// * `width` - Width of tag written before fields. Either [`u8`], [`u16`], [`u32`] or `varint`.
+/// * `type` - [`Compatible variables`](macro.tampon_enum.html#compatible-variabless) or `[type]` for a [`Vec`] written as [`slice`].
+/// * `tag` - Integer literal written before the fields of variant. Must fit in `width` and be unique, verified at compile time.
+///
+/// # Example(s)
+/// ```
+/// // Import macro tampon_enum, buffer, deserialize and trait Tampon
+/// use tampon::{ tampon_enum, buffer, deserialize, Tampon };
+///
+/// tampon_enum! {
+///     #[derive(Debug, PartialEq)]
+///     pub enum Message : u8 {
+///         Ping = 0,
+///         Data(u32, [u8]) = 1,
+///         Login { name: String, tokens: [u32] } = 2,
+///     }
+/// }
+///
+/// let a = Message::Data(7, vec![1, 2, 3]);
+/// let b = Message::Login { name: String::from("user"), tokens: vec![1, 2] };
+///
+/// // Enum is an implementor of Tampon
+/// let buffer = buffer!((a, b):Message);
+/// assert_eq!(buffer[0..12], [1, 7, 0, 0, 0, 3, 0, 0, 0, 1, 2, 3]);
+///
+/// deserialize!(buffer, (c, d):Message);
+/// assert!(a == c && b == d);
+/// ```
+///
+/// # Invalid tag(s)
+/// Tags that don't fit in `width` or are used by more than one variant fail to compile.
+/// ```compile_fail
+/// use tampon::tampon_enum;
+///
+/// tampon_enum! {
+///     // error: tampon_enum! tag 300 doesn't fit in tag width u8
+///     enum Message : u8 {
+///         Ping = 0,
+///         Data(u32) = 300,
+///     }
+/// }
+/// ```
+/// ```compile_fail
+/// use tampon::tampon_enum;
+///
+/// tampon_enum! {
+///     // error: tampon_enum! tags of Message must be unique
+///     enum Message : u8 {
+///         Ping = 1,
+///         Data(u32) = 2,
+///         Pong = 1,
+///     }
+/// }
+/// ```
+///
+/// # Compatible variables(s)
+/// * [`bool`]
+/// * [`char`] written as its 4 bytes [`Unicode scalar value`](https://www.unicode.org/glossary/#unicode_scalar_value)
+/// * All [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html), [`usize`] and [`isize`] are written as [`u64`] and [`i64`]
+/// * [`String`]
+/// * Implementors of trait [`Tampon`](trait.Tampon.html)
+/// * Type aliases, generic and path-qualified types of the above like `type UserId = u32`, `Wrapper<u32>` or `std::string::String`
+/// * [`Option`], [`tuple`] and [`array`] of the above types
+/// * [`slice`] of the above types, including nested containers like `[Vec<f32>]` for a `Vec<Vec<f32>>`
+///
+/// # Endianness
+/// * [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html) and tag bytes are written as [`little endian`](https://en.wikipedia.org/wiki/Endianness).
+#[macro_export]
+macro_rules! tampon_enum {
+    ($(#[$meta:meta])* $vis:vis enum $name:ident : $width:ident { $($variants:tt)* }) => {
+        $crate::tampon_enum_parser!({ $(#[$meta])* $vis enum $name : $width } [] $($variants)*);
+    };
+}
+
+/// Hidden extension of the tampon_enum! macro. Normalize variants then declare enum and implement Tampon.
+/// Not meant to be used directly (although it will still work).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! tampon_enum_parser {
+    // Macro built with Incremental TT munchers pattern : https://danielkeep.github.io/tlborm/book/pat-incremental-tt-munchers.html
+    // Each variant is normalized as ( [definition] [pattern] [binding:type, ...] tag )
+
+    // Unit variant
+    ($head:tt [$($done:tt)*] $variant:ident = $tag:literal $(, $($tail:tt)*)?) => {
+        $crate::tampon_enum_parser!($head [$($done)* ( [$variant] [$variant] [] $tag )] $($($tail)*)?);
+    };
+
+    // Struct variant, fields are normalized one by one
+    ($head:tt [$($done:tt)*] $variant:ident { $($fields:tt)* } = $tag:literal $(, $($tail:tt)*)?) => {
+        $crate::tampon_enum_parser!(@struct $head [$($done)*] $variant $tag [] [$($fields)*] $($($tail)*)?);
+    };
+
+    // Struct field written as slice
+    (@struct $head:tt [$($done:tt)*] $variant:ident $tag:literal [$($bound:tt)*]
+        [$field:ident : [$type:ty] $(, $($fields:tt)*)?] $($tail:tt)*) => {
+        $crate::tampon_enum_parser!(@struct $head [$($done)*] $variant $tag [$($bound)* $field : [slice $type],]
+            [$($($fields)*)?] $($tail)*);
+    };
+
+    // Struct field written as value
+    (@struct $head:tt [$($done:tt)*] $variant:ident $tag:literal [$($bound:tt)*]
+        [$field:ident : $type:ty $(, $($fields:tt)*)?] $($tail:tt)*) => {
+        $crate::tampon_enum_parser!(@struct $head [$($done)*] $variant $tag [$($bound)* $field : [value $type],]
+            [$($($fields)*)?] $($tail)*);
+    };
+
+    // All struct fields normalized
+    (@struct $head:tt [$($done:tt)*] $variant:ident $tag:literal [$($field:ident : $type:tt,)*] [] $($tail:tt)*) => {
+        $crate::tampon_enum_parser!($head [$($done)* (
+            [$variant { $($field : $crate::tampon_enum_type!($type)),* }]
+            [$variant { $($field),* }]
+            [$($field : $type),*] $tag )] $($tail)*);
+    };
+
+    // Tuple variant, fields are bound to names taken from pool
+    ($head:tt [$($done:tt)*] $variant:ident ( $($types:tt)* ) = $tag:literal $(, $($tail:tt)*)?) => {
+        $crate::tampon_enum_parser!(@tuple $head [$($done)*] $variant $tag []
+            [f0 f1 f2 f3 f4 f5 f6 f7 f8 f9 f10 f11 f12 f13 f14 f15] [$($types)*] $($($tail)*)?);
+    };
+
+    // Bind next tuple field written as slice
+    (@tuple $head:tt [$($done:tt)*] $variant:ident $tag:literal [$($bound:tt)*]
+        [$next:ident $($pool:ident)*] [[$type:ty] $(, $($types:tt)*)?] $($tail:tt)*) => {
+        $crate::tampon_enum_parser!(@tuple $head [$($done)*] $variant $tag [$($bound)* $next : [slice $type],]
+            [$($pool)*] [$($($types)*)?] $($tail)*);
+    };
+
+    // Bind next tuple field written as value
+    (@tuple $head:tt [$($done:tt)*] $variant:ident $tag:literal [$($bound:tt)*]
+        [$next:ident $($pool:ident)*] [$type:ty $(, $($types:tt)*)?] $($tail:tt)*) => {
+        $crate::tampon_enum_parser!(@tuple $head [$($done)*] $variant $tag [$($bound)* $next : [value $type],]
+            [$($pool)*] [$($($types)*)?] $($tail)*);
+    };
+
+    // All tuple fields bound
+    (@tuple $head:tt [$($done:tt)*] $variant:ident $tag:literal [$($bound:ident : $btype:tt,)*] [$($pool:ident)*] [] $($tail:tt)*) => {
+        $crate::tampon_enum_parser!($head [$($done)* (
+            [$variant ( $($crate::tampon_enum_type!($btype)),* )]
+            [$variant ( $($bound),* )]
+            [$($bound : $btype),*] $tag )] $($tail)*);
+    };
+
+    // All variants normalized
+    ({ $(#[$meta:meta])* $vis:vis enum $name:ident : $width:ident }
+        [$( ( [$($def:tt)*] [$($pat:tt)*] [$($binding:ident : $type:tt),*] $tag:literal ) )*]) => {
+
+        $(#[$meta])*
+        $vis enum $name {
+            $( $($def)*, )*
+        }
+
+        // Tags are verified at compile time
+        $( const _: () = assert!($tag <= $crate::tampon_enum_width!($width).max(), concat!("tampon_enum! tag ", stringify!($tag), " doesn't fit in tag width ", stringify!($width))); )*
+        const _: () = assert!($crate::tag::unique_tags(&[$($tag),*]), concat!("tampon_enum! tags of ", stringify!($name), " must be unique"));
+
+        impl $crate::Tampon for $name {
+            #[allow(unused_variables)]
+            fn bytes_size(&self) -> usize {
+                match self {
+                    $( Self::$($pat)* => $crate::tampon_enum_width!($width).size($tag)
+                        $( + $crate::tampon_enum_field!(bytes_size, *$binding, $type) )*, )*
+                }
+            }
+
+            fn serialize(&self, buffer : &mut [u8]) -> usize {
+                match self {
+                    $( Self::$($pat)* => {
+                        let mut bytes_copied = $crate::tampon_enum_width!($width).serialize($tag, buffer);
+                        $( bytes_copied += $crate::tampon_enum_field!(serialize, buffer, bytes_copied, *$binding, $type); )*
+                        bytes_copied
+                    }, )*
+                }
+            }
+
+            #[allow(unused_mut)]
+            fn deserialize(buffer : &[u8]) -> ($name, usize) {
+                let (tag, mut bytes_read) = $crate::tampon_enum_width!($width).deserialize(buffer);
+
+                match tag {
+                    $( $tag => {
+                        $( $crate::tampon_enum_field!(deserialize, buffer, bytes_read, $binding, $type); )*
+                        (Self::$($pat)*, bytes_read)
+                    }, )*
+                    _ => panic!("{}", $crate::Error::UnknownTag { offset: 0, tag }),
+                }
+            }
+
+            fn try_serialize(&self, buffer : &mut [u8]) -> Result<usize, $crate::Error> {
+                // Verify size before writing tag
+                let required = $crate::Tampon::bytes_size(self);
+                if required > buffer.len() {
+                    return Err($crate::Error::BufferTooSmall { required, available: buffer.len() });
+                }
+
+                match self {
+                    $( Self::$($pat)* => {
+                        let mut bytes_copied = $crate::tampon_enum_width!($width).try_serialize($tag, buffer)?;
+                        $( bytes_copied += $crate::tampon_enum_field!(try_serialize, buffer, bytes_copied, *$binding, $type); )*
+                        Ok(bytes_copied)
+                    }, )*
+                }
+            }
+
+            fn try_deserialize(buffer : &[u8]) -> Result<($name, usize), $crate::Error> {
+                <Self as $crate::Tampon>::try_deserialize_with(buffer, &$crate::Limits::default(), &mut $crate::Budget::new())
+            }
+
+            #[allow(unused_mut, unused_variables)]
+            fn try_deserialize_with(buffer : &[u8], limits : &$crate::Limits, budget : &mut $crate::Budget) -> Result<($name, usize), $crate::Error> {
+                let (tag, mut bytes_read) = $crate::tampon_enum_width!($width).try_deserialize(buffer)?;
+
+                match tag {
+                    $( $tag => {
+                        $( $crate::tampon_enum_field!(try_deserialize, buffer, bytes_read, limits, budget, $binding, $type); )*
+                        Ok((Self::$($pat)*, bytes_read))
+                    }, )*
+                    _ => Err($crate::Error::UnknownTag { offset: 0, tag }),
+                }
+            }
+
+            #[inline]
+            fn is_fallible() -> bool {
+                true
+            }
+
+            fn serialize_stream(&self, stream : &mut $crate::stream::StreamWriter<'_>) -> Result<(), $crate::Error> {
+                match self {
+                    $( Self::$($pat)* => {
+                        stream.write_with($crate::tampon_enum_width!($width).size($tag), |buffer| $crate::tampon_enum_width!($width).try_serialize($tag, buffer))?;
+                        $( $crate::tampon_enum_field!(serialize_stream, stream, *$binding, $type); )*
+                        Ok(())
+                    }, )*
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn try_deserialize_stream(stream : &mut $crate::stream::StreamReader<'_>, limits : &$crate::Limits) -> Result<$name, $crate::Error> {
+                let offset = stream.bytes_read();
+                let tag = stream.read_with(|buffer| $crate::tampon_enum_width!($width).try_deserialize(buffer))?;
+
+                match tag {
+                    $( $tag => {
+                        $( $crate::tampon_enum_field!(deserialize_stream, stream, limits, $binding, $type); )*
+                        Ok(Self::$($pat)*)
+                    }, )*
+                    _ => Err($crate::Error::UnknownTag { offset, tag }),
+                }
+            }
+        }
+    };
+}
+
+/// Hidden extension of the tampon_enum! macro. Width of tag. Not meant to be used directly (although it will still work).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! tampon_enum_width {
+    (u8) => { $crate::TagWidth::U8 };
+    (u16) => { $crate::TagWidth::U16 };
+    (u32) => { $crate::TagWidth::U32 };
+    (varint) => { $crate::TagWidth::Varint };
+}
+
+/// Hidden extension of the tampon_enum! macro. Type of field. Not meant to be used directly (although it will still work).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! tampon_enum_type {
+    ([slice $type:ty]) => { Vec<$type> };
+    ([value $type:ty]) => { $type };
+}
+
+/// Hidden extension of the tampon_enum! macro. Dispatch field to macros. Not meant to be used directly (although it will still work).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! tampon_enum_field {
+    (bytes_size, $expr:expr, [slice $type:ty]) => { $crate::bytes_size!([$expr]:$type) };
+    (bytes_size, $expr:expr, [value $type:ty]) => { $crate::bytes_size!(($expr):$type) };
+
+    (serialize, $buffer:expr, $index:expr, $expr:expr, [slice $type:ty]) => { $crate::serialize_parser!($buffer, $index, [$expr]:$type) };
+    (serialize, $buffer:expr, $index:expr, $expr:expr, [value $type:ty]) => { $crate::serialize_parser!($buffer, $index, ($expr):$type) };
+
+    (deserialize, $buffer:expr, $bytes_read:expr, $name:ident, [slice $type:ty]) => { $crate::deserialize_parser!($buffer, 0, $bytes_read, [$name]:$type); };
+    (deserialize, $buffer:expr, $bytes_read:expr, $name:ident, [value $type:ty]) => { $crate::deserialize_parser!($buffer, 0, $bytes_read, ($name):$type); };
+
+    (try_serialize, $buffer:expr, $index:expr, $expr:expr, [slice $type:ty]) => { $crate::try_serialize_parser!($buffer, $index, [$expr]:$type) };
+    (try_serialize, $buffer:expr, $index:expr, $expr:expr, [value $type:ty]) => { $crate::try_serialize_parser!($buffer, $index, ($expr):$type) };
+
+    (try_deserialize, $buffer:expr, $bytes_read:expr, $limits:expr, $budget:expr, $name:ident, [slice $type:ty]) => { $crate::try_deserialize_parser!($buffer, $bytes_read, $limits, $budget, [$name]:$type); };
+    (try_deserialize, $buffer:expr, $bytes_read:expr, $limits:expr, $budget:expr, $name:ident, [value $type:ty]) => { $crate::try_deserialize_parser!($buffer, $bytes_read, $limits, $budget, ($name):$type); };
+
+    (serialize_stream, $stream:expr, $expr:expr, [slice $type:ty]) => { $crate::serialize_to_writer_parser!($stream, [$expr]:$type); };
+    (serialize_stream, $stream:expr, $expr:expr, [value $type:ty]) => { $crate::serialize_to_writer_parser!($stream, ($expr):$type); };
+
+    (deserialize_stream, $stream:expr, $limits:expr, $name:ident, [slice $type:ty]) => { $crate::deserialize_from_reader_parser!($stream, $limits, [$name]:$type); };
+    (deserialize_stream, $stream:expr, $limits:expr, $name:ident, [value $type:ty]) => { $crate::deserialize_from_reader_parser!($stream, $limits, ($name):$type); };
+}
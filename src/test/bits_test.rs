@@ -0,0 +1,155 @@
+/*
+ * @file tampon/test/bits_test.rs
+ *
+ * @module tampon::test
+ *
+ * @brief Contains tests for BitWriter and BitReader.
+ * 
+ * @details
+ * Contains tests for BitWriter and BitReader.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ * 
+ * @todo
+ */
+
+use crate::{ BitWriter, BitReader, BitOrder, Error };
+
+// Values written with their width
+static VALUES: [(u64, u32); 7] = [(5, 3), (0xABC, 12), (1, 1), (0, 0), (0x1_FFFF, 17), (u64::MAX, 64), (0x7F, 7)];
+
+#[test]
+fn bits_msb_first() {
+    let mut buffer = [0xFFu8; 2];
+    let mut writer = BitWriter::new(&mut buffer, BitOrder::MsbFirst);
+    writer.write_bits(0b101, 3);
+    writer.write_bits(0b0011, 4);
+    writer.write_bool(true);
+    writer.write_bits(0b01, 2);
+    assert_eq!((writer.position(), writer.bytes_written()), (10, 2));
+    assert_eq!(writer.align(), 2);
+    assert_eq!(buffer, [0b1010_0111, 0b0100_0000]);
+}
+
+#[test]
+fn bits_lsb_first() {
+    let mut buffer = [0xFFu8; 2];
+    let mut writer = BitWriter::new(&mut buffer, BitOrder::LsbFirst);
+    writer.write_bits(0b101, 3);
+    writer.write_bits(0b0011, 4);
+    writer.write_bool(true);
+    writer.write_bits(0b01, 2);
+    assert_eq!(writer.align(), 2);
+    assert_eq!(buffer, [0b1001_1101, 0b0000_0001]);
+
+    // Values crossing bytes keep their low bits first
+    let mut buffer = [0u8; 2];
+    BitWriter::new(&mut buffer, BitOrder::LsbFirst).write_bits(0xABC, 12);
+    assert_eq!(buffer, [0xBC, 0x0A]);
+}
+
+#[test]
+fn bits_round_trip() {
+    for order in [BitOrder::MsbFirst, BitOrder::LsbFirst] {
+        let mut buffer = [0u8; 16];
+        let mut writer = BitWriter::new(&mut buffer, order);
+        for (value, width) in VALUES {
+            writer.write_bits(value, width);
+        }
+        let size = writer.align();
+        assert_eq!(size, 13);
+
+        let mut reader = BitReader::new(&buffer, order);
+        for (value, width) in VALUES {
+            assert_eq!(reader.read_bits(width), value);
+        }
+        assert_eq!((reader.position(), reader.align(), reader.position()), (104, 13, 104));
+    }
+}
+
+#[test]
+fn bits_signed() {
+    let values : [(i64, u32); 6] = [(-1, 1), (-4, 3), (3, 3), (-2048, 12), (i64::MIN, 64), (0, 0)];
+
+    for order in [BitOrder::MsbFirst, BitOrder::LsbFirst] {
+        let mut buffer = [0u8; 11];
+        let mut writer = BitWriter::new(&mut buffer, order);
+        for (value, width) in values {
+            writer.write_signed(value, width);
+        }
+
+        let mut reader = BitReader::new(&buffer, order);
+        for (value, width) in values {
+            assert_eq!(reader.read_signed(width), value);
+        }
+    }
+
+    // Two's complement of 4 bits
+    let mut buffer = [0u8; 1];
+    BitWriter::new(&mut buffer, BitOrder::MsbFirst).write_signed(-6, 4);
+    assert_eq!(buffer, [0b1010_0000]);
+}
+
+#[test]
+fn bits_align() {
+    let buffer = [0b1000_0001, 0b1100_0000];
+    let mut reader = BitReader::new(&buffer, BitOrder::MsbFirst);
+    assert_eq!(reader.align(), 0);
+    assert!(reader.read_bool());
+    assert_eq!(reader.align(), 1);
+    assert_eq!(reader.read_bits(2), 0b11);
+    assert_eq!(reader.bytes_read(), 2);
+
+    // Padding replaces bits of buffer
+    let mut buffer = [0xFFu8; 2];
+    let mut writer = BitWriter::new(&mut buffer, BitOrder::LsbFirst);
+    assert_eq!(writer.align(), 0);
+    writer.write_bits(1, 2);
+    assert_eq!(writer.align(), 1);
+    assert_eq!(buffer, [1, 0xFF]);
+}
+
+#[test]
+fn bits_write_errors() {
+    let mut buffer = [0u8; 2];
+    let mut writer = BitWriter::new(&mut buffer, BitOrder::MsbFirst);
+    writer.write_bits(1, 5);
+
+    assert_eq!(writer.try_write_bits(8, 3), Err(Error::OutOfRange { offset: 0, value: 8 }));
+    assert_eq!(writer.try_write_signed(4, 3), Err(Error::OutOfRange { offset: 0, value: 4 }));
+    assert_eq!(writer.try_write_signed(-5, 3), Err(Error::OutOfRange { offset: 0, value: -5 }));
+    assert_eq!(writer.try_write_bits(0, 65), Err(Error::InvalidValue { offset: 0, reason: String::from("bit width 65 is bigger than 64") }));
+    assert_eq!(writer.try_write_bits(0, 12), Err(Error::BufferTooSmall { required: 3, available: 2 }));
+
+    // Nothing is written on error
+    assert_eq!(writer.position(), 5);
+    assert_eq!(writer.try_write_bits(0x7FF, 11), Ok(()));
+    assert_eq!(writer.try_write_bool(true), Err(Error::BufferTooSmall { required: 3, available: 2 }));
+}
+
+#[test]
+fn bits_read_errors() {
+    let buffer = [0xFFu8; 2];
+    let mut reader = BitReader::new(&buffer, BitOrder::LsbFirst);
+    assert_eq!(reader.try_read_bits(10), Ok(0x3FF));
+
+    assert_eq!(reader.try_read_bits(7), Err(Error::UnexpectedEnd { offset: 1, needed: 2, available: 1 }));
+    assert_eq!(reader.try_read_signed(65), Err(Error::InvalidValue { offset: 1, reason: String::from("bit width 65 is bigger than 64") }));
+    assert_eq!(reader.try_read_signed(6), Ok(-1));
+    assert_eq!(reader.try_read_bool(), Err(Error::UnexpectedEnd { offset: 2, needed: 1, available: 0 }));
+}
+
+#[test]
+#[should_panic]
+fn bits_read_panic() {
+    let buffer = [0u8; 1];
+    BitReader::new(&buffer, BitOrder::MsbFirst).read_bits(9);
+}
@@ -1,5 +1,6 @@
 // generate_buffer() tests
 #[cfg(test)]
+#[allow(clippy::assign_op_pattern, clippy::ptr_arg, clippy::same_item_push)]
 mod generate_test;
 
 // wipe_buffer() tests
@@ -8,4 +9,13 @@ mod wipe_test;
 
 // compare_buffer() tests
 #[cfg(test)]
-mod compare_test;
\ No newline at end of file
+#[allow(clippy::identity_op)]
+mod compare_test;
+
+// varint functions tests
+#[cfg(test)]
+mod varint_test;
+
+// BitWriter and BitReader tests
+#[cfg(test)]
+mod bits_test;
@@ -0,0 +1,76 @@
+/*
+ * @file tampon/test/varint_test.rs
+ *
+ * @module tampon::test
+ *
+ * @brief Contains tests for varint functions.
+ * 
+ * @details
+ * Contains tests for varint functions.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ * 
+ * @todo
+ */
+
+use crate::varint::{ varint_size, write_varint, read_varint, try_read_varint, zigzag_encode, zigzag_decode, VARINT_MAX_SIZE };
+use crate::Error;
+
+// Values at the limits of each size in bytes
+static VALUES: [u64; 8] = [0, 127, 128, 16383, 16384, 300, u32::MAX as u64, u64::MAX];
+
+#[test]
+fn varint_round_trip() {
+    for value in VALUES {
+        let mut buffer = [0u8; VARINT_MAX_SIZE];
+        let size = write_varint(&mut buffer, value);
+
+        assert_eq!(size, varint_size(value));
+        assert_eq!(read_varint(&buffer), (value, size));
+    }
+}
+
+#[test]
+fn varint_encoding() {
+    let mut buffer = [0u8; VARINT_MAX_SIZE];
+
+    assert_eq!(write_varint(&mut buffer, 300), 2);
+    assert_eq!(buffer[0..2], [172, 2]);
+    assert_eq!(varint_size(u64::MAX), VARINT_MAX_SIZE);
+}
+
+#[test]
+fn varint_truncated() {
+    assert_eq!(try_read_varint(&[1, 172], 1), Err(Error::UnexpectedEnd { offset: 2, needed: 1, available: 0 }));
+}
+
+#[test]
+fn varint_overflow() {
+    let buffer = [255u8; 11];
+    assert_eq!(try_read_varint(&buffer, 0), Err(Error::InvalidValue { offset: 0, reason: String::from("varint is bigger than u64") }));
+}
+
+#[test]
+fn varint_overlong() {
+    assert_eq!(try_read_varint(&[0x80, 0x00], 0), Err(Error::InvalidValue { offset: 0, reason: String::from("varint is overlong") }));
+    assert_eq!(try_read_varint(&[7, 0x81, 0x80, 0x00], 1), Err(Error::InvalidValue { offset: 1, reason: String::from("varint is overlong") }));
+    assert_eq!(try_read_varint(&[0x00], 0), Ok((0, 1)));
+}
+
+#[test]
+fn varint_zigzag() {
+    let values : [(i64, u64); 6] = [(0, 0), (-1, 1), (1, 2), (-2, 3), (i64::MAX, u64::MAX - 1), (i64::MIN, u64::MAX)];
+
+    for (signed, unsigned) in values {
+        assert_eq!(zigzag_encode(signed), unsigned);
+        assert_eq!(zigzag_decode(unsigned), signed);
+    }
+}
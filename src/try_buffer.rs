@@ -0,0 +1,102 @@
+/*
+ * @file tampon/try_buffer.rs
+ *
+ * @module tampon
+ *
+ * @brief Macro used to create a buffer limited in size without panicking.
+ *
+ * @details
+ * Macro used to create a buffer limited in size without panicking.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+/// ##### Variadic macro used to create a [`buffer`](https://en.wikipedia.org/wiki/Data_buffer) no bigger than a maximum size and [`serialize`](https://en.wikipedia.org/wiki/Serialization) [`compatible variables`](macro.try_buffer.html#compatible-variabless).
+///
+/// # Description
+/// Fallible counterpart of [`buffer!`](macro.buffer.html) used when the resulting buffer must fit in a fixed size, like a network frame.
+/// The size needed is verified with [`bytes_size!`](macro.bytes_size.html) <b>before</b> anything is allocated.
+///
+/// # Usage
+/// `let result = try_buffer!(max_size, [0..n](v1, ..., vn):type, [0..n][s1, ..., sn]:type);`
+/// * `max_size` - Maximum size in bytes of the created buffer.
+/// * One-to-many `(v1, ..., vn):type` where elements in `parenthesis()` are the variables to be copied into created buffer.
+/// * One-to-many `[s1, ..., sn]:type` where elements in `brackets[]` are the slices to be copied into created buffer.
+///
+/// # Return
+/// `Result<Vec<u8>, tampon::Error>` with the new buffer created with argument(s) serialized.
+///
+/// # Example(s)
+/// ```
+/// // Import macro try_buffer and Error
+/// use tampon::{ try_buffer, Error };
+///
+/// let a:u32 = 12545566;
+/// let b:Vec<f64> = vec![f64::MAX; 50];
+///
+/// // Content fits in a frame of 1024 bytes
+/// let buffer = try_buffer!(1024, (a):u32, [b]:f64);
+/// assert_eq!(buffer.map(|b| b.len()), Ok(408));
+///
+/// // Content doesn't fit in a frame of 128 bytes, fragmentation is needed
+/// let buffer = try_buffer!(128, (a):u32, [b]:f64);
+/// assert_eq!(buffer, Err(Error::BufferTooSmall { required: 408, available: 128 }));
+/// ```
+///
+/// # Compatible variables(s)
+/// * [`bool`]
+/// * [`char`] written as its 4 bytes [`Unicode scalar value`](https://www.unicode.org/glossary/#unicode_scalar_value)
+/// * All [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html), [`usize`] and [`isize`] are written as [`u64`] and [`i64`]
+/// * [`String`]
+/// * Implementors of trait [`Tampon`](trait.Tampon.html)
+/// * Type aliases, generic and path-qualified types of the above like `type UserId = u32`, `Wrapper<u32>` or `std::string::String`
+/// * [`Option`] of the above types written as a presence byte followed by the value when [`Some`]
+/// * [`tuple`] of up to 12 of the above types written as the concatenation of their members
+/// * [`array`] `[T; N]` of the above types written as its `N` elements without length prefix
+/// * [`slice`] of the above types, including nested containers like `[v]:Vec<f32>` for a `Vec<Vec<f32>>`
+/// * [`str`] and `[T]` written like [`String`] and slices, to serialize borrowed values
+/// * [`VecDeque`](std::collections::VecDeque), [`HashSet`](std::collections::HashSet), [`BTreeSet`](std::collections::BTreeSet), [`HashMap`](std::collections::HashMap) and [`BTreeMap`](std::collections::BTreeMap) of the above types written like slices, maps write each key followed by its value
+/// * Integers of fields typed [`Varint<T>`](struct.Varint.html) or its aliases like [`varu64`](type.varu64.html) are written as variable length [`LEB128`](https://en.wikipedia.org/wiki/LEB128), signed integers with [`ZigZag`](https://protobuf.dev/programming-guides/encoding/#signed-ints)
+/// * Slices of [`bool`] of fields typed [`Packed<bool>`](struct.Packed.html) are written with 8 booleans per byte after the length prefix
+///
+/// # Endianness
+/// * [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html) bytes are written as [`little endian`](https://en.wikipedia.org/wiki/Endianness).
+/// * [`usize`] and [`isize`] are always written as 8 bytes [`u64`] and [`i64`], whatever the target pointer width.
+/// * Fields typed [`BigEndian<T>`](struct.BigEndian.html) or its aliases like [`u16be`](type.u16be.html) are written as [`big endian`](https://en.wikipedia.org/wiki/Endianness), including the length prefix of strings and slices.
+///
+/// # Length prefix
+/// * Length of [`String`] and slices is written as a [`u32`] prefix by default.
+/// * Fields typed [`Prefixed<P, T>`](struct.Prefixed.html) write the length prefix of `T` and of its nested strings and slices as `P`, one of [`u8`], [`u16`], [`u32`], [`u64`] or [`Leb128`](struct.Leb128.html).
+///
+/// # Error(s)
+/// * [`Error::BufferTooSmall`](enum.Error.html#variant.BufferTooSmall) if `max_size` is smaller than all sources length combined.
+/// * Any error returned by [`Tampon::try_serialize`](trait.Tampon.html#method.try_serialize) of nested implementors.
+/// * [`Error::OutOfRange`](enum.Error.html#variant.OutOfRange) if a length doesn't fit in the prefix of a [`Prefixed<P, T>`](struct.Prefixed.html) field.
+#[macro_export]
+macro_rules! try_buffer {
+    ($max_size:expr, $($tail:tt)+) => {{
+        // Get size needed for variable serialization
+        let required = $crate::bytes_size!($($tail)+);
+        let available:usize = $max_size;
+
+        let result:Result<Vec<u8>, $crate::Error> = if required > available {
+            Err($crate::Error::BufferTooSmall { required, available })
+        } else {
+            // Create mutable buffer of needed size and serialize variable into it
+            let mut buffer:Vec<u8> = vec![0;required];
+            $crate::try_serialize!(buffer, $($tail)+).map(|_| buffer)
+        };
+
+        result
+    }};
+}
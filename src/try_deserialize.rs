@@ -0,0 +1,211 @@
+/*
+ * @file tampon/try_deserialize.rs
+ *
+ * @module tampon
+ *
+ * @brief Macro used to retrieve values from buffer without panicking on corrupted or truncated buffer.
+ *
+ * @details
+ * Macro used to retrieve values from buffer without panicking on corrupted or truncated buffer.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+/// ##### Variadic macro used to [`deserialize`](https://en.wikipedia.org/wiki/Serialization) [`compatible variables`](macro.try_deserialize.html#compatible-variabless) from a [`buffer`](https://en.wikipedia.org/wiki/Data_buffer) without panicking.
+///
+/// # Description
+/// Fallible counterpart of [`deserialize!`](macro.deserialize.html). Instead of panicking on a truncated or corrupted
+/// [`buffer`](https://en.wikipedia.org/wiki/Data_buffer), the error is returned as [`tampon::Error`](enum.Error.html)
+/// with the offset where decoding failed.
+///
+/// Length prefixes of [`slice`] and [`String`] are verified against the remaining bytes of `buffer` and the optional
+/// [`Limits`](struct.Limits.html) <b>before</b> any allocation happens.
+///
+/// <b>try_deserialize! automatically creates variables when retrieving data.</b> Like the [`?`](https://doc.rust-lang.org/reference/expressions/operator-expr.html#the-question-mark-operator) operator,
+/// it returns `Err` early from the enclosing function, which must return a [`Result`] with an error type implementing `From<tampon::Error>`.
+///
+/// # Usage
+/// `try_deserialize!(buffer, [bytes_read,] [limits = limits, [budget = budget,]] [0..n](v1, ..., vn):type, [0..n][s1, ..., sn]:type);`
+/// * `buffer` - Unmutable reference to [`slice`] of [`u8`] to copy bytes from.
+/// * `bytes_read` - (Optional) Identifier here can be used to get the count of bytes read from buffer.
+/// * `limits = limits` - (Optional) Reference to [`Limits`](struct.Limits.html) bounding slices, strings and total allocation. Passed to nested implementors of [`Tampon`](trait.Tampon.html).
+/// * `budget = budget` - (Optional) Mutable reference to the [`Budget`](struct.Budget.html) counting allocations against `limits`, given by implementors of [`Tampon::try_deserialize_with`](trait.Tampon.html#method.try_deserialize_with) to share the budget of their caller. A new budget is created otherwise.
+/// * One-to-many `(v1, ..., vn):type` where elements in `parenthesis()` are the variables to be read from buffer.
+/// * One-to-many `[s1, ..., sn]:type` where elements in `brackets[]` are the slices to be read from buffer.
+///
+/// # Example(s)
+/// ```
+/// // Import macro try_deserialize and Error
+/// use tampon::{ try_deserialize, Error };
+///
+/// fn read_packet(buffer : &[u8]) -> Result<(u16, String, usize), Error> {
+///     // Deserialize data from buffer. (variable are created during deserialization process)
+///     try_deserialize!(buffer, bytes_read, (a):u16, (b):String);
+///     Ok((a, b, bytes_read))
+/// }
+///
+/// // Valid packet
+/// let buffer: Vec<u8> = vec![202, 17, 4, 0, 0, 0, 84, 101, 115, 116];
+/// assert_eq!(read_packet(&buffer), Ok((4554, String::from("Test"), 10)));
+///
+/// // Truncated packet returns an error instead of panicking
+/// let buffer: Vec<u8> = vec![202, 17, 4, 0, 0];
+/// assert_eq!(read_packet(&buffer), Err(Error::UnexpectedEnd { offset: 2, needed: 4, available: 3 }));
+/// ```
+///
+/// # Compatible variables(s)
+/// * [`bool`]
+/// * [`char`] written as its 4 bytes [`Unicode scalar value`](https://www.unicode.org/glossary/#unicode_scalar_value)
+/// * All [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html), [`usize`] and [`isize`] are written as [`u64`] and [`i64`]
+/// * [`String`]
+/// * Implementors of trait [`Tampon`](trait.Tampon.html)
+/// * Type aliases, generic and path-qualified types of the above like `type UserId = u32`, `Wrapper<u32>` or `std::string::String`
+/// * [`Option`] of the above types written as a presence byte followed by the value when [`Some`]
+/// * [`tuple`] of up to 12 of the above types written as the concatenation of their members
+/// * [`array`] `[T; N]` of the above types written as its `N` elements without length prefix
+/// * [`slice`] of the above types, including nested containers like `[v]:Vec<f32>` for a `Vec<Vec<f32>>`
+/// * [`&str`](str), `&[u8]`, `Cow<str>` and `Cow<[u8]>` borrowed from `buffer` without copy, see [`TamponBorrow`](trait.TamponBorrow.html)
+/// * [`VecDeque`](std::collections::VecDeque), [`HashSet`](std::collections::HashSet), [`BTreeSet`](std::collections::BTreeSet), [`HashMap`](std::collections::HashMap) and [`BTreeMap`](std::collections::BTreeMap) of the above types written like slices, maps write each key followed by its value
+/// * Integers of fields typed [`Varint<T>`](struct.Varint.html) or its aliases like [`varu64`](type.varu64.html) are read as variable length [`LEB128`](https://en.wikipedia.org/wiki/LEB128), signed integers with [`ZigZag`](https://protobuf.dev/programming-guides/encoding/#signed-ints)
+/// * Slices of [`bool`] of fields typed [`Packed<bool>`](struct.Packed.html) are read with 8 booleans per byte after the length prefix
+///
+/// # Endianness
+/// * [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html) bytes are read as [`little endian`](https://en.wikipedia.org/wiki/Endianness).
+/// * Fields typed [`BigEndian<T>`](struct.BigEndian.html) or its aliases like [`u16be`](type.u16be.html) are read as [`big endian`](https://en.wikipedia.org/wiki/Endianness), including the length prefix of strings and slices.
+///
+/// # Length prefix
+/// * Length of [`String`] and slices is read as a [`u32`] prefix by default.
+/// * Fields typed [`Prefixed<P, T>`](struct.Prefixed.html) read the length prefix of `T` and of its nested strings and slices as `P`, one of [`u8`], [`u16`], [`u32`], [`u64`] or [`Leb128`](struct.Leb128.html).
+///
+/// # Error(s)
+/// * [`Error::UnexpectedEnd`](enum.Error.html#variant.UnexpectedEnd) if `buffer` ends before all targets are read.
+/// * [`Error::InvalidUtf8`](enum.Error.html#variant.InvalidUtf8) if a [`String`] isn't valid [`UTF-8`](https://en.wikipedia.org/wiki/UTF-8).
+/// * [`Error::InvalidLength`](enum.Error.html#variant.InvalidLength) if a length prefix is bigger than the remaining bytes.
+/// * [`Error::LimitExceeded`](enum.Error.html#variant.LimitExceeded) if a length prefix or the total allocation is over [`Limits`](struct.Limits.html).
+/// * [`Error::OutOfRange`](enum.Error.html#variant.OutOfRange) if a [`usize`] or [`isize`] doesn't fit the target pointer width or a [`char`] isn't a valid Unicode scalar value.
+/// * [`Error::OutOfRange`](enum.Error.html#variant.OutOfRange) if a [`Varint<T>`](struct.Varint.html) doesn't fit in `T`, and [`Error::InvalidValue`](enum.Error.html#variant.InvalidValue) if it is overlong.
+/// * [`Error::InvalidValue`](enum.Error.html#variant.InvalidValue) if padding bits of a [`Packed<bool>`](struct.Packed.html) slice are set.
+/// * [`Error::DuplicateKey`](enum.Error.html#variant.DuplicateKey) if a map or set contains the same key twice.
+/// * Any error returned by [`Tampon::try_deserialize`](trait.Tampon.html#method.try_deserialize) of nested implementors, offset from the start of `buffer`.
+#[macro_export]
+macro_rules! try_deserialize {
+
+    // With bytes_read, limits and budget
+    ($buffer:expr, $bytes_read:ident, limits = $limits:expr, budget = $budget:expr, $($tail:tt)+) => {
+        // Initialize bytes_read token
+        let mut $bytes_read:usize = 0;
+        let limits:&$crate::Limits = $limits;
+        // Allocations of all targets are counted against the budget of caller
+        $crate::try_deserialize_parser!($buffer, $bytes_read, limits, $budget, $($tail)+);
+    };
+
+    // With limits and budget
+    ($buffer:expr, limits = $limits:expr, budget = $budget:expr, $($tail:tt)+) => {
+        let mut temporary_bytes_read:usize = 0;
+        let limits:&$crate::Limits = $limits;
+        $crate::try_deserialize_parser!($buffer, temporary_bytes_read, limits, $budget, $($tail)+);
+    };
+
+    // With bytes_read and limits
+    ($buffer:expr, $bytes_read:ident, limits = $limits:expr, $($tail:tt)+) => {
+        let mut $bytes_read:usize = 0;
+        let limits:&$crate::Limits = $limits;
+        // Allocations of all targets are counted against the same budget
+        let mut budget = $crate::Budget::new();
+        $crate::try_deserialize_parser!($buffer, $bytes_read, limits, &mut budget, $($tail)+);
+    };
+
+    // With limits
+    ($buffer:expr, limits = $limits:expr, $($tail:tt)+) => {
+        let mut temporary_bytes_read:usize = 0;
+        let limits:&$crate::Limits = $limits;
+        let mut budget = $crate::Budget::new();
+        $crate::try_deserialize_parser!($buffer, temporary_bytes_read, limits, &mut budget, $($tail)+);
+    };
+
+    // With bytes_read
+    ($buffer:expr, $bytes_read:ident, $($tail:tt)+) => {
+        let mut $bytes_read:usize = 0;
+        // Default limits only verify lengths against remaining bytes of buffer
+        let limits = $crate::Limits::default();
+        let mut budget = $crate::Budget::new();
+        $crate::try_deserialize_parser!($buffer, $bytes_read, &limits, &mut budget, $($tail)+);
+    };
+
+    // Without bytes_read
+    ($buffer:expr, $($tail:tt)+) => {
+        let mut temporary_bytes_read:usize = 0;
+        let limits = $crate::Limits::default();
+        let mut budget = $crate::Budget::new();
+        $crate::try_deserialize_parser!($buffer, temporary_bytes_read, &limits, &mut budget, $($tail)+);
+    };
+}
+
+/// Hidden extension of the try_deserialize! macro. Parse tokens. Not meant to be used directly (although it will still work).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! try_deserialize_parser {
+    // Macro built with Incremental TT munchers pattern : https://danielkeep.github.io/tlborm/book/pat-incremental-tt-munchers.html
+
+    // Expression without tail
+    ($buffer:expr, $bytes_read:expr, $limits:expr, $budget:expr, ($name:ident $(,$extra:ident)*):$type:ty) => {
+        $crate::try_deserialize_retriever!($bytes_read, $limits, $budget, $buffer, $name => $type);
+        $( $crate::try_deserialize_retriever!($bytes_read, $limits, $budget, $buffer, $extra => $type); )*
+    };
+
+    // Expression with tail
+    ($buffer:expr, $bytes_read:expr, $limits:expr, $budget:expr, ($name:ident $(,$extra:ident)*):$type:ty, $($tail:tt)*) => {
+        $crate::try_deserialize_retriever!($bytes_read, $limits, $budget, $buffer, $name => $type);
+        $( $crate::try_deserialize_retriever!($bytes_read, $limits, $budget, $buffer, $extra => $type); )*
+        $crate::try_deserialize_parser!($buffer, $bytes_read, $limits, $budget, $($tail)*);
+    };
+
+    // SLICE Without tail
+    ($buffer:expr, $bytes_read:expr, $limits:expr, $budget:expr, [$name:ident $(,$extra:ident)*]:$type:ty) => {
+        $crate::try_deserialize_retriever!($bytes_read, $limits, $budget, $buffer, $name => [$type]);
+        $( $crate::try_deserialize_retriever!($bytes_read, $limits, $budget, $buffer, $extra => [$type]); )*
+    };
+
+    // SLICE With tail
+    ($buffer:expr, $bytes_read:expr, $limits:expr, $budget:expr, [$name:ident $(,$extra:ident)*]:$type:ty, $($tail:tt)*) => {
+        $crate::try_deserialize_retriever!($bytes_read, $limits, $budget, $buffer, $name => [$type]);
+        $( $crate::try_deserialize_retriever!($bytes_read, $limits, $budget, $buffer, $extra => [$type]); )*
+        $crate::try_deserialize_parser!($buffer, $bytes_read, $limits, $budget, $($tail)*);
+    };
+
+}
+
+/// Hidden extension of the try_deserialize! macro. Retrieve value from buffer at offset `$bytes_read`. Not meant to be used directly (although it will still work).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! try_deserialize_retriever {
+    // Types are dispatched through trait Decode, implemented for implementors of Tampon, borrowed types and markers like BigEndian
+
+    // Slice affectator
+    ($bytes_read:expr, $limits:expr, $budget:expr, $buffer:expr, $name:ident => [$type:ty]) => {
+        // Length prefix is validated against limits and remaining bytes before allocating.
+        // Vector is allocated once, numerics are copied in bulk. Error will be returned as soon as buffer ends.
+        let offset = $bytes_read;
+        let temp = <$type as $crate::encoding::Decode>::try_deserialize_slice(&$buffer[offset..], $limits, $budget).map_err(|err| err.offset_by(offset))?;
+        let $name = temp.0;
+        $bytes_read += temp.1;
+    };
+
+    ($bytes_read:expr, $limits:expr, $budget:expr, $buffer:expr, $name:ident => $type:ty) => {
+        // Errors are relative to the buffer of element and need to be offset
+        let offset = $bytes_read;
+        let temp = <$type as $crate::encoding::Decode>::try_deserialize_with(&$buffer[offset..], $limits, $budget).map_err(|err| err.offset_by(offset))?;
+        let $name = temp.0;
+        $bytes_read += temp.1;
+    };
+}
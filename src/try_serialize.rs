@@ -0,0 +1,167 @@
+/*
+ * @file tampon/try_serialize.rs
+ *
+ * @module tampon
+ *
+ * @brief Macro used to fill a buffer without panicking when buffer is too small.
+ *
+ * @details
+ * Macro used to fill a buffer without panicking when buffer is too small.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+/// ##### Variadic macro used to [`serialize`](https://en.wikipedia.org/wiki/Serialization) [`compatible variables`](macro.try_serialize.html#compatible-variabless) into a [`buffer`](https://en.wikipedia.org/wiki/Data_buffer) without panicking.
+///
+/// # Description
+/// Fallible counterpart of [`serialize!`](macro.serialize.html). The size needed is verified with [`bytes_size!`](macro.bytes_size.html)
+/// <b>before</b> anything is written, so the destination `buffer` is left untouched when it is too small.
+///
+/// # Usage
+/// `let result = try_serialize!(buffer, [0..n](v1, ..., vn):type, [0..n][s1, ..., sn]:type);`
+/// * `buffer` - Mutable reference to [`slice`] of [`u8`] to copy bytes into.
+/// * One-to-many `(v1, ..., vn):type` where elements in `parenthesis()` are the variables to be copied into buffer.
+/// * One-to-many `[s1, ..., sn]:type` where elements in `brackets[]` are the slices to be copied into buffer.
+///
+/// # Return
+/// `Result<usize, tampon::Error>` with the count of bytes copied into buffer.
+///
+/// # Example(s)
+/// ```
+/// // Import macro try_serialize and Error
+/// use tampon::{ try_serialize, Error };
+///
+/// let a:u32 = 12545566;
+/// let b:String = String::from("Example string");
+///
+/// // Fixed size frame big enough
+/// let mut frame = [0u8; 64];
+/// assert_eq!(try_serialize!(frame, (a):u32, (b):String), Ok(22));
+///
+/// // Fixed size frame too small, nothing is written
+/// let mut frame = [0u8; 16];
+/// assert_eq!(try_serialize!(frame, (a):u32, (b):String), Err(Error::BufferTooSmall { required: 22, available: 16 }));
+/// assert_eq!(frame, [0u8; 16]);
+/// ```
+///
+/// # Compatible variables(s)
+/// * [`bool`]
+/// * [`char`] written as its 4 bytes [`Unicode scalar value`](https://www.unicode.org/glossary/#unicode_scalar_value)
+/// * All [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html), [`usize`] and [`isize`] are written as [`u64`] and [`i64`]
+/// * [`String`]
+/// * Implementors of trait [`Tampon`](trait.Tampon.html)
+/// * Type aliases, generic and path-qualified types of the above like `type UserId = u32`, `Wrapper<u32>` or `std::string::String`
+/// * [`Option`] of the above types written as a presence byte followed by the value when [`Some`]
+/// * [`tuple`] of up to 12 of the above types written as the concatenation of their members
+/// * [`array`] `[T; N]` of the above types written as its `N` elements without length prefix
+/// * [`slice`] of the above types, including nested containers like `[v]:Vec<f32>` for a `Vec<Vec<f32>>`
+/// * [`str`] and `[T]` written like [`String`] and slices, to serialize borrowed values
+/// * [`VecDeque`](std::collections::VecDeque), [`HashSet`](std::collections::HashSet), [`BTreeSet`](std::collections::BTreeSet), [`HashMap`](std::collections::HashMap) and [`BTreeMap`](std::collections::BTreeMap) of the above types written like slices, maps write each key followed by its value
+/// * Integers of fields typed [`Varint<T>`](struct.Varint.html) or its aliases like [`varu64`](type.varu64.html) are written as variable length [`LEB128`](https://en.wikipedia.org/wiki/LEB128), signed integers with [`ZigZag`](https://protobuf.dev/programming-guides/encoding/#signed-ints)
+/// * Slices of [`bool`] of fields typed [`Packed<bool>`](struct.Packed.html) are written with 8 booleans per byte after the length prefix
+///
+/// # Endianness
+/// * [`Numeric types`](https://doc.rust-lang.org/reference/types/numeric.html) bytes are written as [`little endian`](https://en.wikipedia.org/wiki/Endianness).
+/// * [`usize`] and [`isize`] are always written as 8 bytes [`u64`] and [`i64`], whatever the target pointer width.
+/// * Fields typed [`BigEndian<T>`](struct.BigEndian.html) or its aliases like [`u16be`](type.u16be.html) are written as [`big endian`](https://en.wikipedia.org/wiki/Endianness), including the length prefix of strings and slices.
+///
+/// # Length prefix
+/// * Length of [`String`] and slices is written as a [`u32`] prefix by default.
+/// * Fields typed [`Prefixed<P, T>`](struct.Prefixed.html) write the length prefix of `T` and of its nested strings and slices as `P`, one of [`u8`], [`u16`], [`u32`], [`u64`] or [`Leb128`](struct.Leb128.html).
+///
+/// # Error(s)
+/// * [`Error::BufferTooSmall`](enum.Error.html#variant.BufferTooSmall) if `buffer` length is smaller than all sources length combined.
+/// * Any error returned by [`Tampon::try_serialize`](trait.Tampon.html#method.try_serialize) of nested implementors, offset from the start of `buffer`.
+/// * [`Error::OutOfRange`](enum.Error.html#variant.OutOfRange) if a length doesn't fit in the prefix of a [`Prefixed<P, T>`](struct.Prefixed.html) field.
+#[macro_export]
+macro_rules! try_serialize {
+    ($buffer:expr, $($tail:tt)+) => {{
+        // Get size needed and available before writing anything
+        let required = $crate::bytes_size!($($tail)+);
+        let available = $buffer.len();
+
+        let result:Result<usize, $crate::Error> = if required > available {
+            Err($crate::Error::BufferTooSmall { required, available })
+        } else {
+            // Buffer is big enough, dispatch to parser inside a closure so errors of Tampon implementors can use `?`
+            (|| Ok($crate::try_serialize_parser!($buffer, 0, $($tail)+)))()
+        };
+
+        result
+    }};
+}
+
+/// Hidden extension of the try_serialize! macro. Not meant to be used directly (although it will still work).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! try_serialize_parser {
+    // Macro built with Incremental TT munchers pattern : https://danielkeep.github.io/tlborm/book/pat-incremental-tt-munchers.html
+
+    // Expression without tail
+    ($buffer:expr, $index:expr, ($expr:expr $(,$extra:expr)*):$type:ty) => {{
+        let buffer_size = $buffer.len();
+        let mut bytes_copied = $crate::try_serialize_retriever!($buffer[$index..buffer_size], $index, $expr => $type);
+        $(bytes_copied += $crate::try_serialize_retriever!($buffer[$index + bytes_copied..buffer_size], $index + bytes_copied, $extra => $type); )*
+        bytes_copied
+    } as usize };
+
+    // Expression with tail
+    ($buffer:expr, $index:expr, ($expr:expr $(,$extra:expr)*):$type:ty, $($tail:tt)*) => {{
+        let buffer_size = $buffer.len();
+        let mut bytes_copied = $crate::try_serialize_retriever!($buffer[$index..buffer_size], $index, $expr => $type);
+        $(bytes_copied += $crate::try_serialize_retriever!($buffer[$index + bytes_copied..buffer_size], $index + bytes_copied, $extra => $type); )*
+        bytes_copied += $crate::try_serialize_parser!($buffer, $index + bytes_copied, $($tail)*);
+        bytes_copied
+    } as usize };
+
+    // Slice without tail
+    ($buffer:expr, $index:expr, [$expr:expr $(,$extra:expr)*]:$type:ty) => {{
+        let buffer_size = $buffer.len();
+        let mut bytes_copied = $crate::try_serialize_retriever!($buffer[$index..buffer_size], $index, $expr => [$type]);
+        $( bytes_copied += $crate::try_serialize_retriever!($buffer[$index + bytes_copied..buffer_size], $index + bytes_copied, $extra => [$type]); )*
+        bytes_copied
+    } as usize };
+
+    // Slice with tail
+    ($buffer:expr, $index:expr, [$expr:expr $(,$extra:expr)*]:$type:ty, $($tail:tt)*) => {{
+        let buffer_size = $buffer.len();
+        let mut bytes_copied = $crate::try_serialize_retriever!($buffer[$index..buffer_size], $index, $expr => [$type]);
+        $( bytes_copied += $crate::try_serialize_retriever!($buffer[$index + bytes_copied..buffer_size], $index + bytes_copied, $extra => [$type]); )*
+        bytes_copied += $crate::try_serialize_parser!($buffer, $index + bytes_copied, $($tail)*);
+        bytes_copied
+    } as usize };
+
+}
+
+/// Hidden extension of the try_serialize! macro. Only [`Tampon`](trait.Tampon.html) implementors can fail, other types are
+/// written by serialize_retriever!. Not meant to be used directly (although it will still work).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! try_serialize_retriever {
+    // Types are dispatched through trait Encode, implemented for implementors of Tampon and markers like BigEndian
+
+    // Slice affectator
+    ($buffer:expr, $offset:expr, $expr:expr => [$type:ty]) => {{
+        // Errors of elements are relative to the buffer of slice and need to be offset
+        let offset = $offset;
+        #[allow(unused_imports)]
+        use $crate::encoding::{ EncodeValues as _, EncodeBorrowed as _ };
+        (&$crate::encoding::Elements::<$type, _>::new(&$expr[..])).try_serialize_slice(&mut $buffer).map_err(|err| err.offset_by(offset))?
+    } as usize };
+
+    ($buffer:expr, $offset:expr, $expr:expr => $type:ty) => {{
+        // Errors of nested implementor are relative to its own buffer and need to be offset
+        let offset = $offset;
+        <$type as $crate::encoding::Encode>::try_serialize(&$expr, &mut $buffer).map_err(|err| err.offset_by(offset))?
+    } as usize };
+}
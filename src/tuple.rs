@@ -0,0 +1,158 @@
+/*
+ * @file tampon/tuple.rs
+ *
+ * @module tampon
+ *
+ * @brief Contain implementation of Tampon trait for tuples.
+ *
+ * @details
+ * Contain implementation of Tampon trait for tuples of up to 12 members. Tuples are written as
+ * the concatenation of their members, without length prefix.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use crate::{ Tampon, Error, Limits, Budget, LengthPrefix };
+use crate::stream::{ StreamWriter, StreamReader };
+
+/********
+* TUPLE *
+********/
+/// Implement Tampon for tuple of members `$name` accessed with `$index`.
+macro_rules! tampon_tuple {
+    ($($name:ident : $index:tt),+) => {
+        impl<$($name: Tampon),+> Tampon for ($($name,)+) {
+            fn bytes_size(&self) -> usize {
+                self.bytes_size_prefixed(LengthPrefix::default())
+            }
+
+            fn serialize(&self, buffer : &mut [u8]) -> usize {
+                self.serialize_prefixed(buffer, LengthPrefix::default())
+            }
+
+            fn deserialize(buffer : &[u8]) -> (Self, usize) {
+                Self::deserialize_prefixed(buffer, LengthPrefix::default())
+            }
+
+            fn try_serialize(&self, buffer : &mut [u8]) -> Result<usize, Error> {
+                self.try_serialize_prefixed(buffer, LengthPrefix::default())
+            }
+
+            fn try_deserialize(buffer : &[u8]) -> Result<(Self, usize), Error> {
+                Self::try_deserialize_with(buffer, &Limits::default(), &mut Budget::new())
+            }
+
+            fn try_deserialize_with(buffer : &[u8], limits : &Limits, budget : &mut Budget) -> Result<(Self, usize), Error> {
+                Self::try_deserialize_prefixed(buffer, limits, budget, LengthPrefix::default())
+            }
+
+            #[inline]
+            fn is_fallible() -> bool {
+                $( $name::is_fallible() )&&+
+            }
+
+            #[inline]
+            fn min_bytes_size() -> usize {
+                0 $(+ $name::min_bytes_size())+
+            }
+
+            #[inline]
+            fn min_bytes_size_prefixed(prefix : LengthPrefix) -> usize {
+                0 $(+ $name::min_bytes_size_prefixed(prefix))+
+            }
+
+            fn bytes_size_prefixed(&self, prefix : LengthPrefix) -> usize {
+                0 $(+ self.$index.bytes_size_prefixed(prefix))+
+            }
+
+            fn serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> usize {
+                // Write each member after the previous one
+                let mut bytes_copied = 0;
+                $(bytes_copied += self.$index.serialize_prefixed(&mut buffer[bytes_copied..], prefix);)+
+                bytes_copied
+            }
+
+            fn try_serialize_prefixed(&self, buffer : &mut [u8], prefix : LengthPrefix) -> Result<usize, Error> {
+                // Verify size before writing anything
+                let required = self.bytes_size_prefixed(prefix);
+                if required > buffer.len() {
+                    return Err(Error::BufferTooSmall { required, available: buffer.len() });
+                }
+
+                // Errors of members are relative to their own buffer and need to be offset
+                let mut bytes_copied = 0;
+                $(
+                    let offset = bytes_copied;
+                    bytes_copied += self.$index.try_serialize_prefixed(&mut buffer[offset..], prefix).map_err(|err| err.offset_by(offset))?;
+                )+
+                Ok(bytes_copied)
+            }
+
+            fn deserialize_prefixed(buffer : &[u8], prefix : LengthPrefix) -> (Self, usize) {
+                // Members of tuple expression are evaluated from left to right
+                let mut bytes_read = 0;
+                let tuple = ($({
+                    let (member, size) = $name::deserialize_prefixed(&buffer[bytes_read..], prefix);
+                    bytes_read += size;
+                    member
+                },)+);
+
+                (tuple, bytes_read)
+            }
+
+            fn try_deserialize_prefixed(buffer : &[u8], limits : &Limits, budget : &mut Budget, prefix : LengthPrefix) -> Result<(Self, usize), Error> {
+                let mut bytes_read = 0;
+                let tuple = ($({
+                    let offset = bytes_read;
+                    let (member, size) = $name::try_deserialize_prefixed(&buffer[offset..], limits, budget, prefix).map_err(|err| err.offset_by(offset))?;
+                    bytes_read += size;
+                    member
+                },)+);
+
+                Ok((tuple, bytes_read))
+            }
+
+            fn serialize_stream(&self, stream : &mut StreamWriter<'_>) -> Result<(), Error> {
+                let size = self.bytes_size();
+                if stream.fits(size) {
+                    return stream.write_with(size, |buffer| self.try_serialize(buffer));
+                }
+                $(self.$index.serialize_stream(stream)?;)+
+                Ok(())
+            }
+
+            #[inline]
+            fn try_deserialize_stream(stream : &mut StreamReader<'_>, limits : &Limits) -> Result<Self, Error> {
+                Self::try_deserialize_stream_prefixed(stream, limits, LengthPrefix::default())
+            }
+
+            fn try_deserialize_stream_prefixed(stream : &mut StreamReader<'_>, limits : &Limits, prefix : LengthPrefix) -> Result<Self, Error> {
+                // Members are read one by one, so big members don't decode the previous ones again
+                Ok(($($name::try_deserialize_stream_prefixed(stream, limits, prefix)?,)+))
+            }
+        }
+    };
+}
+
+tampon_tuple!(A:0);
+tampon_tuple!(A:0, B:1);
+tampon_tuple!(A:0, B:1, C:2);
+tampon_tuple!(A:0, B:1, C:2, D:3);
+tampon_tuple!(A:0, B:1, C:2, D:3, E:4);
+tampon_tuple!(A:0, B:1, C:2, D:3, E:4, F:5);
+tampon_tuple!(A:0, B:1, C:2, D:3, E:4, F:5, G:6);
+tampon_tuple!(A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7);
+tampon_tuple!(A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8);
+tampon_tuple!(A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8, J:9);
+tampon_tuple!(A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8, J:9, K:10);
+tampon_tuple!(A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8, J:9, K:10, L:11);
@@ -0,0 +1,279 @@
+/*
+ * @file tampon/varint.rs
+ *
+ * @module tampon
+ *
+ * @brief Contain functions to encode and decode variable length integers and Varint marker.
+ *
+ * @details
+ * Contain functions to encode and decode variable length unsigned integers (LEB128) and signed
+ * integers mapped to unsigned with ZigZag. Varint<T> is used as type of macros fields and reads
+ * and writes integers of type T as varint, so small values take fewer bytes.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ * https://en.wikipedia.org/wiki/LEB128
+ * https://protobuf.dev/programming-guides/encoding/#signed-ints
+ *
+ * @todo
+ */
+
+use core::marker::PhantomData;
+
+use crate::{ Error, Limits, Budget };
+use crate::encoding::{ Encode, Decode };
+
+/// Maximum size in bytes of a varint encoded [`u64`].
+pub const VARINT_MAX_SIZE : usize = 10;
+
+/// Size in bytes of `value` encoded as varint.
+pub fn varint_size(value : u64) -> usize {
+    let mut size = 1;
+    let mut value = value >> 7;
+
+    while value != 0 {
+        size += 1;
+        value >>= 7;
+    }
+
+    size
+}
+
+/// Write `value` as varint into `buffer` and return the bytes count written.
+///
+/// # Panic(s)
+/// * Will panic! if `buffer` is smaller than [`varint_size`] of `value`.
+pub fn write_varint(buffer : &mut [u8], value : u64) -> usize {
+    let mut value = value;
+    let mut index = 0;
+
+    // 7 bits per byte, high bit set when more bytes follow
+    while value >= 0x80 {
+        buffer[index] = (value as u8) | 0x80;
+        value >>= 7;
+        index += 1;
+    }
+    buffer[index] = value as u8;
+
+    index + 1
+}
+
+/// Read a varint from `buffer` and return it with the bytes count read.
+///
+/// # Panic(s)
+/// * Will panic! if `buffer` ends before the varint or if the varint is bigger than [`u64`] or overlong.
+pub fn read_varint(buffer : &[u8]) -> (u64, usize) {
+    match try_read_varint(buffer, 0) {
+        Ok(result) => result,
+        Err(err) => panic!("{}", err),
+    }
+}
+
+/// Read a varint at `offset` of `buffer` and return it with the bytes count read.
+///
+/// # Error(s)
+/// * [`Error::UnexpectedEnd`] if `buffer` ends before the last byte of varint.
+/// * [`Error::InvalidValue`] if the varint is bigger than [`u64`] or overlong, with padding bytes a shorter encoding doesn't have.
+pub fn try_read_varint(buffer : &[u8], offset : usize) -> Result<(u64, usize), Error> {
+    let mut value : u64 = 0;
+
+    for index in 0..VARINT_MAX_SIZE {
+        let byte = match buffer.get(offset + index) {
+            Some(byte) => *byte,
+            None => return Err(Error::UnexpectedEnd { offset: offset + index, needed: 1, available: 0 }),
+        };
+
+        // 10th byte can only hold the last bit of u64
+        if index == VARINT_MAX_SIZE - 1 && byte > 1 {
+            break;
+        }
+
+        value |= ((byte & 0x7F) as u64) << (7 * index);
+
+        if byte & 0x80 == 0 {
+            // Last byte of 0 only adds padding, so the same value has a shorter encoding
+            if byte == 0 && index > 0 {
+                return Err(Error::InvalidValue { offset, reason: String::from("varint is overlong") });
+            }
+            return Ok((value, index + 1));
+        }
+    }
+
+    Err(Error::InvalidValue { offset, reason: String::from("varint is bigger than u64") })
+}
+
+/// Map signed `value` to unsigned with ZigZag, so values close to 0 have short varints.
+#[inline]
+pub fn zigzag_encode(value : i64) -> u64 {
+    // 0, -1, 1, -2, 2 become 0, 1, 2, 3, 4
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+/// Map unsigned `value` mapped with [`zigzag_encode`] back to signed.
+#[inline]
+pub fn zigzag_decode(value : u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+/// ##### Marker used as type of macros fields to write integers of type `T` as variable length integers.
+///
+/// Integers are written at their full width by default, so a [`u64`] counter holding `3` takes 8 bytes. `Varint<T>`
+/// writes unsigned integers as [`LEB128`](https://en.wikipedia.org/wiki/LEB128), 7 bits per byte, and signed integers
+/// mapped with [`ZigZag`](https://protobuf.dev/programming-guides/encoding/#signed-ints) first so small negative values
+/// are short too. Variables keep their type, only the field type of macros changes. Aliases like [`varu64`] are given.
+///
+/// `Varint<T>` is implemented for [`u16`], [`u32`], [`u64`], [`usize`], [`i16`], [`i32`], [`i64`], [`isize`] and `Vec<T>`
+/// of those. It is never constructed. With feature `derive`, fields are written as varint with `#[tampon(varint)]`.
+///
+/// Decoding rejects overlong encodings and values that don't fit in `T`.
+///
+/// # Example(s)
+/// ```
+/// use tampon::{ varu64, vari32, buffer, bytes_size, deserialize };
+///
+/// let count:u64 = 3;
+/// let delta:i32 = -2;
+/// let ids:Vec<u64> = vec![1, 300];
+///
+/// let buffer = buffer!((count):varu64, (delta):vari32, [ids]:varu64);
+/// assert_eq!(buffer, vec![3, 3, 2, 0, 0, 0, 1, 0xAC, 0x02]);
+/// assert_eq!(bytes_size!((count):varu64, (delta):vari32, [ids]:varu64), 9);
+///
+/// deserialize!(buffer, (a):varu64, (b):vari32, [c]:varu64);
+/// assert_eq!((a, b, c), (count, delta, ids));
+/// ```
+pub struct Varint<T: ?Sized>(PhantomData<T>);
+
+/// Implement Varint for integer types converted to [`u64`] with `$encode` and back with `$decode`.
+macro_rules! varint_integer {
+    ($($type:ident),* => $encode:expr, $decode:expr) => {
+        $(
+            // Cast is unnecessary for u64 and i64 only
+            #[allow(clippy::unnecessary_cast)]
+            impl Encode for Varint<$type> {
+                type Value = $type;
+
+                #[inline]
+                fn bytes_size(value : &$type) -> usize {
+                    varint_size($encode(*value))
+                }
+
+                #[inline]
+                fn serialize(value : &$type, buffer : &mut [u8]) -> usize {
+                    write_varint(buffer, $encode(*value))
+                }
+            }
+
+            impl<'a> Decode<'a> for Varint<$type> {
+                type Value = $type;
+
+                #[inline]
+                fn deserialize(buffer : &'a [u8]) -> ($type, usize) {
+                    match Self::try_deserialize_with(buffer, &Limits::default(), &mut Budget::new()) {
+                        Ok(result) => result,
+                        Err(err) => panic!("{}", err),
+                    }
+                }
+
+                fn try_deserialize_with(buffer : &'a [u8], _limits : &Limits, _budget : &mut Budget) -> Result<($type, usize), Error> {
+                    // Values bigger than type are rejected
+                    let (value, bytes_read) = try_read_varint(buffer, 0)?;
+                    let value = $decode(value);
+                    match <$type>::try_from(value) {
+                        Ok(value) => Ok((value, bytes_read)),
+                        Err(_) => Err(Error::OutOfRange { offset: 0, value: value as i128 }),
+                    }
+                }
+
+                #[inline]
+                fn min_bytes_size() -> usize {
+                    1
+                }
+            }
+        )*
+    };
+}
+
+varint_integer!(u16, u32, u64, usize => |value| value as u64, |value:u64| value);
+varint_integer!(i16, i32, i64, isize => |value| zigzag_encode(value as i64), zigzag_decode);
+
+/// Vec is written like `[v]:Varint<T>` in macros, so nested containers keep varint.
+impl<T> Encode for Varint<Vec<T>> where Varint<T>: Encode<Value = T> {
+    type Value = Vec<T>;
+
+    #[inline]
+    fn bytes_size(value : &Vec<T>) -> usize {
+        <Varint<T> as Encode>::slice_bytes_size(value)
+    }
+
+    #[inline]
+    fn serialize(value : &Vec<T>, buffer : &mut [u8]) -> usize {
+        <Varint<T> as Encode>::serialize_slice(value, buffer)
+    }
+
+    #[inline]
+    fn try_serialize(value : &Vec<T>, buffer : &mut [u8]) -> Result<usize, Error> {
+        <Varint<T> as Encode>::try_serialize_slice(value, buffer)
+    }
+}
+
+impl<'a, T> Decode<'a> for Varint<Vec<T>> where Varint<T>: Decode<'a, Value = T> {
+    type Value = Vec<T>;
+
+    #[inline]
+    fn deserialize(buffer : &'a [u8]) -> (Vec<T>, usize) {
+        <Varint<T> as Decode>::deserialize_slice(buffer)
+    }
+
+    #[inline]
+    fn try_deserialize_with(buffer : &'a [u8], limits : &Limits, budget : &mut Budget) -> Result<(Vec<T>, usize), Error> {
+        <Varint<T> as Decode>::try_deserialize_slice(buffer, limits, budget)
+    }
+
+    #[inline]
+    fn min_bytes_size() -> usize {
+        crate::SLICE_SIZE_IN_BYTES
+    }
+}
+
+/**********
+* ALIASES *
+**********/
+/// [`u16`] written as varint.
+#[allow(non_camel_case_types)]
+pub type varu16 = Varint<u16>;
+
+/// [`u32`] written as varint.
+#[allow(non_camel_case_types)]
+pub type varu32 = Varint<u32>;
+
+/// [`u64`] written as varint.
+#[allow(non_camel_case_types)]
+pub type varu64 = Varint<u64>;
+
+/// [`usize`] written as varint.
+#[allow(non_camel_case_types)]
+pub type varusize = Varint<usize>;
+
+/// [`i16`] written as ZigZag varint.
+#[allow(non_camel_case_types)]
+pub type vari16 = Varint<i16>;
+
+/// [`i32`] written as ZigZag varint.
+#[allow(non_camel_case_types)]
+pub type vari32 = Varint<i32>;
+
+/// [`i64`] written as ZigZag varint.
+#[allow(non_camel_case_types)]
+pub type vari64 = Varint<i64>;
+
+/// [`isize`] written as ZigZag varint.
+#[allow(non_camel_case_types)]
+pub type varisize = Varint<isize>;
@@ -47,6 +47,7 @@
  /// // Print wiped buffer
  /// println!("Buffer = {:?}", buffer);
  /// ```
+ #[allow(clippy::ptr_arg)]
  pub fn wipe_buffer(buffer : &mut Vec<u8>){
 
     for elem in buffer.iter_mut() {
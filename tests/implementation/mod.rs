@@ -23,12 +23,13 @@
 
 use std::vec;
 
-use tampon::deserialize;
+use tampon::{deserialize, try_deserialize, try_serialize, Error, Budget, Limits};
 pub use tampon::{Tampon, bytes_size, serialize};
 
 use crate::data::{do_vecs_match, do_vecs_eq_match};
 
 // Struct used to test Tampon traits in macros
+ #[derive(Debug)]
  pub struct TamponS1 {
     _f1:u8,
     _f2:u32,
@@ -60,7 +61,7 @@ use crate::data::{do_vecs_match, do_vecs_eq_match};
  }
 
 
-impl Tampon<TamponS1> for TamponS1 {
+impl Tampon for TamponS1 {
     fn bytes_size(&self) -> usize {
         bytes_size!((self._f1):u8, (self._f2):u32, (self._f3):f64, (&self.f4):TamponS2, [self.v1]:u8, [self.v2]:f64, [self.v3]:TamponS2)
     }
@@ -70,7 +71,7 @@ impl Tampon<TamponS1> for TamponS1 {
         to_size
     }
 
-    fn deserialize(buffer : &[u8]) -> (TamponS1, usize) {
+    fn deserialize(buffer : &[u8]) -> (Self, usize) {
         
         deserialize!(buffer, from_size, (_f1):u8, (_f2):u32, (_f3):f64, (f4):TamponS2, [v1]:u8, [v2]:f64, [v3]:TamponS2);
 
@@ -90,6 +91,7 @@ impl PartialEq for TamponS1 {
 
 
  // Struct used as inner struct of test struct
+ #[derive(Debug)]
  pub struct TamponS2 {
     _f1:u8,
     _f2:i128
@@ -103,7 +105,7 @@ impl PartialEq for TamponS1 {
     }
  }
 
- impl Tampon<TamponS2> for TamponS2 {
+ impl Tampon for TamponS2 {
     fn bytes_size(&self) -> usize {
         bytes_size!((self._f1):u8, (self._f2):i128)
     }
@@ -113,7 +115,7 @@ impl PartialEq for TamponS1 {
         to_size
     }
 
-    fn deserialize(buffer : &[u8]) -> (TamponS2, usize) {
+    fn deserialize(buffer : &[u8]) -> (Self, usize) {
         deserialize!(buffer, from_size, (_f1):u8, (_f2):i128);
 
         (TamponS2 {
@@ -126,4 +128,91 @@ impl PartialEq for TamponS2 {
     fn eq(&self, other: &Self) -> bool {
         self._f1 == other._f1 && self._f2 == other._f2
     }
-}
\ No newline at end of file
+}
+
+ // Struct with percentage validated by try_serialize and try_deserialize
+ #[derive(Debug)]
+ pub struct TamponS3 {
+    pub percent:u8
+ }
+
+ impl Tampon for TamponS3 {
+    fn bytes_size(&self) -> usize {
+        bytes_size!((self.percent):u8)
+    }
+
+    fn serialize(&self, buffer : &mut [u8]) -> usize {
+        serialize!(buffer, to_size, (self.percent):u8);
+        to_size
+    }
+
+    fn deserialize(buffer : &[u8]) -> (Self, usize) {
+        deserialize!(buffer, from_size, (percent):u8);
+        (TamponS3 { percent }, from_size)
+    }
+
+    fn try_serialize(&self, buffer : &mut [u8]) -> Result<usize, Error> {
+        if self.percent > 100 {
+            return Err(Error::InvalidValue { offset: 0, reason: String::from("percent > 100") });
+        }
+        try_serialize!(buffer, (self.percent):u8)
+    }
+
+    fn try_deserialize(buffer : &[u8]) -> Result<(Self, usize), Error> {
+        try_deserialize!(buffer, from_size, (percent):u8);
+        if percent > 100 {
+            return Err(Error::InvalidValue { offset: 0, reason: String::from("percent > 100") });
+        }
+        Ok((TamponS3 { percent }, from_size))
+    }
+}
+
+impl PartialEq for TamponS3 {
+    fn eq(&self, other: &Self) -> bool {
+        self.percent == other.percent
+    }
+}
+
+
+ // Struct containing TamponS3 used to test error propagation of nested implementors
+ #[derive(Debug)]
+ pub struct TamponS4 {
+    pub f1:u32,
+    pub f2:TamponS3,
+    pub v1:Vec<TamponS3>
+ }
+
+ impl Tampon for TamponS4 {
+    fn bytes_size(&self) -> usize {
+        bytes_size!((self.f1):u32, (self.f2):TamponS3, [self.v1]:TamponS3)
+    }
+
+    fn serialize(&self, buffer : &mut [u8]) -> usize {
+        serialize!(buffer, to_size, (self.f1):u32, (self.f2):TamponS3, [self.v1]:TamponS3);
+        to_size
+    }
+
+    fn deserialize(buffer : &[u8]) -> (Self, usize) {
+        deserialize!(buffer, from_size, (f1):u32, (f2):TamponS3, [v1]:TamponS3);
+        (TamponS4 { f1, f2, v1 }, from_size)
+    }
+
+    fn try_serialize(&self, buffer : &mut [u8]) -> Result<usize, Error> {
+        try_serialize!(buffer, (self.f1):u32, (self.f2):TamponS3, [self.v1]:TamponS3)
+    }
+
+    fn try_deserialize(buffer : &[u8]) -> Result<(Self, usize), Error> {
+        Self::try_deserialize_with(buffer, &Limits::default(), &mut Budget::new())
+    }
+
+    fn try_deserialize_with(buffer : &[u8], limits : &Limits, budget : &mut Budget) -> Result<(Self, usize), Error> {
+        try_deserialize!(buffer, from_size, limits = limits, budget = budget, (f1):u32, (f2):TamponS3, [v1]:TamponS3);
+        Ok((TamponS4 { f1, f2, v1 }, from_size))
+    }
+}
+
+impl PartialEq for TamponS4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.f1 == other.f1 && self.f2.eq(&other.f2) && do_vecs_eq_match(&self.v1, &other.v1)
+    }
+}
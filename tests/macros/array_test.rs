@@ -0,0 +1,86 @@
+/*
+ * @file tampon/tests/array_test.rs
+ *
+ * @module tampon::tests
+ *
+ * @brief Contains integration tests for fixed-size arrays written without length prefix.
+ *
+ * @details
+ * Contains integration tests for fixed-size arrays written without length prefix.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use tampon::{buffer, deserialize, bytes_size, serialize, try_buffer, try_deserialize, Error};
+pub use tampon::Tampon;
+use crate::implementation::{TamponS2, TamponS3};
+
+// Deserialize a hash and keys without panicking
+fn try_deserialize_keys(buffer : &[u8]) -> Result<([u8; 4], Vec<[u16; 2]>), Error> {
+    try_deserialize!(buffer, (a):[u8; 4], [b]:[u16; 2]);
+    Ok((a, b))
+}
+
+#[test]
+// Arrays round trip in macros and slices
+fn array_round_trip() {
+    let a:[u8; 32] = [7; 32];
+    let b:[String; 2] = [String::from("a"), String::from("bc")];
+    let c:[TamponS2; 2] = [TamponS2::new(1, 2), TamponS2::new(3, 4)];
+    let d:Vec<[i16; 3]> = vec![[1, 2, 3], [-1, -2, -3]];
+    let e:[[u8; 2]; 2] = [[1, 2], [3, 4]];
+
+    let mut buffer = vec![0u8; bytes_size!((a):[u8; 32], (b):[String; 2], (c):[TamponS2; 2], [d]:[i16; 3], (e):[[u8; 2]; 2])];
+    serialize!(buffer, bytes_written, (a):[u8; 32], (b):[String; 2], (c):[TamponS2; 2], [d]:[i16; 3], (e):[[u8; 2]; 2]);
+    assert_eq!(bytes_written, buffer.len());
+
+    deserialize!(buffer, bytes_read, (f):[u8; 32], (g):[String; 2], (h):[TamponS2; 2], [i]:[i16; 3], (j):[[u8; 2]; 2]);
+    assert_eq!(bytes_read, buffer.len());
+    assert!(a == f && b == g && c == h && d == i && e == j);
+}
+
+#[test]
+// Arrays are written without length prefix
+fn array_wire_format() {
+    let a:[u8; 4] = [1, 2, 3, 4];
+    let b:[u16; 2] = [0x0102, 0x0304];
+    let c:Vec<[u8; 2]> = vec![[5, 6]];
+
+    assert_eq!(bytes_size!((a):[u8; 4], (b):[u16; 2]), 8);
+    assert_eq!(buffer!((a):[u8; 4], (b):[u16; 2], [c]:[u8; 2]), vec![1, 2, 3, 4, 2, 1, 4, 3, 1, 0, 0, 0, 5, 6]);
+
+    let d:[u8; 0] = [];
+    assert_eq!(buffer!((d):[u8; 0]), Vec::<u8>::new());
+}
+
+#[test]
+// Arrays return errors when there are too few bytes
+fn array_errors() {
+    let buffer:Vec<u8> = vec![1, 2, 3, 4, 1, 0, 0, 0, 1, 0, 2];
+    assert_eq!(try_deserialize_keys(&buffer[0..3]), Err(Error::UnexpectedEnd { offset: 3, needed: 1, available: 0 }));
+    assert_eq!(try_deserialize_keys(&buffer), Err(Error::InvalidLength { offset: 4, length: 4, available: 3 }));
+    assert_eq!(<[u32; 2]>::try_deserialize(&[0; 7]), Err(Error::UnexpectedEnd { offset: 4, needed: 4, available: 3 }));
+
+    // Errors of elements are offset
+    let a:[TamponS3; 2] = [TamponS3 { percent: 1 }, TamponS3 { percent: 101 }];
+    assert_eq!(try_buffer!(2, (a):[TamponS3; 2]), Err(Error::InvalidValue { offset: 1, reason: String::from("percent > 100") }));
+    assert_eq!(<[TamponS3; 2]>::try_deserialize(&[1, 101]).map(|(array, _)| array.len()), Err(Error::InvalidValue { offset: 1, reason: String::from("percent > 100") }));
+}
+
+#[test]
+#[should_panic]
+// deserialize! panics when there are too few bytes
+fn array_panic() {
+    let buffer:Vec<u8> = vec![1, 2, 3];
+    deserialize!(buffer, (_a):[u8; 4]);
+}
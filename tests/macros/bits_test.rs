@@ -0,0 +1,152 @@
+/*
+ * @file tampon/tests/bits_test.rs
+ *
+ * @module tampon::tests
+ *
+ * @brief Contains integration tests for bit fields mixed with macros.
+ *
+ * @details
+ * Contains integration tests for implementors of Tampon writing bit fields with BitWriter and BitReader
+ * followed by fields written with macros.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use tampon::{buffer, bytes_size, deserialize, serialize, try_deserialize, try_serialize, BitWriter, BitReader, BitOrder, Error, Budget, Limits, Tampon};
+
+// Size in bits of Packet bit fields
+const PACKET_BITS : usize = 3 + 12 + 1 + 6;
+
+// Packet with a 3-bit opcode, a 12-bit id, a 1-bit flag and a 6-bit signed delta followed by a payload
+#[derive(Debug, PartialEq)]
+struct Packet {
+    opcode : u8,
+    id : u16,
+    ack : bool,
+    delta : i8,
+    payload : Vec<u16>,
+}
+
+impl Tampon for Packet {
+    fn bytes_size(&self) -> usize {
+        PACKET_BITS.div_ceil(8) + bytes_size!([self.payload]:u16)
+    }
+
+    fn serialize(&self, buffer : &mut [u8]) -> usize {
+        let mut writer = BitWriter::new(buffer, BitOrder::MsbFirst);
+        writer.write_bits(self.opcode as u64, 3);
+        writer.write_bits(self.id as u64, 12);
+        writer.write_bool(self.ack);
+        writer.write_signed(self.delta as i64, 6);
+        let bits_size = writer.align();
+
+        let rest = &mut buffer[bits_size..];
+        serialize!(rest, bytes_copied, [self.payload]:u16);
+        bits_size + bytes_copied
+    }
+
+    fn try_serialize(&self, buffer : &mut [u8]) -> Result<usize, Error> {
+        let mut writer = BitWriter::new(buffer, BitOrder::MsbFirst);
+        writer.try_write_bits(self.opcode as u64, 3)?;
+        writer.try_write_bits(self.id as u64, 12)?;
+        writer.try_write_bool(self.ack)?;
+        writer.try_write_signed(self.delta as i64, 6)?;
+        let bits_size = writer.align();
+
+        let rest = &mut buffer[bits_size..];
+        let bytes_copied = try_serialize!(rest, [self.payload]:u16).map_err(|err| err.offset_by(bits_size))?;
+        Ok(bits_size + bytes_copied)
+    }
+
+    fn deserialize(buffer : &[u8]) -> (Self, usize) {
+        let mut reader = BitReader::new(buffer, BitOrder::MsbFirst);
+        let opcode = reader.read_bits(3) as u8;
+        let id = reader.read_bits(12) as u16;
+        let ack = reader.read_bool();
+        let delta = reader.read_signed(6) as i8;
+        let bits_size = reader.align();
+
+        deserialize!(buffer[bits_size..], bytes_read, [payload]:u16);
+        (Packet { opcode, id, ack, delta, payload }, bits_size + bytes_read)
+    }
+
+    fn try_deserialize_with(buffer : &[u8], limits : &Limits, budget : &mut Budget) -> Result<(Self, usize), Error> {
+        let mut reader = BitReader::new(buffer, BitOrder::MsbFirst);
+        let opcode = reader.try_read_bits(3)? as u8;
+        let id = reader.try_read_bits(12)? as u16;
+        let ack = reader.try_read_bool()?;
+        let delta = reader.try_read_signed(6)? as i8;
+        let bits_size = reader.align();
+
+        let rest = &buffer[bits_size..];
+        let (payload, bytes_read) = (|| -> Result<(Vec<u16>, usize), Error> {
+            try_deserialize!(rest, bytes_read, limits = limits, budget = budget, [payload]:u16);
+            Ok((payload, bytes_read))
+        })().map_err(|err| err.offset_by(bits_size))?;
+        Ok((Packet { opcode, id, ack, delta, payload }, bits_size + bytes_read))
+    }
+}
+
+// Create a packet
+fn packet() -> Packet {
+    Packet { opcode: 6, id: 0x0F0, ack: true, delta: -3, payload: vec![1, 0x0203] }
+}
+
+#[test]
+// Bit fields are written before fields of macros
+fn bits_mixed_wire_format() {
+    let to = packet();
+    assert_eq!(to.bytes_size(), 3 + 4 + 4);
+
+    let mut buffer = vec![0u8; to.bytes_size()];
+    assert_eq!(to.serialize(&mut buffer), buffer.len());
+    assert_eq!(buffer[0..3], [0b1100_0001, 0b1110_0001, 0b1111_0100]);
+    assert_eq!(buffer[3..], buffer!([to.payload]:u16)[..]);
+}
+
+#[test]
+// Implementors with bit fields round trip in macros
+fn bits_mixed_round_trip() {
+    let a = packet();
+    let b = vec![packet(), Packet { opcode: 0, id: 0xFFF, ack: false, delta: 31, payload: Vec::new() }];
+
+    let buffer = buffer!((a):Packet, [b]:Packet);
+    assert_eq!(buffer.len(), bytes_size!((a):Packet, [b]:Packet));
+
+    deserialize!(buffer, bytes_read, (a2):Packet, [b2]:Packet);
+    assert_eq!((bytes_read, &a2, &b2), (buffer.len(), &a, &b));
+
+    let result = (|| -> Result<(Packet, Vec<Packet>), Error> { try_deserialize!(buffer, (a3):Packet, [b3]:Packet); Ok((a3, b3)) })();
+    assert_eq!(result, Ok((a, b)));
+}
+
+#[test]
+// Errors of bit fields and macros are offset in implementors
+fn bits_mixed_errors() {
+    let a = packet();
+    let mut buffer = [0u8; 16];
+    assert_eq!(try_serialize!(buffer, (1):u8, (a):Packet), Ok(1 + 11));
+
+    // Values that don't fit in their bit field
+    let b = Packet { delta: 32, ..packet() };
+    assert_eq!(try_serialize!(buffer, (1):u8, (b):Packet), Err(Error::OutOfRange { offset: 3, value: 32 }));
+    let c = Packet { id: 0x1000, ..packet() };
+    assert_eq!(try_serialize!(buffer, (c):Packet), Err(Error::OutOfRange { offset: 0, value: 0x1000 }));
+
+    // Buffer ends in bit fields and in payload
+    let buffer = buffer!((7):u8, (a):Packet);
+    let result = (|| -> Result<Packet, Error> { try_deserialize!(buffer[0..3], (_b):u8, (a2):Packet); Ok(a2) })();
+    assert_eq!(result, Err(Error::UnexpectedEnd { offset: 3, needed: 1, available: 0 }));
+    let result = (|| -> Result<Packet, Error> { try_deserialize!(buffer[0..10], (_b):u8, (a2):Packet); Ok(a2) })();
+    assert_eq!(result, Err(Error::InvalidLength { offset: 4, length: 4, available: 2 }));
+}
@@ -0,0 +1,143 @@
+/*
+ * @file tampon/tests/borrow_test.rs
+ *
+ * @module tampon::tests
+ *
+ * @brief Contains integration tests for deserialization of values borrowed from buffer.
+ *
+ * @details
+ * Contains integration tests for deserialization of values borrowed from buffer.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use std::borrow::Cow;
+use tampon::{buffer, deserialize, bytes_size, serialize_to_writer, try_buffer, try_deserialize, try_serialize, BigEndian, Error, Limits, TamponBorrow};
+
+// Packet borrowing its fields from buffer
+#[derive(Debug, PartialEq)]
+struct Packet<'a> {
+    id:u32,
+    name:&'a str,
+    payload:&'a [u8],
+    tags:Vec<Cow<'a, str>>,
+}
+
+// Deserialize a packet without panicking or copying
+fn inspect<'a>(buffer : &'a [u8], limits : &Limits) -> Result<Packet<'a>, Error> {
+    try_deserialize!(buffer, limits = limits, (id):u32, (name):&str, (payload):&[u8], [tags]:Cow<str>);
+    Ok(Packet { id, name, payload, tags })
+}
+
+#[test]
+// Borrowed values point into buffer
+fn borrow_zero_copy() {
+    let name = "tampon";
+    let payload:Vec<u8> = vec![1, 2, 3];
+    let tags:Vec<&str> = vec!["a", "bc"];
+    let buffer = buffer!((7):u32, (name):str, (payload):[u8], [tags]:str);
+
+    deserialize!(buffer, bytes_read, (_id):u32, (a):&str, (b):&[u8], [c]:Cow<str>);
+    assert_eq!(bytes_read, buffer.len());
+    assert_eq!((a, b), ("tampon", &payload[..]));
+    assert_eq!(a.as_ptr(), buffer[8..].as_ptr());
+    assert_eq!(b.as_ptr(), buffer[18..].as_ptr());
+    assert!(c.iter().all(|tag| matches!(tag, Cow::Borrowed(_))));
+
+    let packet = inspect(&buffer, &Limits::default()).unwrap();
+    assert_eq!(packet, Packet { id: 7, name: "tampon", payload: &[1, 2, 3], tags: vec![Cow::from("a"), Cow::from("bc")] });
+
+    // Cow can own its data once buffer is gone
+    let owned:Cow<'static, [u8]> = Cow::Owned(Cow::<[u8]>::deserialize_borrowed(&buffer[14..]).0.into_owned());
+    assert_eq!(owned, Cow::from(&payload[..]));
+}
+
+#[test]
+// Borrowed values use the same wire format as String and slices
+fn borrow_wire_format() {
+    let a:&str = "abc";
+    let b:&[u8] = &[1, 2];
+    let c = String::from("abc");
+    let d:Vec<u8> = vec![1, 2];
+
+    assert_eq!(bytes_size!((a):str, (b):[u8]), bytes_size!((c):String, [d]:u8));
+    assert_eq!(buffer!((a):str, (b):[u8]), buffer!((c):String, [d]:u8));
+    assert_eq!(try_buffer!(13, (a):str, (b):[u8]), Ok(buffer!((c):String, [d]:u8)));
+
+    // Owned and borrowed values read each other
+    let buffer = buffer!((c):String, [d]:u8);
+    deserialize!(buffer, (e):Cow<str>, (f):Cow<[u8]>);
+    assert_eq!((e.as_ref(), f.as_ref()), (a, b));
+}
+
+#[test]
+// Borrowed values return errors for invalid buffers
+fn borrow_errors() {
+    let tags:Vec<&str> = vec!["tampon"];
+    let buffer = buffer!((7):u32, ("name"):str, (vec![1u8, 2]):[u8], [tags]:str);
+
+    // Truncated string, slice and elements
+    assert_eq!(inspect(&buffer[0..10], &Limits::default()), Err(Error::InvalidLength { offset: 4, length: 4, available: 2 }));
+    assert_eq!(inspect(&buffer[0..17], &Limits::default()), Err(Error::InvalidLength { offset: 12, length: 2, available: 1 }));
+    assert_eq!(inspect(&buffer[0..24], &Limits::default()), Err(Error::InvalidLength { offset: 18, length: 4, available: 2 }));
+
+    // Limits are enforced on borrowed values
+    assert_eq!(inspect(&buffer, &Limits::new(16, 2, 1024)), Err(Error::LimitExceeded { offset: 4, length: 4, limit: 2 }));
+    assert_eq!(inspect(&buffer, &Limits::new(1, 16, 1024)), Err(Error::LimitExceeded { offset: 12, length: 2, limit: 1 }));
+
+    // Borrowed values aren't copied and don't use the allocation budget, only the vector of tags does
+    let tags_size = core::mem::size_of::<Cow<str>>();
+    assert!(inspect(&buffer, &Limits::new(16, 16, tags_size)).is_ok());
+    let long = "x".repeat(23);
+    let long_buffer = buffer!((long):str, (long):BigEndian<str>);
+    let result = (|| -> Result<(&str, &str), Error> {
+        try_deserialize!(long_buffer, limits = &Limits::new(usize::MAX, usize::MAX, 10), (a):&str, (b):BigEndian<&str>);
+        Ok((a, b))
+    })();
+    assert_eq!(result, Ok((&long[..], &long[..])));
+
+    // Invalid UTF-8
+    let mut invalid = buffer.clone();
+    invalid[10] = 0xFF;
+    assert_eq!(inspect(&invalid, &Limits::default()), Err(Error::InvalidUtf8 { offset: 10 }));
+    assert_eq!(<&str>::try_deserialize_borrowed(&invalid[4..]), Err(Error::InvalidUtf8 { offset: 6 }));
+}
+
+#[test]
+#[should_panic]
+// deserialize! panics for invalid UTF-8 in borrowed string
+fn borrow_invalid_utf8_panic() {
+    let buffer:Vec<u8> = vec![1, 0, 0, 0, 0xFF];
+    deserialize!(buffer, (_a):&str);
+}
+
+#[test]
+// Slices of references and of owned values are written like slices of strings
+fn borrow_slice_of_references() {
+    let refs:Vec<&str> = vec!["a", "bc", ""];
+    let owned:Vec<String> = refs.iter().map(|tag| tag.to_string()).collect();
+    let expected = buffer!([owned]:String);
+
+    assert_eq!(buffer!([refs]:str), expected);
+    assert_eq!(buffer!([owned]:str), expected);
+    assert_eq!(try_buffer!(64, [refs]:str), Ok(expected.clone()));
+
+    let mut writer:Vec<u8> = Vec::new();
+    serialize_to_writer!(&mut writer, [refs]:str).unwrap();
+    assert_eq!(writer, expected);
+
+    // Buffer too small is reported before writing anything
+    let mut small = vec![0u8; 8];
+    assert_eq!(try_serialize!(small, [refs]:str), Err(Error::BufferTooSmall { required: 19, available: 8 }));
+    assert_eq!(small, vec![0u8; 8]);
+}
@@ -0,0 +1,151 @@
+/*
+ * @file tampon/tests/bulk_test.rs
+ *
+ * @module tampon::tests
+ *
+ * @brief Contains integration tests for numeric slices copied in bulk.
+ *
+ * @details
+ * Contains integration tests for numeric slices copied in bulk.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use tampon::{buffer, deserialize, bytes_size, serialize, try_buffer, try_deserialize, try_serialize, Tampon, Error, Budget, Limits};
+use crate::implementation::TamponS3;
+
+// Slices of different numeric widths
+type Numerics = (Vec<u8>, Vec<i8>, Vec<u16>, Vec<f64>);
+
+// Deserialize numeric slices without panicking
+fn try_deserialize_numerics(buffer : &[u8]) -> Result<Numerics, Error> {
+    try_deserialize!(buffer, [a]:u8, [b]:i8, [c]:u16, [d]:f64);
+    Ok((a, b, c, d))
+}
+
+/// Per-element encoding of `slice` to compare with bulk copy.
+fn per_element<T: Tampon>(slice : &[T]) -> Vec<u8> {
+    let mut buffer = (slice.len() as u32).to_le_bytes().to_vec();
+    for elem in slice {
+        let mut bytes = vec![0u8; elem.bytes_size()];
+        elem.serialize(&mut bytes);
+        buffer.extend(bytes);
+    }
+    buffer
+}
+
+#[test]
+// Every numeric width round trip in bulk
+fn bulk_round_trip() {
+    let a:Vec<u8> = (0..=255).collect();
+    let b:Vec<i8> = (-128..=127).collect();
+    let c:Vec<u16> = vec![0, 1, u16::MAX];
+    let d:Vec<u32> = vec![0, 0x01020304, u32::MAX];
+    let e:Vec<u64> = vec![0, 0x0102030405060708, u64::MAX];
+    let f:Vec<u128> = vec![0, u128::MAX];
+    let g:Vec<i16> = vec![i16::MIN, -1, i16::MAX];
+    let h:Vec<i32> = vec![i32::MIN, -1, i32::MAX];
+    let i:Vec<i64> = vec![i64::MIN, -1, i64::MAX];
+    let j:Vec<i128> = vec![i128::MIN, -1, i128::MAX];
+    let k:Vec<f32> = vec![f32::MIN, -0.5, f32::MAX];
+    let l:Vec<f64> = vec![f64::MIN, -0.5, f64::MAX];
+
+    let buffer = buffer!([a]:u8, [b]:i8, [c]:u16, [d]:u32, [e]:u64, [f]:u128, [g]:i16, [h]:i32, [i]:i64, [j]:i128, [k]:f32, [l]:f64);
+    assert_eq!(buffer.len(), 12 * 4 + 256 + 256 + 6 + 12 + 24 + 32 + 6 + 12 + 24 + 48 + 12 + 24);
+
+    deserialize!(buffer, bytes_read, [a2]:u8, [b2]:i8, [c2]:u16, [d2]:u32, [e2]:u64, [f2]:u128, [g2]:i16, [h2]:i32, [i2]:i64, [j2]:i128, [k2]:f32, [l2]:f64);
+    assert_eq!(bytes_read, buffer.len());
+    assert_eq!((a2, b2, c2, d2, e2, f2), (a, b, c, d, e, f));
+    assert_eq!((g2, h2, i2, j2, k2, l2), (g, h, i, j, k, l));
+
+    // Vec and slice implementations use the same bulk copy
+    let m:Vec<u16> = vec![1, 2, 3];
+    let (n, size) = Vec::<u16>::try_deserialize(&buffer!([m]:u16)).unwrap();
+    assert_eq!((n, size), (m.clone(), 10));
+    assert_eq!(buffer!((m):Vec<u16>), buffer!((m[..]):[u16]));
+}
+
+#[test]
+// Bulk copy writes the same bytes as elements written one by one
+fn bulk_wire_format() {
+    let a:Vec<u8> = vec![1, 2, 3];
+    let b:Vec<i8> = vec![-1, 0, 1];
+    let c:Vec<u32> = vec![0x01020304, 7];
+    let d:Vec<f32> = vec![1.5, -2.25];
+    let e:[i64; 2] = [-1, 2];
+
+    assert_eq!(buffer!([a]:u8), per_element(&a));
+    assert_eq!(buffer!([b]:i8), per_element(&b));
+    assert_eq!(buffer!([c]:u32), vec![2, 0, 0, 0, 4, 3, 2, 1, 7, 0, 0, 0]);
+    assert_eq!(buffer!([d]:f32), per_element(&d));
+    assert_eq!(buffer!([e]:i64), per_element(&e));
+    assert_eq!(bytes_size!([a]:u8, [c]:u32, [e]:i64), 7 + 12 + 20);
+
+    // Serialize in the middle of a bigger buffer
+    let mut buffer = [0xFFu8; 16];
+    let middle = &mut buffer[2..];
+    serialize!(middle, bytes_copied, [c]:u32);
+    assert_eq!(bytes_copied, 12);
+    assert_eq!(buffer[0..2], [0xFF, 0xFF]);
+    assert_eq!(buffer[2..14], per_element(&c)[..]);
+    assert_eq!(buffer[14..16], [0xFF, 0xFF]);
+}
+
+#[test]
+// Truncated numeric slices return errors at the right offset
+fn bulk_errors() {
+    let a:Vec<u8> = vec![1, 2];
+    let b:Vec<i8> = vec![-1];
+    let c:Vec<u16> = vec![1, 2];
+    let d:Vec<f64> = vec![0.5];
+    let buffer = buffer!([a]:u8, [b]:i8, [c]:u16, [d]:f64);
+    assert_eq!(try_deserialize_numerics(&buffer), Ok((a, b, c, d)));
+
+    // Length prefixes bigger than remaining bytes are rejected before copy
+    assert_eq!(try_deserialize_numerics(&buffer[0..5]), Err(Error::InvalidLength { offset: 0, length: 2, available: 1 }));
+    assert_eq!(try_deserialize_numerics(&buffer[0..18]), Err(Error::InvalidLength { offset: 11, length: 4, available: 3 }));
+    assert_eq!(try_deserialize_numerics(&buffer[0..29]), Err(Error::InvalidLength { offset: 19, length: 8, available: 6 }));
+    assert_eq!(Vec::<u16>::try_deserialize(&buffer[11..17]), Err(Error::InvalidLength { offset: 0, length: 4, available: 2 }));
+
+    // Buffer too small to write slice
+    let e:Vec<u32> = vec![1, 2, 3];
+    let mut small = vec![0u8; 15];
+    assert_eq!(try_serialize!(small, [e]:u32), Err(Error::BufferTooSmall { required: 16, available: 15 }));
+    assert_eq!(e.try_serialize(&mut small), Err(Error::BufferTooSmall { required: 16, available: 15 }));
+    assert_eq!(try_buffer!(16, [e]:u32).map(|buffer| buffer.len()), Ok(16));
+
+    // Slices of implementors are still written one by one with offset of errors
+    let f:Vec<TamponS3> = vec![TamponS3 { percent: 1 }, TamponS3 { percent: 101 }];
+    assert_eq!(try_buffer!(16, (7):u8, [f]:TamponS3), Err(Error::InvalidValue { offset: 6, reason: String::from("percent > 100") }));
+}
+
+#[test]
+// Decoded vectors are allocated once at their final capacity
+fn bulk_capacity() {
+    let a:Vec<u32> = vec![7; 100];
+    let b:Vec<String> = vec![String::from("tampon"); 10];
+    let buffer = buffer!([a]:u32, [b]:String);
+
+    deserialize!(buffer, [c]:u32, [d]:String);
+    assert_eq!((c.capacity(), d.capacity()), (100, 10));
+
+    let (e, _) = Vec::<u32>::deserialize(&buffer);
+    assert_eq!(e.capacity(), 100);
+
+    // Huge prefix with permissive limits can't allocate more than buffer holds
+    let mut huge = buffer!([b]:String);
+    huge[0..4].copy_from_slice(&u32::MAX.to_le_bytes());
+    let limits = Limits::new(usize::MAX, usize::MAX, usize::MAX);
+    let result = Vec::<String>::try_deserialize_with(&huge, &limits, &mut Budget::new());
+    assert!(matches!(result, Err(Error::InvalidLength { offset: 0, .. })));
+}
@@ -30,7 +30,7 @@ fn bytes_size_1_bool(){
     let mut size = 0;
     boolean_var!(size, _b0);
     assert!(macro_test_validation(size,
-        bytes_size!((b0):bool)
+        bytes_size!((_b0):bool)
     ));
 }
 
@@ -40,7 +40,7 @@ fn bytes_size_2_bools(){
     let mut size = 0;
     boolean_var!(size, _b0, _b1);
     assert!(macro_test_validation(size,
-        bytes_size!((b0,b1):bool)
+        bytes_size!((_b0,_b1):bool)
     ));
 }
 
@@ -50,7 +50,7 @@ fn bytes_size_10_bools(){
     let mut size = 0;
     boolean_var!(size, _b0, _b1, _b2, _b3, _b4, _b5, _b6, _b7, _b8, _b9);
     assert!(macro_test_validation(size,
-        bytes_size!((b0,b1):bool, (b2):bool, (b3,b4,b5,b6,b7):bool, (b8,b9):bool)
+        bytes_size!((_b0,_b1):bool, (_b2):bool, (_b3,_b4,_b5,_b6,_b7):bool, (_b8,_b9):bool)
     ));
 }
 
@@ -91,7 +91,7 @@ fn bytes_size_1_numeric(){
     let mut size = 0;
     numeric_var!(size, _n0:i8);
     assert!(macro_test_validation(size,
-        bytes_size!((n0):i8)
+        bytes_size!((_n0):i8)
     ));
 }
 
@@ -101,7 +101,7 @@ fn bytes_size_2_numerics(){
     let mut size = 0;
     numeric_var!(size, _n0:f32, _n1:f32, _n2:i128);
     assert!(macro_test_validation(size,
-        bytes_size!((n0, n1):f32, (n2):i128)
+        bytes_size!((_n0, _n1):f32, (_n2):i128)
     ));
 }
 
@@ -112,8 +112,8 @@ fn bytes_size_all_numerics(){
     numeric_var!(size, _n0:u8, _n1:u16, _n2:u32, _n3:u64, _n4:u128, _n5:f32, _n6:f64,
         _n7:i8, _n8:i16, _n9:i32, _n10:i64, _n11:i128);
     assert!(macro_test_validation(size,
-        bytes_size!((n0):u8, (n1):u16, (n2):u32, (n3):u64, (n4):u128, (n5):f32, (n6):f64,
-            (n7):i8, (n8):i16, (n9):i32, (n10):i64, (n11):i128)
+        bytes_size!((_n0):u8, (_n1):u16, (_n2):u32, (_n3):u64, (_n4):u128, (_n5):f32, (_n6):f64,
+            (_n7):i8, (_n8):i16, (_n9):i32, (_n10):i64, (_n11):i128)
     ));
 }
 
@@ -343,7 +343,7 @@ fn bytes_size_1b_1n_1s_1t(){
     tampon_var!(size, t0:TamponS1);
 
     assert!(macro_test_validation(size,
-        bytes_size!((b0):bool, (n0):i8, (s0):String, (t0):TamponS1)
+        bytes_size!((_b0):bool, (_n0):i8, (s0):String, (t0):TamponS1)
     ));
 
 }
@@ -379,7 +379,7 @@ fn bytes_size_1n_1s_1t_1ns_1ss_1ts(){
     tampon_slice!(size, 0, ts0:TamponS1);
 
     assert!(macro_test_validation(size,
-        bytes_size!((b0):bool, (n0):i8, (s0):String, (t0):TamponS1, [bs0]:bool, [ns0]:i8, [ss0]:String, [ts0]:TamponS1)
+        bytes_size!((_b0):bool, (_n0):i8, (s0):String, (t0):TamponS1, [bs0]:bool, [ns0]:i8, [ss0]:String, [ts0]:TamponS1)
     ));
 
 }
@@ -406,10 +406,10 @@ fn bytes_size_10n_10s_10t_10ns_10ss_10ts(){
 
     assert!(macro_test_validation(size,
         bytes_size!(
-            (b0,b1):bool, (b2):bool, (b3,b4,b5,b6,b7):bool, (b8,b9):bool,
+            (_b0,_b1):bool, (_b2):bool, (_b3,_b4,_b5,_b6,_b7):bool, (_b8,_b9):bool,
             [bs0,bs1]:bool, [bs2]:bool, [bs3,bs4,bs5,bs6,bs7]:bool, [bs8,bs9]:bool,
-            (n0):u8, (n1):u16, (n2):u32, (n3):u64, (n4):u128, (n5):f32, (n6):f64,
-            (n7):i8, (n8):i16, (n9):i32, (n10):i64, (n11):i128,
+            (_n0):u8, (_n1):u16, (_n2):u32, (_n3):u64, (_n4):u128, (_n5):f32, (_n6):f64,
+            (_n7):i8, (_n8):i16, (_n9):i32, (_n10):i64, (_n11):i128,
             [ns0]:u8, [ns1]:u16, [ns2]:u32, [ns3]:u64, [ns4]:u128, [ns5]:f32, [ns6]:f64,
             [ns7]:i8, [ns8]:i16, [ns9]:i32, [ns10]:i64, [ns11]:i128,
             (s0,s1):String, (s2,s3,s4):String, (s5):String, (s6,s7,s8,s9):String,
@@ -0,0 +1,80 @@
+/*
+ * @file tampon/tests/char_test.rs
+ *
+ * @module tampon::tests
+ *
+ * @brief Contains integration tests for char encoding.
+ *
+ * @details
+ * Contains integration tests for char encoding.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use tampon::{buffer, deserialize, bytes_size, try_deserialize, Error};
+pub use tampon::Tampon;
+
+// Deserialize a char and a slice of char without panicking
+fn try_deserialize_chars(buffer : &[u8]) -> Result<(char, Vec<char>), Error> {
+    try_deserialize!(buffer, (a):char, [b]:char);
+    Ok((a, b))
+}
+
+#[test]
+// char round trip in macros and slices
+fn char_round_trip() {
+    let a = 'a';
+    let b = '\u{10FFFF}';
+    let c:Vec<char> = "tàmpön🦀".chars().collect();
+
+    assert_eq!(bytes_size!((a, b):char, [c]:char), 4 + 4 + 4 + 4 * c.len());
+
+    let buffer = buffer!((a, b):char, [c]:char);
+    deserialize!(buffer, bytes_read, (d, e):char, [f]:char);
+
+    assert_eq!(bytes_read, buffer.len());
+    assert!(a == d && b == e && c == f);
+    assert_eq!(try_deserialize_chars(&buffer[4..]), Ok((b, c)));
+}
+
+#[test]
+// char is written as its scalar value in 4 bytes
+fn char_wire_format() {
+    let a = '🦀';
+    let b:u32 = 0x1F980;
+
+    assert_eq!(buffer!((a):char), buffer!((b):u32));
+    assert_eq!(buffer!((a):char), vec![0x80, 0xF9, 0x01, 0x00]);
+}
+
+#[test]
+// Surrogates and values over char::MAX are rejected
+fn char_invalid() {
+    // Surrogate
+    assert_eq!(char::try_deserialize(&[0x00, 0xD8, 0, 0]), Err(Error::OutOfRange { offset: 0, value: 0xD800 }));
+
+    // Over char::MAX inside slice
+    let buffer:Vec<u8> = vec![65, 0, 0, 0, 2, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0x11, 0];
+    assert_eq!(try_deserialize_chars(&buffer), Err(Error::OutOfRange { offset: 12, value: 0x110000 }));
+
+    // Truncated
+    assert_eq!(try_deserialize_chars(&buffer[0..2]), Err(Error::UnexpectedEnd { offset: 0, needed: 4, available: 2 }));
+}
+
+#[test]
+#[should_panic]
+// deserialize! panics for surrogates
+fn char_invalid_panic() {
+    let buffer:Vec<u8> = vec![0x00, 0xDC, 0, 0];
+    deserialize!(buffer, (_a):char);
+}
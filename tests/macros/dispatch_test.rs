@@ -0,0 +1,92 @@
+/*
+ * @file tampon/tests/dispatch_test.rs
+ *
+ * @module tampon::tests
+ *
+ * @brief Contains integration tests for dispatch of types through Tampon trait in macros.
+ *
+ * @details
+ * Contains integration tests for dispatch of types through Tampon trait in macros.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use tampon::{buffer, deserialize, bytes_size, try_buffer, try_deserialize, Error, Budget, Limits};
+pub use tampon::Tampon;
+
+// Aliases of compatible types
+type UserId = u32;
+type Name = String;
+type Flag = bool;
+
+#[test]
+// Type aliases use the encoding of their type
+fn dispatch_alias() {
+    let id:UserId = 0x01020304;
+    let name:Name = String::from("user");
+    let flags:Vec<Flag> = vec![true, false];
+
+    assert_eq!(bytes_size!((id):UserId, (name):Name, [flags]:Flag), 4 + 8 + 6);
+
+    let buffer = buffer!((id):UserId, (name):Name, [flags]:Flag);
+    assert_eq!(buffer, buffer!((id):u32, (name):String, [flags]:bool));
+
+    deserialize!(buffer, bytes_read, (id2):UserId, (name2):Name, [flags2]:Flag);
+    assert!(id == id2 && name == name2 && flags == flags2 && bytes_read == buffer.len());
+}
+
+#[test]
+// Path-qualified primitive types
+fn dispatch_path() {
+    let a:u64 = u64::MAX;
+    let b = vec![String::from("a"), String::from("bc")];
+
+    let buffer = buffer!((a):core::primitive::u64, [b]:std::string::String);
+    assert_eq!(buffer, buffer!((a):u64, [b]:String));
+
+    let result = (|| -> Result<(u64, Vec<String>), Error> {
+        try_deserialize!(buffer, (c):core::primitive::u64, [d]:std::string::String);
+        Ok((c, d))
+    })();
+    assert_eq!(result, Ok((a, b)));
+}
+
+#[test]
+// Primitives implement Tampon with the same encoding as macros
+fn dispatch_primitive_trait() {
+    let mut buffer = [0u8; 4];
+    assert_eq!(0x0A0B0C0Du32.serialize(&mut buffer), 4);
+    assert_eq!(buffer, [0x0D, 0x0C, 0x0B, 0x0A]);
+    assert_eq!(u32::deserialize(&buffer), (0x0A0B0C0D, 4));
+    assert_eq!(<bool as Tampon>::deserialize(&[2]), (true, 1));
+
+    let name = String::from("tampon");
+    assert_eq!(name.bytes_size(), 10);
+    assert_eq!(try_buffer!(1024, (name):String), Ok(buffer!((name):String)));
+}
+
+#[test]
+// Errors of primitives keep their offset in buffer
+fn dispatch_errors() {
+    let buffer:Vec<u8> = vec![1, 2, 0, 0, 0, 0xFF, 0xFE, 7];
+
+    assert_eq!(u16::try_deserialize(&buffer[0..1]), Err(Error::UnexpectedEnd { offset: 0, needed: 2, available: 1 }));
+    assert_eq!(String::try_deserialize(&buffer[1..]), Err(Error::InvalidUtf8 { offset: 4 }));
+    assert_eq!(String::try_deserialize_with(&buffer[1..], &Limits::new(16, 1, 1024), &mut Budget::new()), Err(Error::LimitExceeded { offset: 0, length: 2, limit: 1 }));
+
+    let result = (|| -> Result<String, Error> {
+        try_deserialize!(buffer, (_a):u8, (b):Name);
+        Ok(b)
+    })();
+    assert_eq!(result, Err(Error::InvalidUtf8 { offset: 5 }));
+}
@@ -0,0 +1,127 @@
+/*
+ * @file tampon/tests/endian_test.rs
+ *
+ * @module tampon::tests
+ *
+ * @brief Contains integration tests for big endian fields.
+ *
+ * @details
+ * Contains integration tests for big endian fields written with BigEndian<T> and its aliases.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use tampon::{buffer, deserialize, bytes_size, serialize, try_buffer, try_deserialize, try_serialize, Error, Limits, BigEndian,
+    u16be, u32be, u64be, u128be, i16be, i32be, i64be, i128be, f32be, f64be};
+
+// Header of a network packet
+type Header = (u16, u32, String, Vec<u16>);
+
+// Deserialize a big endian header without panicking
+fn try_deserialize_header(buffer : &[u8], limits : &Limits) -> Result<Header, Error> {
+    try_deserialize!(buffer, limits = limits, (a):u16be, (b):u32be, (c):BigEndian<String>, [d]:u16be);
+    Ok((a, b, c, d))
+}
+
+#[test]
+// Numerics are written with most significant byte first
+fn endian_wire_format() {
+    assert_eq!(buffer!((0x0102):u16be), vec![1, 2]);
+    assert_eq!(buffer!((0x01020304):u32be), vec![1, 2, 3, 4]);
+    assert_eq!(buffer!((0x0102030405060708):u64be), vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(buffer!((1):u128be), [vec![0; 15], vec![1]].concat());
+    assert_eq!(buffer!((-2):i16be), vec![0xFF, 0xFE]);
+    assert_eq!(buffer!((-2):i32be), vec![0xFF, 0xFF, 0xFF, 0xFE]);
+    assert_eq!(buffer!((-2):i64be), vec![0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE]);
+    assert_eq!(buffer!((-2):i128be), [vec![0xFF; 15], vec![0xFE]].concat());
+    assert_eq!(buffer!((1.0):f32be), 1.0f32.to_be_bytes().to_vec());
+    assert_eq!(buffer!((1.0):f64be), 1.0f64.to_be_bytes().to_vec());
+    assert_eq!(buffer!((7):BigEndian<u8>, (-1):BigEndian<i8>), vec![7, 0xFF]);
+    assert_eq!(buffer!((258):BigEndian<usize>, (-2):BigEndian<isize>), vec![0, 0, 0, 0, 0, 0, 1, 2, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE]);
+    assert_eq!(buffer!(('A'):BigEndian<char>), vec![0, 0, 0, 0x41]);
+
+    // Length prefixes of strings and slices follow the same order
+    let a:Vec<u16> = vec![1, 2];
+    assert_eq!(buffer!(("ab"):BigEndian<str>), vec![0, 0, 0, 2, b'a', b'b']);
+    assert_eq!(buffer!([a]:u16be), vec![0, 0, 0, 2, 0, 1, 0, 2]);
+    assert_eq!(buffer!([a]:BigEndian<u16>), buffer!((a):BigEndian<Vec<u16>>));
+}
+
+#[test]
+// Big endian fields round trip in all macros
+fn endian_round_trip() {
+    let a:u16 = 8080;
+    let b:u32 = 0x7F000001;
+    let c = String::from("localhost");
+    let d:Vec<u16> = vec![80, 443];
+    let e:char = 'é';
+    let f:Vec<Vec<i32>> = vec![vec![-1, 2], vec![]];
+    let g:Vec<String> = vec![String::from("tampon")];
+
+    let size = bytes_size!((a):u16be, (b):u32be, (c):BigEndian<String>, [d]:u16be, (e):BigEndian<char>, [f]:BigEndian<Vec<i32>>, [g]:BigEndian<String>);
+    assert_eq!(size, 2 + 4 + 13 + 8 + 4 + 4 + 12 + 4 + 4 + 10);
+
+    let buffer = buffer!((a):u16be, (b):u32be, (c):BigEndian<String>, [d]:u16be, (e):BigEndian<char>, [f]:BigEndian<Vec<i32>>, [g]:BigEndian<String>);
+    assert_eq!(buffer.len(), size);
+    assert_eq!(try_buffer!(size, (a):u16be, (b):u32be, (c):BigEndian<String>, [d]:u16be, (e):BigEndian<char>, [f]:BigEndian<Vec<i32>>, [g]:BigEndian<String>), Ok(buffer.clone()));
+
+    let mut copy = vec![0u8; size];
+    serialize!(copy, bytes_copied, (a):u16be, (b):u32be, (c):BigEndian<String>, [d]:u16be, (e):BigEndian<char>, [f]:BigEndian<Vec<i32>>, [g]:BigEndian<String>);
+    assert_eq!((bytes_copied, &copy), (size, &buffer));
+
+    let mut fallible = vec![0u8; size];
+    assert_eq!(try_serialize!(fallible, (a):u16be, (b):u32be, (c):BigEndian<String>, [d]:u16be, (e):BigEndian<char>, [f]:BigEndian<Vec<i32>>, [g]:BigEndian<String>), Ok(size));
+    assert_eq!(fallible, buffer);
+
+    deserialize!(buffer, bytes_read, (a2):u16be, (b2):u32be, (c2):BigEndian<&str>, [d2]:u16be, (e2):BigEndian<char>, [f2]:BigEndian<Vec<i32>>, [g2]:BigEndian<String>);
+    assert_eq!(bytes_read, size);
+    assert_eq!((a2, b2, c2, d2, e2, f2, g2), (a, b, c.as_str(), d.clone(), e, f.clone(), g.clone()));
+
+    let header = try_deserialize_header(&buffer, &Limits::default());
+    assert_eq!(header, Ok((a, b, c.clone(), d.clone())));
+
+    // Little and big endian fields can be mixed
+    let buffer = buffer!((a):u16be, (a):u16, [d]:u16be, [d]:u16);
+    assert_eq!(buffer[0..4], [0x1F, 0x90, 0x90, 0x1F]);
+    deserialize!(buffer, (h):u16be, (i):u16, [j]:u16be, [k]:u16);
+    assert_eq!((h, i, j, k), (a, a, d.clone(), d));
+}
+
+#[test]
+// Big endian fields return errors for invalid buffers
+fn endian_errors() {
+    let buffer = buffer!((1):u16be, (2):u32be, ("tampon"):BigEndian<str>, [vec![1u16, 2]]:u16be);
+    assert!(try_deserialize_header(&buffer, &Limits::default()).is_ok());
+
+    // Truncated numerics, string and slice
+    assert_eq!(try_deserialize_header(&buffer[0..1], &Limits::default()), Err(Error::UnexpectedEnd { offset: 0, needed: 2, available: 1 }));
+    assert_eq!(try_deserialize_header(&buffer[0..4], &Limits::default()), Err(Error::UnexpectedEnd { offset: 2, needed: 4, available: 2 }));
+    assert_eq!(try_deserialize_header(&buffer[0..12], &Limits::default()), Err(Error::InvalidLength { offset: 6, length: 6, available: 2 }));
+    assert_eq!(try_deserialize_header(&buffer[0..23], &Limits::default()), Err(Error::InvalidLength { offset: 16, length: 4, available: 3 }));
+
+    // Limits are enforced with big endian prefixes
+    assert_eq!(try_deserialize_header(&buffer, &Limits::new(1, 16, 1024)), Err(Error::LimitExceeded { offset: 16, length: 2, limit: 1 }));
+    assert_eq!(try_deserialize_header(&buffer, &Limits::new(16, 2, 1024)), Err(Error::LimitExceeded { offset: 6, length: 6, limit: 2 }));
+
+    // Invalid UTF-8 and char
+    let mut invalid = buffer.clone();
+    invalid[11] = 0xFF;
+    assert_eq!(try_deserialize_header(&invalid, &Limits::default()), Err(Error::InvalidUtf8 { offset: 11 }));
+    let char_buffer = buffer!((0xD800):u32be);
+    let result = (|| -> Result<char, Error> { try_deserialize!(char_buffer, (a):BigEndian<char>); Ok(a) })();
+    assert_eq!(result, Err(Error::OutOfRange { offset: 0, value: 0xD800 }));
+
+    // Buffer too small
+    let mut small = [0u8; 7];
+    assert_eq!(try_serialize!(small, [vec![1u16, 2]]:u16be), Err(Error::BufferTooSmall { required: 8, available: 7 }));
+}
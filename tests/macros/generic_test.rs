@@ -0,0 +1,204 @@
+/*
+ * @file tampon/tests/generic_test.rs
+ *
+ * @module tampon::tests
+ *
+ * @brief Contains integration tests for generic, path-qualified and legacy implementors of Tampon.
+ *
+ * @details
+ * Contains integration tests for generic, path-qualified and legacy implementors of Tampon.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use tampon::{buffer, deserialize, serialize, bytes_size, try_buffer, try_serialize, try_deserialize, Error, Budget, Limits};
+pub use tampon::Tampon;
+use crate::implementation::{TamponS2, TamponS3};
+
+// Generic struct wrapping an implementor of Tampon
+#[derive(Debug, PartialEq)]
+pub struct Wrapper<T: Tampon> {
+    id:u16,
+    inner:T,
+    list:Vec<T>,
+}
+
+impl<T: Tampon> Tampon for Wrapper<T> {
+    fn bytes_size(&self) -> usize {
+        bytes_size!((self.id):u16, (self.inner):T, [self.list]:T)
+    }
+
+    fn serialize(&self, buffer : &mut [u8]) -> usize {
+        serialize!(buffer, bytes_copied, (self.id):u16, (self.inner):T, [self.list]:T);
+        bytes_copied
+    }
+
+    fn deserialize(buffer : &[u8]) -> (Self, usize) {
+        deserialize!(buffer, bytes_read, (id):u16, (inner):T, [list]:T);
+        (Wrapper { id, inner, list }, bytes_read)
+    }
+
+    fn try_serialize(&self, buffer : &mut [u8]) -> Result<usize, Error> {
+        try_serialize!(buffer, (self.id):u16, (self.inner):T, [self.list]:T)
+    }
+
+    fn try_deserialize_with(buffer : &[u8], limits : &Limits, budget : &mut Budget) -> Result<(Self, usize), Error> {
+        try_deserialize!(buffer, bytes_read, limits = limits, budget = budget, (id):u16, (inner):T, [list]:T);
+        Ok((Wrapper { id, inner, list }, bytes_read))
+    }
+}
+
+// Module used for path-qualified types
+pub mod protocol {
+    use tampon::{Tampon, bytes_size, serialize, deserialize};
+
+    #[derive(Debug, PartialEq)]
+    pub struct Header {
+        pub version:u8,
+    }
+
+    impl Tampon for Header {
+        fn bytes_size(&self) -> usize {
+            bytes_size!((self.version):u8)
+        }
+
+        fn serialize(&self, buffer : &mut [u8]) -> usize {
+            serialize!(buffer, bytes_copied, (self.version):u8);
+            bytes_copied
+        }
+
+        fn deserialize(buffer : &[u8]) -> (Self, usize) {
+            deserialize!(buffer, bytes_read, (version):u8);
+            (Header { version }, bytes_read)
+        }
+    }
+}
+
+// Implementor of previous Tampon<T> trait
+#[allow(deprecated)]
+pub mod legacy {
+    use tampon::{bytes_size, serialize, deserialize, legacy::Tampon};
+
+    #[derive(Debug, PartialEq)]
+    pub struct Old {
+        pub value:u32,
+    }
+
+    impl Tampon<Old> for Old {
+        fn bytes_size(&self) -> usize {
+            bytes_size!((self.value):u32)
+        }
+
+        fn serialize(&self, buffer : &mut [u8]) -> usize {
+            serialize!(buffer, bytes_copied, (self.value):u32);
+            bytes_copied
+        }
+
+        fn deserialize(buffer : &[u8]) -> (Old, usize) {
+            deserialize!(buffer, bytes_read, (value):u32);
+            (Old { value }, bytes_read)
+        }
+    }
+}
+
+// Create a wrapper of TamponS2
+fn wrapper() -> Wrapper<TamponS2> {
+    Wrapper { id: 7, inner: TamponS2::new(1, -5), list: vec![TamponS2::new(2, 10), TamponS2::new(3, 20)] }
+}
+
+#[test]
+// Serialize and deserialize generic types with all macros
+fn generic_round_trip() {
+    let a = wrapper();
+    let b = vec![wrapper(), wrapper()];
+
+    let buffer = buffer!((a):Wrapper<TamponS2>, [b]:Wrapper<TamponS2>, (a.id):u16);
+    assert_eq!(buffer.len(), bytes_size!((a):Wrapper<TamponS2>, [b]:Wrapper<TamponS2>, (a.id):u16));
+
+    deserialize!(buffer, bytes_read, (c):Wrapper<TamponS2>, [d]:Wrapper<TamponS2>, (e):u16);
+    assert_eq!(bytes_read, buffer.len());
+    assert!(a == c && b == d && e == 7);
+}
+
+#[test]
+// Generic types written with the same format as their hand-written counterpart
+fn generic_wire_format() {
+    let a = wrapper();
+    let buffer = buffer!((a):Wrapper<TamponS2>);
+    let expected = buffer!((a.id):u16, (a.inner):TamponS2, [a.list]:TamponS2);
+    assert_eq!(buffer, expected);
+}
+
+#[test]
+// Path-qualified types
+fn generic_path_qualified() {
+    let a = protocol::Header { version: 3 };
+    let b = vec![protocol::Header { version: 4 }];
+
+    let buffer = buffer!((a):protocol::Header, [b]:protocol::Header);
+    assert_eq!(buffer, vec![3, 1, 0, 0, 0, 4]);
+
+    deserialize!(buffer, (c):protocol::Header, [d]:crate::macros::generic_test::protocol::Header);
+    assert!(a == c && b == d);
+}
+
+#[test]
+// Fallible macros with generic types
+fn generic_fallible() {
+    let a = Wrapper { id: 1, inner: TamponS3 { percent: 50 }, list: vec![TamponS3 { percent: 10 }, TamponS3 { percent: 200 }] };
+
+    // Validation error of element nested in generic type is offset
+    let buffer = buffer!((a):Wrapper<TamponS3>);
+    let result = (|| -> Result<Wrapper<TamponS3>, Error> {
+        try_deserialize!(buffer, (b):Wrapper<TamponS3>);
+        Ok(b)
+    })();
+    assert_eq!(result.err(), Some(Error::InvalidValue { offset: 8, reason: String::from("percent > 100") }));
+    assert_eq!(try_buffer!(1024, (a):Wrapper<TamponS3>).err(), Some(Error::InvalidValue { offset: 8, reason: String::from("percent > 100") }));
+
+    // Buffer too small
+    assert_eq!(try_buffer!(2, (a):Wrapper<TamponS3>).err(), Some(Error::BufferTooSmall { required: 9, available: 2 }));
+}
+
+#[test]
+// Implementors of legacy trait are usable with macros and the new trait
+fn generic_legacy() {
+    let a = legacy::Old { value: 42 };
+    let b = vec![legacy::Old { value: 1 }, legacy::Old { value: 2 }];
+
+    let buffer = buffer!((a):legacy::Old, [b]:legacy::Old);
+    deserialize!(buffer, (c):legacy::Old, [d]:legacy::Old);
+    assert!(a == c && b == d);
+
+    // Nested in a generic type
+    let w = Wrapper { id: 2, inner: legacy::Old { value: 3 }, list: Vec::new() };
+    let buffer = buffer!((w):Wrapper<legacy::Old>);
+    assert_eq!(buffer, vec![2, 0, 3, 0, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+// Tampon can be used as a trait object to size and serialize
+fn generic_dyn() {
+    let values:Vec<Box<dyn Tampon>> = vec![Box::new(wrapper()), Box::new(protocol::Header { version: 9 })];
+
+    let size:usize = values.iter().map(|value| value.bytes_size()).sum();
+    let mut buffer = vec![0u8; size];
+
+    let mut bytes_copied = 0;
+    for value in values.iter() {
+        bytes_copied += value.serialize(&mut buffer[bytes_copied..]);
+    }
+
+    assert_eq!(bytes_copied, size);
+    assert_eq!(buffer[size - 1], 9);
+}
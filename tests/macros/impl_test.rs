@@ -0,0 +1,114 @@
+/*
+ * @file tampon/tests/impl_test.rs
+ *
+ * @module tampon::tests
+ *
+ * @brief Contains integration tests for implementations of Tampon trait of primitives, String and Vec.
+ *
+ * @details
+ * Contains integration tests for implementations of Tampon trait of primitives, String and Vec.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use tampon::{buffer, deserialize, Error, Budget, Limits};
+pub use tampon::Tampon;
+use crate::implementation::{TamponS2, TamponS3};
+
+// Generic function accepting any implementor
+fn send<M: Tampon>(message : &M) -> Vec<u8> {
+    let mut buffer = vec![0u8; message.bytes_size()];
+    assert_eq!(message.serialize(&mut buffer), buffer.len());
+    buffer
+}
+
+// Generic function deserializing any implementor
+fn receive<M: Tampon>(buffer : &[u8]) -> M {
+    let (message, bytes_read) = M::try_deserialize(buffer).unwrap();
+    assert_eq!(bytes_read, buffer.len());
+    message
+}
+
+#[test]
+// Primitives and String produce the same bytes as macros
+fn impl_primitives() {
+    let (a, b, c, d, e) = (true, u8::MAX, i128::MIN, -2.5f64, String::from("tampon"));
+
+    assert_eq!(send(&a), buffer!((a):bool));
+    assert_eq!(send(&b), buffer!((b):u8));
+    assert_eq!(send(&c), buffer!((c):i128));
+    assert_eq!(send(&d), buffer!((d):f64));
+    assert_eq!(send(&e), buffer!((e):String));
+
+    assert_eq!(receive::<bool>(&send(&a)), a);
+    assert_eq!(receive::<i128>(&send(&c)), c);
+    assert_eq!(receive::<String>(&send(&e)), e);
+}
+
+#[test]
+// Vec produce the same bytes as slices of macros
+fn impl_vec() {
+    let a:Vec<u32> = vec![1, 2, 3];
+    let b:Vec<String> = vec![String::from("a"), String::from("")];
+    let c:Vec<TamponS2> = vec![TamponS2::new(1, 2), TamponS2::new(3, 4)];
+    let d:Vec<bool> = Vec::new();
+
+    assert_eq!(send(&a), buffer!([a]:u32));
+    assert_eq!(send(&b), buffer!([b]:String));
+    assert_eq!(send(&c), buffer!([c]:TamponS2));
+    assert_eq!(send(&d), vec![0, 0, 0, 0]);
+
+    assert_eq!(receive::<Vec<u32>>(&send(&a)), a);
+    assert_eq!(receive::<Vec<String>>(&send(&b)), b);
+    assert_eq!(receive::<Vec<TamponS2>>(&send(&c)), c);
+    assert_eq!(Vec::<TamponS2>::deserialize(&send(&c)), (c, 4 + 17 * 2));
+}
+
+#[test]
+// Vec is usable as a type in macros and as slice element
+fn impl_vec_macros() {
+    let a:Vec<u16> = vec![1, 2];
+    let b:Vec<Vec<u16>> = vec![vec![3], vec![], vec![4, 5]];
+
+    let buffer = buffer!((a):Vec<u16>, [b]:Vec<u16>);
+    assert_eq!(buffer[0..8], buffer!([a]:u16));
+
+    deserialize!(buffer, bytes_read, (c):Vec<u16>, [d]:Vec<u16>);
+    assert!(a == c && b == d && bytes_read == buffer.len());
+}
+
+#[test]
+// Fallible functions of Vec offset errors of elements
+fn impl_vec_errors() {
+    // Validation of element
+    let a = vec![TamponS3 { percent: 1 }, TamponS3 { percent: 101 }];
+    let mut buffer = vec![0u8; a.bytes_size()];
+    assert_eq!(a.try_serialize(&mut buffer), Err(Error::InvalidValue { offset: 5, reason: String::from("percent > 100") }));
+    assert_eq!(a.try_serialize(&mut buffer[0..2]), Err(Error::BufferTooSmall { required: 6, available: 2 }));
+
+    a.serialize(&mut buffer);
+    assert_eq!(Vec::<TamponS3>::try_deserialize(&buffer), Err(Error::InvalidValue { offset: 5, reason: String::from("percent > 100") }));
+
+    // Truncated buffer and hostile length prefix
+    let b:Vec<u32> = vec![7, 8];
+    let buffer = send(&b);
+    assert_eq!(Vec::<u32>::try_deserialize(&buffer[0..2]), Err(Error::UnexpectedEnd { offset: 0, needed: 4, available: 2 }));
+    assert_eq!(Vec::<u32>::try_deserialize(&buffer[0..10]), Err(Error::InvalidLength { offset: 0, length: 8, available: 6 }));
+    assert_eq!(Vec::<u32>::try_deserialize(&[0xFF, 0xFF, 0xFF, 0xFF]), Err(Error::InvalidLength { offset: 0, length: 0xFFFFFFFF * 4, available: 0 }));
+
+    // Limits are shared by nested vectors
+    let c:Vec<Vec<u8>> = vec![vec![1, 2], vec![3, 4, 5]];
+    let buffer = send(&c);
+    assert_eq!(Vec::<Vec<u8>>::try_deserialize_with(&buffer, &Limits::new(2, 16, 1024), &mut Budget::new()), Err(Error::LimitExceeded { offset: 10, length: 3, limit: 2 }));
+    assert_eq!(Vec::<Vec<u8>>::try_deserialize(&buffer), Ok((c, buffer.len())));
+}
@@ -0,0 +1,141 @@
+/*
+ * @file tampon/tests/map_test.rs
+ *
+ * @module tampon::tests
+ *
+ * @brief Contains integration tests for maps, sets and Sorted.
+ *
+ * @details
+ * Contains integration tests for maps, sets and Sorted.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use std::collections::{ BTreeMap, BTreeSet, HashMap, HashSet, VecDeque };
+use tampon::{buffer, deserialize, bytes_size, try_buffer, try_deserialize, Error, Leb128, Budget, Limits, Prefixed, Sorted};
+pub use tampon::Tampon;
+use crate::implementation::TamponS3;
+
+// Create a map with many keys to get a random iteration order
+fn scores() -> HashMap<String, u32> {
+    (0..32).map(|i| (format!("player{}", i), i * 10)).collect()
+}
+
+// Deserialize a map and a set without panicking
+fn try_deserialize_collections(buffer : &[u8]) -> Result<(BTreeMap<u8, String>, HashSet<u16>), Error> {
+    try_deserialize!(buffer, (a):BTreeMap<u8, String>, (b):HashSet<u16>);
+    Ok((a, b))
+}
+
+#[test]
+// Collections round trip in macros and slices
+fn map_round_trip() {
+    let a:HashMap<String, u32> = scores();
+    let b:BTreeMap<u8, Vec<String>> = [(1, vec![String::from("a")]), (2, vec![])].into_iter().collect();
+    let c:HashSet<i64> = [-1, 0, 1].into_iter().collect();
+    let d:BTreeSet<char> = ['a', 'b'].into_iter().collect();
+    let e:VecDeque<u16> = [1, 2, 3].into_iter().collect();
+    let f:Vec<BTreeMap<u8, u8>> = vec![[(1, 2)].into_iter().collect(), BTreeMap::new()];
+
+    let buffer = buffer!((a):HashMap<String, u32>, (b):BTreeMap<u8, Vec<String>>, (c):HashSet<i64>, (d):BTreeSet<char>,
+        (e):VecDeque<u16>, [f]:BTreeMap<u8, u8>);
+    deserialize!(buffer, bytes_read, (g):HashMap<String, u32>, (h):BTreeMap<u8, Vec<String>>, (i):HashSet<i64>,
+        (j):BTreeSet<char>, (k):VecDeque<u16>, [l]:BTreeMap<u8, u8>);
+
+    assert_eq!(bytes_read, buffer.len());
+    assert!(a == g && b == h && c == i && d == j && e == k && f == l);
+}
+
+#[test]
+// Collections are written with length prefix, maps write each key followed by its value
+fn map_wire_format() {
+    let a:BTreeMap<u8, u16> = [(2, 0x0304), (1, 0x0102)].into_iter().collect();
+    let b:BTreeSet<u8> = [9, 8].into_iter().collect();
+    let c:VecDeque<u8> = [7, 6].into_iter().collect();
+    let d:Vec<u8> = vec![7, 6];
+
+    assert_eq!(bytes_size!((a):BTreeMap<u8, u16>), 4 + 6);
+    assert_eq!(buffer!((a):BTreeMap<u8, u16>), vec![2, 0, 0, 0, 1, 2, 1, 2, 4, 3]);
+    assert_eq!(buffer!((b):BTreeSet<u8>), vec![2, 0, 0, 0, 8, 9]);
+    assert_eq!(buffer!((c):VecDeque<u8>), buffer!([d]:u8));
+}
+
+#[test]
+// Sorted writes hash collections in order of encoded keys
+fn map_sorted() {
+    let a:Sorted<HashMap<String, u32>> = Sorted(scores());
+    let b:Sorted<HashMap<String, u32>> = Sorted(scores());
+
+    // Same buffer for equal maps and same size as HashMap
+    let buffer = buffer!((a):Sorted<HashMap<String, u32>>);
+    assert_eq!(buffer, buffer!((b):Sorted<HashMap<String, u32>>));
+    assert_eq!(buffer.len(), a.0.bytes_size());
+
+    // Readable as HashMap and Sorted
+    deserialize!(buffer, (c):HashMap<String, u32>);
+    assert_eq!(c, *a);
+    assert_eq!(Sorted::<HashMap<String, u32>>::try_deserialize(&buffer), Ok((a, buffer.len())));
+
+    // Sets are sorted by encoded bytes, which is little endian for numerics
+    let e:Sorted<HashSet<u16>> = Sorted([0x0201, 0x0102, 0x0003].into_iter().collect());
+    assert_eq!(buffer!((e):Sorted<HashSet<u16>>), vec![3, 0, 0, 0, 1, 2, 2, 1, 3, 0]);
+
+    // Keys are sorted with the length prefix they are written with
+    let f:Sorted<HashSet<String>> = Sorted([String::from("z"), "a".repeat(256)].into_iter().collect());
+    assert_eq!(&buffer!((f):Sorted<HashSet<String>>)[4..9], &[0, 1, 0, 0, b'a']);
+    assert_eq!(&buffer!((f):Prefixed<Leb128, Sorted<HashSet<String>>>)[1..3], &[1, b'z']);
+    let buffer = buffer!((f):Prefixed<Leb128, Sorted<HashSet<String>>>);
+    deserialize!(buffer, (g):Prefixed<Leb128, HashSet<String>>);
+    assert_eq!(g, f.0);
+}
+
+#[test]
+// Duplicate keys are rejected
+fn map_duplicate_key() {
+    let buffer:Vec<u8> = vec![2, 0, 0, 0, 1, 1, 0, 0, 0, 97, 1, 1, 0, 0, 0, 98, 0, 0, 0, 0];
+    assert_eq!(try_deserialize_collections(&buffer), Err(Error::DuplicateKey { offset: 10 }));
+
+    let buffer:Vec<u8> = vec![0, 0, 0, 0, 3, 0, 0, 0, 1, 0, 2, 0, 1, 0];
+    assert_eq!(try_deserialize_collections(&buffer), Err(Error::DuplicateKey { offset: 12 }));
+    assert_eq!(Error::DuplicateKey { offset: 12 }.to_string(), "duplicate key at offset 12");
+
+    // Duplicates are allowed in VecDeque
+    assert_eq!(VecDeque::<u16>::try_deserialize(&buffer[4..]).map(|(deque, _)| deque.len()), Ok(3));
+}
+
+#[test]
+#[should_panic]
+// deserialize! panics for duplicate keys
+fn map_duplicate_key_panic() {
+    let buffer:Vec<u8> = vec![2, 0, 0, 0, 1, 1];
+    deserialize!(buffer, (_a):BTreeSet<u8>);
+}
+
+#[test]
+// Errors of collections are offset and limits are applied
+fn map_errors() {
+    let a:BTreeMap<u8, String> = [(1, String::from("tampon"))].into_iter().collect();
+    let b:HashSet<u16> = HashSet::new();
+    let buffer = buffer!((a):BTreeMap<u8, String>, (b):HashSet<u16>);
+
+    assert_eq!(try_deserialize_collections(&buffer), Ok((a, b)));
+    assert_eq!(try_deserialize_collections(&buffer[0..8]), Err(Error::InvalidLength { offset: 0, length: 5, available: 4 }));
+    assert_eq!(BTreeMap::<u8, String>::try_deserialize_with(&buffer, &Limits::new(16, 2, 1024), &mut Budget::new()), Err(Error::LimitExceeded { offset: 5, length: 6, limit: 2 }));
+    assert_eq!(BTreeSet::<u8>::try_deserialize_with(&[3, 0, 0, 0, 1, 2, 3], &Limits::new(2, 16, 1024), &mut Budget::new()), Err(Error::LimitExceeded { offset: 0, length: 3, limit: 2 }));
+
+    // Errors of values are offset on serialize
+    let c:HashMap<u8, TamponS3> = [(1, TamponS3 { percent: 101 })].into_iter().collect();
+    assert_eq!(try_buffer!(64, (c):HashMap<u8, TamponS3>), Err(Error::InvalidValue { offset: 5, reason: String::from("percent > 100") }));
+    let d:Sorted<HashMap<u8, TamponS3>> = Sorted(c);
+    assert_eq!(try_buffer!(64, (d):Sorted<HashMap<u8, TamponS3>>), Err(Error::InvalidValue { offset: 5, reason: String::from("percent > 100") }));
+}
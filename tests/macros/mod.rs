@@ -31,4 +31,92 @@ mod de_serialize_test;
 
 // buffer! integration macro tests
 #[cfg(test)]
-mod buffer_test;
\ No newline at end of file
+mod buffer_test;
+
+// try_deserialize! macro tests
+#[cfg(test)]
+mod try_deserialize_test;
+
+// try_serialize! macro tests
+#[cfg(test)]
+mod try_serialize_test;
+
+// try_buffer! macro tests
+#[cfg(test)]
+mod try_buffer_test;
+
+// tampon_enum! macro tests
+#[cfg(test)]
+mod tampon_enum_test;
+
+// Generic, path-qualified and legacy implementors tests
+#[cfg(test)]
+mod generic_test;
+
+// Type dispatch through Tampon trait tests
+#[cfg(test)]
+mod dispatch_test;
+
+// Tampon implementations of primitives, String and Vec tests
+#[cfg(test)]
+mod impl_test;
+
+// usize and isize portable encoding tests
+#[cfg(test)]
+mod pointer_sized_test;
+
+// char encoding tests
+#[cfg(test)]
+mod char_test;
+
+// Option presence byte tests
+#[cfg(test)]
+mod option_test;
+
+// Fixed-size arrays tests
+#[cfg(test)]
+mod array_test;
+
+// Tuples tests
+#[cfg(test)]
+mod tuple_test;
+
+// Maps, sets and Sorted tests
+#[cfg(test)]
+mod map_test;
+
+// Nested containers tests
+#[cfg(test)]
+mod nested_test;
+
+// Borrowed deserialization tests
+#[cfg(test)]
+mod borrow_test;
+
+// Numeric slices copied in bulk tests
+#[cfg(test)]
+mod bulk_test;
+
+// Big endian fields tests
+#[cfg(test)]
+mod endian_test;
+
+// Length prefix widths tests
+#[cfg(test)]
+mod prefix_test;
+
+// Varint fields tests
+#[cfg(test)]
+mod varint_test;
+
+// Packed bool slices tests
+#[cfg(test)]
+mod packed_test;
+
+// Bit fields mixed with macros tests
+#[cfg(test)]
+mod bits_test;
+
+// Streaming to writer and from reader tests
+#[cfg(test)]
+mod stream_test;
@@ -0,0 +1,85 @@
+/*
+ * @file tampon/tests/nested_test.rs
+ *
+ * @module tampon::tests
+ *
+ * @brief Contains integration tests for nested containers like Vec<Vec<T>>.
+ *
+ * @details
+ * Contains integration tests for nested containers like Vec<Vec<T>>.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use tampon::{buffer, deserialize, bytes_size, serialize, try_buffer, try_deserialize, Error, Limits};
+use crate::implementation::TamponS3;
+
+// Ragged samples and labels per frame
+type Frames = (Vec<Vec<f32>>, Vec<Vec<String>>);
+
+// Deserialize ragged samples without panicking
+fn try_deserialize_frames(buffer : &[u8], limits : &Limits) -> Result<Frames, Error> {
+    try_deserialize!(buffer, limits = limits, [a]:Vec<f32>, [b]:Vec<String>);
+    Ok((a, b))
+}
+
+#[test]
+// Nested containers round trip in macros
+fn nested_round_trip() {
+    let element:u8 = 9;
+    let a:Vec<Vec<f32>> = vec![vec![1.0, 2.0], vec![], vec![3.0]];
+    let b:Vec<Vec<String>> = vec![vec![String::from("a"), String::from("bc")], vec![String::new()]];
+    let c:Vec<Vec<Vec<u8>>> = vec![vec![vec![1], vec![2, 3]], vec![]];
+    let d:Vec<Vec<u16>> = vec![vec![4, 5]];
+
+    let mut buffer = vec![0u8; bytes_size!([a]:Vec<f32>, [b]:Vec<String>, [c]:Vec<Vec<u8>>, (d):Vec<Vec<u16>>, (element):u8)];
+    serialize!(buffer, bytes_written, [a]:Vec<f32>, [b]:Vec<String>, [c]:Vec<Vec<u8>>, (d):Vec<Vec<u16>>, (element):u8);
+    assert_eq!(bytes_written, buffer.len());
+
+    // Variables named like macro temporaries don't collide
+    deserialize!(buffer, bytes_read, [e]:Vec<f32>, [f]:Vec<String>, [g]:Vec<Vec<u8>>, (h):Vec<Vec<u16>>, (element):u8);
+    assert_eq!(bytes_read, buffer.len());
+    assert!(a == e && b == f && c == g && d == h && element == 9);
+}
+
+#[test]
+// Each nested container is written with its own length prefix
+fn nested_wire_format() {
+    let a:Vec<Vec<u8>> = vec![vec![1, 2], vec![], vec![3]];
+
+    assert_eq!(bytes_size!([a]:Vec<u8>), 4 + 6 + 4 + 5);
+    assert_eq!(bytes_size!([a]:Vec<u8>), bytes_size!((a):Vec<Vec<u8>>));
+    assert_eq!(buffer!([a]:Vec<u8>), vec![3, 0, 0, 0, 2, 0, 0, 0, 1, 2, 0, 0, 0, 0, 1, 0, 0, 0, 3]);
+    assert_eq!(buffer!([a]:Vec<u8>), buffer!((a):Vec<Vec<u8>>));
+}
+
+#[test]
+// Errors of nested containers are offset from the start of buffer
+fn nested_errors() {
+    let a:Vec<Vec<f32>> = vec![vec![1.0], vec![2.0, 3.0]];
+    let b:Vec<Vec<String>> = vec![vec![String::from("tampon")]];
+    let buffer = buffer!([a]:Vec<f32>, [b]:Vec<String>);
+
+    assert_eq!(try_deserialize_frames(&buffer, &Limits::default()), Ok((a, b)));
+
+    // Inner length prefix bigger than remaining bytes
+    assert_eq!(try_deserialize_frames(&buffer[0..16], &Limits::default()), Err(Error::InvalidLength { offset: 12, length: 8, available: 0 }));
+
+    // Limits apply to inner containers and strings
+    assert_eq!(try_deserialize_frames(&buffer, &Limits::new(1, 16, 1024)), Err(Error::LimitExceeded { offset: 0, length: 2, limit: 1 }));
+    assert_eq!(try_deserialize_frames(&buffer, &Limits::new(16, 2, 1024)), Err(Error::LimitExceeded { offset: 32, length: 6, limit: 2 }));
+
+    // Errors of inner elements on serialize
+    let c:Vec<Vec<TamponS3>> = vec![vec![], vec![TamponS3 { percent: 1 }, TamponS3 { percent: 101 }]];
+    assert_eq!(try_buffer!(64, [c]:Vec<TamponS3>), Err(Error::InvalidValue { offset: 13, reason: String::from("percent > 100") }));
+}
@@ -0,0 +1,101 @@
+/*
+ * @file tampon/tests/option_test.rs
+ *
+ * @module tampon::tests
+ *
+ * @brief Contains integration tests for Option encoding.
+ *
+ * @details
+ * Contains integration tests for Option encoding.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use tampon::{buffer, deserialize, bytes_size, try_buffer, try_deserialize, Error, Budget, Limits};
+pub use tampon::Tampon;
+use crate::implementation::{TamponS2, TamponS3};
+
+// Optional values deserialized by try_deserialize_options
+type Options = (Option<u32>, Option<String>, Vec<Option<TamponS3>>);
+
+// Deserialize optional values without panicking
+fn try_deserialize_options(buffer : &[u8]) -> Result<Options, Error> {
+    try_deserialize!(buffer, (a):Option<u32>, (b):Option<String>, [c]:Option<TamponS3>);
+    Ok((a, b, c))
+}
+
+#[test]
+// Option round trip in macros and slices
+fn option_round_trip() {
+    let a:Option<u32> = Some(12);
+    let b:Option<u32> = None;
+    let c:Option<String> = Some(String::from("tampon"));
+    let d:Option<TamponS2> = Some(TamponS2::new(1, 2));
+    let e:Vec<Option<i16>> = vec![Some(-1), None, Some(1)];
+
+    let buffer = buffer!((a, b):Option<u32>, (c):Option<String>, (d):Option<TamponS2>, [e]:Option<i16>);
+    deserialize!(buffer, bytes_read, (f, g):Option<u32>, (h):Option<String>, (i):Option<TamponS2>, [j]:Option<i16>);
+
+    assert_eq!(bytes_read, buffer.len());
+    assert!(a == f && b == g && c == h && d == i && e == j);
+}
+
+#[test]
+// None is only the presence byte
+fn option_bytes_size() {
+    let a:Option<u64> = Some(1);
+    let b:Option<u64> = None;
+    let c:Vec<Option<u64>> = vec![None, Some(2), None];
+
+    assert_eq!(bytes_size!((a):Option<u64>), 9);
+    assert_eq!(bytes_size!((b):Option<u64>), 1);
+    assert_eq!(bytes_size!([c]:Option<u64>), 4 + 1 + 9 + 1);
+    assert_eq!(b.bytes_size(), 1);
+}
+
+#[test]
+// Option is written as presence byte followed by value when Some
+fn option_wire_format() {
+    let a:Option<u16> = Some(0x0102);
+    let b:Option<u16> = None;
+
+    assert_eq!(buffer!((a, b):Option<u16>), vec![1, 2, 1, 0]);
+}
+
+#[test]
+// Errors of Option values are offset after presence byte
+fn option_errors() {
+    let a:Option<u32> = Some(1);
+    let b:Option<String> = None;
+    let c:Vec<Option<TamponS3>> = vec![None, Some(TamponS3 { percent: 50 })];
+    let buffer = buffer!((a):Option<u32>, (b):Option<String>, [c]:Option<TamponS3>);
+
+    assert_eq!(try_deserialize_options(&buffer).map(|(a, b, _)| (a, b)), Ok((Some(1), None)));
+
+    // Truncated value after presence byte
+    assert_eq!(try_deserialize_options(&buffer[0..3]), Err(Error::UnexpectedEnd { offset: 1, needed: 4, available: 2 }));
+
+    // Invalid value inside slice
+    let mut invalid = buffer.clone();
+    invalid[12] = 101;
+    assert_eq!(try_deserialize_options(&invalid), Err(Error::InvalidValue { offset: 12, reason: String::from("percent > 100") }));
+
+    // Invalid value on serialize
+    let d:Vec<Option<TamponS3>> = vec![Some(TamponS3 { percent: 101 })];
+    assert_eq!(try_buffer!(64, [d]:Option<TamponS3>), Err(Error::InvalidValue { offset: 5, reason: String::from("percent > 100") }));
+
+    // Limits are passed to values
+    let e:Option<String> = Some(String::from("tampon"));
+    let buffer = buffer!((e):Option<String>);
+    assert_eq!(Option::<String>::try_deserialize_with(&buffer, &Limits::new(16, 2, 1024), &mut Budget::new()), Err(Error::LimitExceeded { offset: 1, length: 6, limit: 2 }));
+}
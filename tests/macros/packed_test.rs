@@ -0,0 +1,137 @@
+/*
+ * @file tampon/tests/packed_test.rs
+ *
+ * @module tampon::tests
+ *
+ * @brief Contains integration tests for packed bool slices.
+ *
+ * @details
+ * Contains integration tests for slices of bool written with 8 booleans per byte with Packed<bool>.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use tampon::{buffer, deserialize, bytes_size, serialize, try_buffer, try_deserialize, try_serialize, Error, Limits, Packed};
+
+// Occupancy grid with feature flags
+type Grid = (u8, Vec<bool>, Vec<bool>);
+
+// Deserialize a grid without panicking
+fn try_deserialize_grid(buffer : &[u8], limits : &Limits) -> Result<Grid, Error> {
+    try_deserialize!(buffer, limits = limits, (a):u8, [b]:Packed<bool>, (c):Packed<Vec<bool>>);
+    Ok((a, b, c))
+}
+
+// Pattern of booleans with `len` elements
+fn pattern(len : usize) -> Vec<bool> {
+    (0..len).map(|index| index % 3 == 0 || index % 7 == 1).collect()
+}
+
+#[test]
+// Booleans are packed least significant bit first
+fn packed_wire_format() {
+    let a:Vec<bool> = Vec::new();
+    assert_eq!(buffer!([a]:Packed<bool>), vec![0, 0, 0, 0]);
+
+    let b = [true];
+    assert_eq!(buffer!([b]:Packed<bool>), vec![1, 0, 0, 0, 1]);
+
+    let c = [false, true, false, false, false, false, false, true];
+    assert_eq!(buffer!([c]:Packed<bool>), vec![8, 0, 0, 0, 0b1000_0010]);
+
+    let d = vec![true; 9];
+    assert_eq!(buffer!([d]:Packed<bool>), vec![9, 0, 0, 0, 0xFF, 0b0000_0001]);
+    assert_eq!(buffer!([d]:Packed<bool>), buffer!((d):Packed<Vec<bool>>));
+
+    // Single booleans are written like bool
+    assert_eq!(buffer!((true):Packed<bool>, (false):Packed<bool>), buffer!((true):bool, (false):bool));
+}
+
+#[test]
+// bytes_size! counts 1 byte for each 8 booleans
+fn packed_bytes_size() {
+    for len in [0, 1, 7, 8, 9, 15, 16, 17, 1000] {
+        let a = pattern(len);
+        assert_eq!(bytes_size!([a]:Packed<bool>), 4 + len.div_ceil(8));
+        assert_eq!(bytes_size!([a]:Packed<bool>), buffer!([a]:Packed<bool>).len());
+        assert_eq!(bytes_size!((a):Packed<Vec<bool>>), bytes_size!([a]:Packed<bool>));
+    }
+
+    let b = vec![true; 4000];
+    assert_eq!((bytes_size!([b]:bool), bytes_size!([b]:Packed<bool>)), (4004, 504));
+}
+
+#[test]
+// Packed slices round trip in all macros, including lengths that aren't multiples of 8
+fn packed_round_trip() {
+    for len in [0, 1, 5, 8, 13, 64, 65, 1001] {
+        let a:u8 = 7;
+        let b = pattern(len);
+        let c = vec![true; len % 11];
+
+        let size = bytes_size!((a):u8, [b]:Packed<bool>, (c):Packed<Vec<bool>>);
+        let buffer = buffer!((a):u8, [b]:Packed<bool>, (c):Packed<Vec<bool>>);
+        assert_eq!(buffer.len(), size);
+        assert_eq!(try_buffer!(size, (a):u8, [b]:Packed<bool>, (c):Packed<Vec<bool>>), Ok(buffer.clone()));
+
+        let mut copy = vec![0u8; size];
+        serialize!(copy, bytes_copied, (a):u8, [b]:Packed<bool>, (c):Packed<Vec<bool>>);
+        assert_eq!((bytes_copied, &copy), (size, &buffer));
+
+        let mut fallible = vec![0u8; size];
+        assert_eq!(try_serialize!(fallible, (a):u8, [b]:Packed<bool>, (c):Packed<Vec<bool>>), Ok(size));
+        assert_eq!(fallible, buffer);
+
+        deserialize!(buffer, bytes_read, (a2):u8, [b2]:Packed<bool>, (c2):Packed<Vec<bool>>);
+        assert_eq!(bytes_read, size);
+        assert_eq!((a2, &b2, &c2), (a, &b, &c));
+
+        assert_eq!(try_deserialize_grid(&buffer, &Limits::default()), Ok((a, b, c)));
+    }
+
+    // Nested vectors keep packing
+    let d = vec![pattern(3), Vec::new(), pattern(10)];
+    let buffer = buffer!([d]:Packed<Vec<bool>>);
+    assert_eq!(buffer.len(), 4 + 5 + 4 + 6);
+    deserialize!(buffer, [e]:Packed<Vec<bool>>);
+    assert_eq!(e, d);
+}
+
+#[test]
+#[should_panic]
+// deserialize! panics when padding bits are set
+fn packed_padding_panic() {
+    let buffer = [3u8, 0, 0, 0, 0b0000_1001];
+    deserialize!(buffer, [_a]:Packed<bool>);
+}
+
+#[test]
+// Packed slices return errors for invalid buffers
+fn packed_errors() {
+    let a = pattern(20);
+    let mut buffer = [0u8; 7];
+    assert_eq!(try_serialize!(buffer, [a]:Packed<bool>), Ok(7));
+    assert_eq!(try_serialize!(buffer, (1):u8, [a]:Packed<bool>), Err(Error::BufferTooSmall { required: 8, available: 7 }));
+
+    // Truncated and invalid content
+    let buffer = buffer!((1):u8, [a]:Packed<bool>, [a]:Packed<bool>);
+    assert_eq!(try_deserialize_grid(&buffer[0..3], &Limits::default()), Err(Error::UnexpectedEnd { offset: 1, needed: 4, available: 2 }));
+    assert_eq!(try_deserialize_grid(&buffer[0..7], &Limits::default()), Err(Error::InvalidLength { offset: 1, length: 3, available: 2 }));
+
+    let mut padded = buffer.clone();
+    padded[7] |= 0b0001_0000;
+    assert_eq!(try_deserialize_grid(&padded, &Limits::default()), Err(Error::InvalidValue { offset: 7, reason: String::from("padding bits of packed bool are set") }));
+
+    // Limits are enforced on number of booleans
+    assert_eq!(try_deserialize_grid(&buffer, &Limits::new(16, 16, 1024)), Err(Error::LimitExceeded { offset: 1, length: 20, limit: 16 }));
+}
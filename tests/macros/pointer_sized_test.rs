@@ -0,0 +1,86 @@
+/*
+ * @file tampon/tests/pointer_sized_test.rs
+ *
+ * @module tampon::tests
+ *
+ * @brief Contains integration tests for usize and isize portable encoding.
+ *
+ * @details
+ * Contains integration tests for usize and isize portable encoding.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use tampon::{buffer, deserialize, bytes_size, try_buffer, try_deserialize, Error};
+pub use tampon::Tampon;
+
+#[test]
+// usize and isize round trip in macros and slices
+fn pointer_sized_round_trip() {
+    let a:usize = usize::MAX;
+    let b:isize = isize::MIN;
+    let c:Vec<usize> = vec![0, 1, 2];
+    let d:Vec<isize> = vec![-1, 0, 1];
+
+    assert_eq!(bytes_size!((a):usize, (b):isize, [c]:usize, [d]:isize), 8 + 8 + 28 + 28);
+
+    let buffer = buffer!((a):usize, (b):isize, [c]:usize, [d]:isize);
+    deserialize!(buffer, bytes_read, (e):usize, (f):isize, [g]:usize, [h]:isize);
+
+    assert_eq!(bytes_read, buffer.len());
+    assert!(a == e && b == f && c == g && d == h);
+}
+
+#[test]
+// usize and isize are written exactly like u64 and i64
+fn pointer_sized_wire_format() {
+    let a:usize = 0x01020304;
+    let b:isize = -2;
+    let c:u64 = 0x01020304;
+    let d:i64 = -2;
+
+    assert_eq!(buffer!((a):usize, (b):isize), buffer!((c):u64, (d):i64));
+    assert_eq!(buffer!((a):usize), vec![4, 3, 2, 1, 0, 0, 0, 0]);
+    assert_eq!(a.bytes_size(), 8);
+}
+
+#[test]
+// usize and isize return errors for truncated buffer
+fn pointer_sized_errors() {
+    let buffer:Vec<u8> = vec![1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0];
+    let result = (|| -> Result<(usize, isize), Error> {
+        try_deserialize!(buffer, (a):usize, (b):isize);
+        Ok((a, b))
+    })();
+    assert_eq!(result, Err(Error::UnexpectedEnd { offset: 8, needed: 8, available: 3 }));
+
+    let a:usize = 1;
+    let mut buffer = vec![0u8; 4];
+    assert_eq!(a.try_serialize(&mut buffer), Err(Error::BufferTooSmall { required: 8, available: 4 }));
+    assert_eq!(try_buffer!(8, (a):usize).map(|buffer| buffer.len()), Ok(8));
+}
+
+#[test]
+// Values that don't fit target pointer width are out of range
+fn pointer_sized_out_of_range() {
+    let error = Error::OutOfRange { offset: 4, value: u64::MAX as i128 };
+    assert_eq!(error.offset(), 4);
+    assert_eq!(error.to_string(), "value 18446744073709551615 at offset 4 is out of range of target type");
+
+    // Only 32-bit targets can't hold every value written by 64-bit targets
+    if cfg!(target_pointer_width = "32") {
+        let c:u64 = u64::MAX;
+        let buffer = buffer!((c):u64);
+        assert_eq!(usize::try_deserialize(&buffer), Err(Error::OutOfRange { offset: 0, value: u64::MAX as i128 }));
+    }
+}
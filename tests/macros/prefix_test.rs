@@ -0,0 +1,190 @@
+/*
+ * @file tampon/tests/prefix_test.rs
+ *
+ * @module tampon::tests
+ *
+ * @brief Contains integration tests for length prefix widths.
+ *
+ * @details
+ * Contains integration tests for length prefix widths selected with Prefixed<P, T>.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use std::collections::BTreeMap;
+use tampon::{buffer, deserialize, bytes_size, serialize, try_buffer, try_deserialize, try_serialize, Error, Limits, Prefixed, Leb128, LengthPrefix};
+use crate::implementation::TamponS3;
+
+// Message of a chatty protocol
+type Message = (String, Vec<String>, Vec<u32>, Vec<u8>);
+
+// Deserialize a message with small prefixes without panicking
+fn try_deserialize_message(buffer : &[u8], limits : &Limits) -> Result<Message, Error> {
+    try_deserialize!(buffer, limits = limits, (a):Prefixed<u8, String>, (b):Prefixed<u16, Vec<String>>, [c]:Prefixed<Leb128, u32>, (d):Prefixed<u64, Vec<u8>>);
+    Ok((a, b, c, d))
+}
+
+#[test]
+// Length prefix is written with the selected width
+fn prefix_wire_format() {
+    assert_eq!(buffer!(("ab"):Prefixed<u8, str>), vec![2, b'a', b'b']);
+    assert_eq!(buffer!(("ab"):Prefixed<u16, str>), vec![2, 0, b'a', b'b']);
+    assert_eq!(buffer!(("ab"):Prefixed<u32, str>), buffer!(("ab"):str));
+    assert_eq!(buffer!(("ab"):Prefixed<u64, str>), vec![2, 0, 0, 0, 0, 0, 0, 0, b'a', b'b']);
+    assert_eq!(buffer!(("ab"):Prefixed<Leb128, str>), vec![2, b'a', b'b']);
+
+    // Varint prefix grows with length
+    let a = "a".repeat(300);
+    let buffer = buffer!((a):Prefixed<Leb128, String>);
+    assert_eq!((buffer.len(), &buffer[0..2]), (302, &[0xAC, 0x02][..]));
+
+    // Slices write their own prefix with the same width
+    let b:Vec<u16> = vec![1, 2];
+    assert_eq!(buffer!([b]:Prefixed<u8, u16>), vec![2, 1, 0, 2, 0]);
+    assert_eq!(buffer!([b]:Prefixed<u8, u16>), buffer!((b):Prefixed<u8, Vec<u16>>));
+
+    // Nested strings and slices use the same width
+    let c = vec![String::from("x"), String::from("yz")];
+    let d:Option<String> = Some(String::from("a"));
+    let e:(u16, String) = (7, String::from("a"));
+    let f:BTreeMap<u8, String> = [(1, String::from("a"))].into_iter().collect();
+    let g:[String; 2] = [String::from("a"), String::from("b")];
+    assert_eq!(buffer!((c):Prefixed<u8, Vec<String>>), vec![2, 1, b'x', 2, b'y', b'z']);
+    assert_eq!(buffer!([c]:Prefixed<u8, String>), vec![2, 1, b'x', 2, b'y', b'z']);
+    assert_eq!(buffer!((d):Prefixed<u8, Option<String>>), vec![1, 1, b'a']);
+    assert_eq!(buffer!((e):Prefixed<u8, (u16, String)>), vec![7, 0, 1, b'a']);
+    assert_eq!(buffer!((f):Prefixed<u8, BTreeMap<u8, String>>), vec![1, 1, 1, b'a']);
+    assert_eq!(buffer!((g):Prefixed<u8, [String; 2]>), vec![1, b'a', 1, b'b']);
+
+    // Implementors are written with their own encoding
+    let h = [TamponS3 { percent: 1 }, TamponS3 { percent: 2 }];
+    assert_eq!(buffer!([h]:Prefixed<u8, TamponS3>), vec![2, 1, 2]);
+
+    // Sizes follow prefixes
+    assert_eq!(bytes_size!((c):Prefixed<u8, Vec<String>>, [b]:Prefixed<u64, u16>, (a):Prefixed<Leb128, String>), 6 + 12 + 302);
+    assert_eq!((LengthPrefix::default(), LengthPrefix::Varint.size(127), LengthPrefix::Varint.size(128)), (LengthPrefix::U32, 1, 2));
+}
+
+#[test]
+// Prefixed fields round trip in all macros
+fn prefix_round_trip() {
+    let a = String::from("tampon");
+    let b = vec![String::from("a"), String::from("bc")];
+    let c:Vec<u32> = vec![1, 2, 3];
+    let d:Vec<u8> = vec![7; 4];
+    let e:Option<String> = Some(String::from("x"));
+    let f:Vec<Vec<String>> = vec![vec![String::from("a")], vec![]];
+
+    let size = bytes_size!((a):Prefixed<u8, String>, (b):Prefixed<u16, Vec<String>>, [c]:Prefixed<Leb128, u32>, (d):Prefixed<u64, Vec<u8>>, (e):Prefixed<u8, Option<String>>, [f]:Prefixed<u8, Vec<String>>);
+    assert_eq!(size, 7 + 9 + 13 + 12 + 3 + 5);
+
+    let buffer = buffer!((a):Prefixed<u8, String>, (b):Prefixed<u16, Vec<String>>, [c]:Prefixed<Leb128, u32>, (d):Prefixed<u64, Vec<u8>>, (e):Prefixed<u8, Option<String>>, [f]:Prefixed<u8, Vec<String>>);
+    assert_eq!(buffer.len(), size);
+    assert_eq!(try_buffer!(size, (a):Prefixed<u8, String>, (b):Prefixed<u16, Vec<String>>, [c]:Prefixed<Leb128, u32>, (d):Prefixed<u64, Vec<u8>>, (e):Prefixed<u8, Option<String>>, [f]:Prefixed<u8, Vec<String>>), Ok(buffer.clone()));
+
+    let mut copy = vec![0u8; size];
+    serialize!(copy, bytes_copied, (a):Prefixed<u8, String>, (b):Prefixed<u16, Vec<String>>, [c]:Prefixed<Leb128, u32>, (d):Prefixed<u64, Vec<u8>>, (e):Prefixed<u8, Option<String>>, [f]:Prefixed<u8, Vec<String>>);
+    assert_eq!((bytes_copied, &copy), (size, &buffer));
+
+    let mut fallible = vec![0u8; size];
+    assert_eq!(try_serialize!(fallible, (a):Prefixed<u8, String>, (b):Prefixed<u16, Vec<String>>, [c]:Prefixed<Leb128, u32>, (d):Prefixed<u64, Vec<u8>>, (e):Prefixed<u8, Option<String>>, [f]:Prefixed<u8, Vec<String>>), Ok(size));
+    assert_eq!(fallible, buffer);
+
+    deserialize!(buffer, bytes_read, (a2):Prefixed<u8, &str>, (b2):Prefixed<u16, Vec<String>>, [c2]:Prefixed<Leb128, u32>, (d2):Prefixed<u64, &[u8]>, (e2):Prefixed<u8, Option<String>>, [f2]:Prefixed<u8, Vec<String>>);
+    assert_eq!(bytes_read, size);
+    assert_eq!((a2, b2, c2, d2, e2, f2), (a.as_str(), b.clone(), c.clone(), &d[..], e, f));
+
+    let message = try_deserialize_message(&buffer, &Limits::default());
+    assert_eq!(message, Ok((a, b, c, d)));
+
+    // Empty strings take a single byte and aren't rejected by the length check
+    let g = vec![String::new(); 3];
+    let buffer = buffer!([g]:Prefixed<u8, String>);
+    assert_eq!(buffer, vec![3, 0, 0, 0]);
+    let result = (|| -> Result<Vec<String>, Error> { try_deserialize!(buffer, [h]:Prefixed<u8, String>); Ok(h) })();
+    assert_eq!(result, Ok(g));
+}
+
+#[test]
+#[should_panic]
+// serialize! panics when a length doesn't fit in the prefix
+fn prefix_overflow_panic() {
+    let a = "a".repeat(256);
+    let _buffer = buffer!((a):Prefixed<u8, String>);
+}
+
+#[test]
+// Prefixed fields return errors for invalid lengths and buffers
+fn prefix_errors() {
+    // Lengths that don't fit in the prefix
+    let a = "a".repeat(256);
+    let b = [String::from("a"), a.clone()];
+    let c = vec![0u8; 256];
+    let mut buffer = vec![0u8; 300];
+    assert_eq!(try_serialize!(buffer, (a):Prefixed<u8, String>), Err(Error::OutOfRange { offset: 0, value: 256 }));
+    assert_eq!(try_serialize!(buffer, (7):u8, [b]:Prefixed<u8, String>), Err(Error::OutOfRange { offset: 4, value: 256 }));
+    assert_eq!(try_serialize!(buffer, [c]:Prefixed<u8, u8>), Err(Error::OutOfRange { offset: 0, value: 256 }));
+    assert_eq!(try_serialize!(buffer, (a):Prefixed<u16, String>), Ok(258));
+
+    // Errors of implementors are offset by the prefix
+    let d = [TamponS3 { percent: 1 }, TamponS3 { percent: 101 }];
+    assert_eq!(try_buffer!(16, (7):u8, [d]:Prefixed<u8, TamponS3>), Err(Error::InvalidValue { offset: 3, reason: String::from("percent > 100") }));
+
+    // Truncated prefixes and content
+    let e = (String::from("ab"), vec![String::from("c")], vec![1u32], vec![2u8]);
+    let buffer = buffer!((e.0):Prefixed<u8, String>, (e.1):Prefixed<u16, Vec<String>>, [e.2]:Prefixed<Leb128, u32>, (e.3):Prefixed<u64, Vec<u8>>);
+    assert_eq!(try_deserialize_message(&buffer, &Limits::default()), Ok(e));
+    assert_eq!(try_deserialize_message(&buffer[0..2], &Limits::default()), Err(Error::InvalidLength { offset: 0, length: 2, available: 1 }));
+    assert_eq!(try_deserialize_message(&buffer[0..4], &Limits::default()), Err(Error::UnexpectedEnd { offset: 3, needed: 2, available: 1 }));
+    assert_eq!(try_deserialize_message(&buffer[0..8], &Limits::default()), Err(Error::UnexpectedEnd { offset: 8, needed: 1, available: 0 }));
+    assert_eq!(try_deserialize_message(&buffer[0..20], &Limits::default()), Err(Error::UnexpectedEnd { offset: 13, needed: 8, available: 7 }));
+
+    // Limits are enforced with any prefix
+    assert_eq!(try_deserialize_message(&buffer, &Limits::new(16, 1, 1024)), Err(Error::LimitExceeded { offset: 0, length: 2, limit: 1 }));
+    assert_eq!(try_deserialize_message(&buffer, &Limits::new(0, 16, 1024)), Err(Error::LimitExceeded { offset: 3, length: 1, limit: 0 }));
+
+    // Varint prefix bigger than u64
+    let overlong = [0xFFu8; 11];
+    let result = (|| -> Result<String, Error> { try_deserialize!(overlong, (f):Prefixed<Leb128, String>); Ok(f) })();
+    assert_eq!(result, Err(Error::InvalidValue { offset: 0, reason: String::from("varint is bigger than u64") }));
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+// Default length prefixes reject lengths bigger than u32 instead of truncating them
+fn prefix_default_out_of_range() {
+    use tampon::encoding::Encode;
+    use tampon::BigEndian;
+
+    let len = u32::MAX as usize + 1;
+    let mut buffer = vec![0u8; 8];
+    assert_eq!(<u8 as Encode>::try_serialize_len(len, &mut buffer), Err(Error::OutOfRange { offset: 0, value: 4294967296 }));
+    assert_eq!(<BigEndian<u8> as Encode>::try_serialize_len(len, &mut buffer), Err(Error::OutOfRange { offset: 0, value: 4294967296 }));
+    assert_eq!(buffer, vec![0u8; 8]);
+
+    // Lengths that fit are written as before
+    assert_eq!(<u8 as Encode>::try_serialize_len(u32::MAX as usize, &mut buffer), Ok(4));
+    assert_eq!(<BigEndian<u8> as Encode>::try_serialize_len(1, &mut buffer), Ok(4));
+    assert_eq!(&buffer[0..4], &[0, 0, 0, 1]);
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+#[should_panic]
+// Default length prefix panics instead of truncating
+fn prefix_default_out_of_range_panic() {
+    use tampon::encoding::Encode;
+
+    let mut buffer = vec![0u8; 8];
+    <u32 as Encode>::serialize_len(u32::MAX as usize + 1, &mut buffer);
+}
@@ -0,0 +1,438 @@
+/*
+ * @file tampon/tests/stream_test.rs
+ *
+ * @module tampon::tests
+ *
+ * @brief Contains integration tests for streaming to writer and from reader.
+ *
+ * @details
+ * Contains integration tests for serialize_to_writer!, deserialize_from_reader! and the streaming
+ * functions of Tampon.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use std::collections::{ BTreeMap, HashSet };
+use std::io::{ ErrorKind, Read, Write };
+use std::sync::atomic::{ AtomicUsize, Ordering };
+use tampon::{buffer, bytes_size, deserialize, deserialize_from_reader, serialize, serialize_to_writer, tampon_enum, try_deserialize, BigEndian, Error, Leb128, Budget, Limits, Packed, Prefixed, Sorted, Tampon, Varint};
+
+// Snapshot of a simulation
+type Snapshot = (u64, String, Vec<f32>, Vec<String>, BTreeMap<u32, String>);
+
+// Read a snapshot from reader
+fn read_snapshot(reader : &mut dyn Read, limits : &Limits) -> Result<(Snapshot, usize), Error> {
+    deserialize_from_reader!(reader, bytes_read, limits = limits, (a):u64, (b):String, [c]:f32, (d):Vec<String>, (e):BTreeMap<u32, String>);
+    Ok(((a, b, c, d, e), bytes_read))
+}
+
+tampon_enum! {
+    // Command of a stream
+    #[derive(Debug, PartialEq)]
+    enum Command : varint {
+        Stop = 0,
+        Load { name: String, chunks: [u32] } = 300,
+    }
+}
+
+// Snapshot with `len` elements in each collection
+fn snapshot(len : usize) -> Snapshot {
+    (
+        len as u64,
+        "x".repeat(len),
+        (0..len).map(|index| index as f32 * 0.5).collect(),
+        (0..len).map(|index| format!("entity {}", index)).collect(),
+        (0..len as u32).map(|index| (index, index.to_string())).collect(),
+    )
+}
+
+// Reader giving bytes one at a time
+struct SlowReader<'a> {
+    bytes : &'a [u8],
+}
+
+impl Read for SlowReader<'_> {
+    fn read(&mut self, buf : &mut [u8]) -> std::io::Result<usize> {
+        if self.bytes.is_empty() || buf.is_empty() {
+            return Ok(0);
+        }
+        buf[0] = self.bytes[0];
+        self.bytes = &self.bytes[1..];
+        Ok(1)
+    }
+}
+
+// Writer failing after `capacity` bytes
+struct FullWriter {
+    written : Vec<u8>,
+    capacity : usize,
+}
+
+impl Write for FullWriter {
+    fn write(&mut self, buf : &[u8]) -> std::io::Result<usize> {
+        if self.written.len() + buf.len() > self.capacity {
+            return Err(std::io::Error::new(ErrorKind::StorageFull, "disk full"));
+        }
+        self.written.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+// Reader failing after its bytes
+struct BrokenReader<'a> {
+    bytes : &'a [u8],
+}
+
+impl Read for BrokenReader<'_> {
+    fn read(&mut self, buf : &mut [u8]) -> std::io::Result<usize> {
+        if self.bytes.is_empty() {
+            return Err(std::io::Error::new(ErrorKind::ConnectionReset, "connection reset"));
+        }
+        let size = buf.len().min(self.bytes.len());
+        buf[..size].copy_from_slice(&self.bytes[..size]);
+        self.bytes = &self.bytes[size..];
+        Ok(size)
+    }
+}
+
+#[test]
+// Streamed bytes are the bytes of buffer!
+fn stream_same_bytes() {
+    for len in [0, 1, 100, 5000] {
+        let (a, b, c, d, e) = snapshot(len);
+
+        let mut writer:Vec<u8> = Vec::new();
+        let bytes_written = serialize_to_writer!(&mut writer, (a):u64, (b):String, [c]:f32, (d):Vec<String>, (e):BTreeMap<u32, String>).unwrap();
+
+        assert_eq!(writer, buffer!((a):u64, (b):String, [c]:f32, (d):Vec<String>, (e):BTreeMap<u32, String>));
+        assert_eq!(bytes_written, writer.len());
+    }
+}
+
+#[test]
+// Values bigger than the internal buffer are read back
+fn stream_round_trip() {
+    for len in [0, 1, 100, 5000] {
+        let (a, b, c, d, e) = snapshot(len);
+
+        let mut writer:Vec<u8> = Vec::new();
+        serialize_to_writer!(&mut writer, (a):u64, (b):String, [c]:f32, (d):Vec<String>, (e):BTreeMap<u32, String>).unwrap();
+
+        assert_eq!(read_snapshot(&mut &writer[..], &Limits::default()), Ok((snapshot(len), writer.len())));
+        assert_eq!(read_snapshot(&mut SlowReader { bytes: &writer }, &Limits::default()), Ok((snapshot(len), writer.len())));
+    }
+}
+
+#[test]
+// Bytes after values are left in reader
+fn stream_read_in_sequence() {
+    let mut writer:Vec<u8> = Vec::new();
+    serialize_to_writer!(&mut writer, (7u16):u16, (String::from("first")):String).unwrap();
+    serialize_to_writer!(&mut writer, (vec![1u32, 2, 3]):Vec<u32>, (0xABu8):u8).unwrap();
+
+    let mut reader = &writer[..];
+    let result:Result<_, Error> = (|| {
+        deserialize_from_reader!(&mut reader, bytes_read, (a):u16, (b):String);
+        Ok((a, b, bytes_read))
+    })();
+    assert_eq!(result, Ok((7, String::from("first"), 11)));
+
+    let result:Result<_, Error> = (|| {
+        deserialize_from_reader!(&mut reader, (c):Vec<u32>);
+        Ok(c)
+    })();
+    assert_eq!(result, Ok(vec![1, 2, 3]));
+    assert_eq!(reader, [0xAB]);
+}
+
+#[test]
+// Markers are streamed with their encoding
+fn stream_markers() {
+    let a:u32 = 0x01020304;
+    let b:Vec<u64> = vec![0, 300, u64::MAX];
+    let c:Vec<i32> = (-10000..10000).map(|value| value * 1000).collect();
+    let d:String = String::from("Prefixed");
+    let e:Vec<bool> = (0..20).map(|index| index % 3 == 0).collect();
+
+    let mut writer:Vec<u8> = Vec::new();
+    serialize_to_writer!(&mut writer, (a):BigEndian<u32>, [b]:Varint<u64>, (c):Varint<Vec<i32>>, (d):Prefixed<u8, String>, [e]:Packed<bool>).unwrap();
+    assert_eq!(writer, buffer!((a):BigEndian<u32>, [b]:Varint<u64>, (c):Varint<Vec<i32>>, (d):Prefixed<u8, String>, [e]:Packed<bool>));
+
+    let result:Result<_, Error> = (|| {
+        deserialize_from_reader!(&mut SlowReader { bytes: &writer }, (a):BigEndian<u32>, [b]:Varint<u64>, (c):Varint<Vec<i32>>, (d):Prefixed<u8, String>, [e]:Packed<bool>);
+        Ok((a, b, c, d, e))
+    })();
+    assert_eq!(result, Ok((a, b, c, d, e)));
+}
+
+#[test]
+// Functions of Tampon stream implementors
+fn stream_tampon_functions() {
+    let snapshot = snapshot(3000);
+
+    let mut writer:Vec<u8> = Vec::new();
+    assert_eq!(snapshot.serialize_to_writer(&mut writer), Ok(snapshot.bytes_size()));
+    assert_eq!(writer, buffer!((snapshot):Snapshot));
+
+    // Trailing bytes aren't read
+    writer.extend_from_slice(&[1, 2, 3]);
+    let mut reader = &writer[..];
+    assert_eq!(Snapshot::deserialize_from_reader(&mut reader), Ok((snapshot.clone(), snapshot.bytes_size())));
+    assert_eq!(reader, [1, 2, 3]);
+
+    // Trait objects stream too
+    let values:Vec<Box<dyn Tampon>> = vec![Box::new(12u8), Box::new(String::from("dyn"))];
+    let mut writer:Vec<u8> = Vec::new();
+    for value in values.iter() {
+        value.serialize_to_writer(&mut writer).unwrap();
+    }
+    assert_eq!(writer, buffer!((12u8):u8, (String::from("dyn")):String));
+}
+
+#[test]
+// Errors of writer and reader are returned with the bytes count before them
+fn stream_io_error() {
+    let (a, b, c, d, e) = snapshot(5000);
+
+    let mut writer = FullWriter { written: Vec::new(), capacity: 10000 };
+    assert_eq!(serialize_to_writer!(&mut writer, (a):u64, (b):String, [c]:f32, (d):Vec<String>, (e):BTreeMap<u32, String>),
+        Err(Error::Io { offset: 5016, kind: ErrorKind::StorageFull, message: String::from("disk full") }));
+
+    let buffer = buffer!((a):u64, (b):String, [c]:f32);
+    let result = read_snapshot(&mut BrokenReader { bytes: &buffer }, &Limits::default());
+    assert_eq!(result, Err(Error::Io { offset: buffer.len(), kind: ErrorKind::ConnectionReset, message: String::from("connection reset") }));
+    assert_eq!(result.unwrap_err().to_string(), format!("I/O error at offset {} (connection reset)", buffer.len()));
+}
+
+#[test]
+// Truncated reader returns errors with offset from start of stream
+fn stream_truncated() {
+    let buffer = buffer!((4554u16):u16, (String::from("Test")):String);
+
+    let read = |bytes : &[u8]| -> Result<(u16, String), Error> {
+        deserialize_from_reader!(&mut SlowReader { bytes }, (a):u16, (b):String);
+        Ok((a, b))
+    };
+    assert_eq!(read(&buffer), Ok((4554, String::from("Test"))));
+    assert_eq!(read(&buffer[..1]), Err(Error::UnexpectedEnd { offset: 0, needed: 2, available: 1 }));
+    assert_eq!(read(&buffer[..5]), Err(Error::UnexpectedEnd { offset: 2, needed: 4, available: 3 }));
+    assert_eq!(read(&buffer[..8]), Err(Error::InvalidLength { offset: 2, length: 4, available: 2 }));
+
+    // Corrupted length of a collection ends with reader instead of allocating it
+    let buffer = buffer!((u32::MAX):u32, (1u64):u64);
+    let result:Result<Vec<u64>, Error> = (|| {
+        deserialize_from_reader!(&mut &buffer[..], [a]:u64);
+        Ok(a)
+    })();
+    assert_eq!(result, Err(Error::UnexpectedEnd { offset: 12, needed: 8, available: 0 }));
+}
+
+#[test]
+// Limits are enforced before allocating
+fn stream_limits() {
+    let (a, b, c, d, e) = snapshot(100);
+    let buffer = buffer!((a):u64, (b):String, [c]:f32, (d):Vec<String>, (e):BTreeMap<u32, String>);
+
+    assert!(read_snapshot(&mut &buffer[..], &Limits::new(100, usize::MAX, usize::MAX)).is_ok());
+    assert_eq!(read_snapshot(&mut &buffer[..], &Limits::new(99, usize::MAX, usize::MAX)), Err(Error::LimitExceeded { offset: 112, length: 100, limit: 99 }));
+}
+
+#[test]
+// Enums of tampon_enum! are streamed variant by variant
+fn stream_enum() {
+    let commands = vec![Command::Load { name: String::from("map"), chunks: (0..5000).collect() }, Command::Stop];
+
+    let mut writer:Vec<u8> = Vec::new();
+    serialize_to_writer!(&mut writer, [commands]:Command).unwrap();
+    assert_eq!(writer, buffer!([commands]:Command));
+
+    assert_eq!(Vec::<Command>::deserialize_from_reader(&mut SlowReader { bytes: &writer }), Ok((commands, writer.len())));
+
+    // Unknown tag is offset from start of stream
+    let buffer = buffer!((1u32):u32, (5u8):u8);
+    assert_eq!(Vec::<Command>::deserialize_from_reader(&mut &buffer[..]), Err(Error::UnknownTag { offset: 4, tag: 5 }));
+}
+
+// Hand-written implementor with the required functions only
+#[derive(Debug, PartialEq)]
+struct Minimal {
+    value:u32,
+}
+
+impl Tampon for Minimal {
+    fn bytes_size(&self) -> usize {
+        4
+    }
+
+    fn serialize(&self, buffer : &mut [u8]) -> usize {
+        buffer[0..4].copy_from_slice(&self.value.to_le_bytes());
+        4
+    }
+
+    fn deserialize(buffer : &[u8]) -> (Self, usize) {
+        (Minimal { value: u32::from_le_bytes(buffer[0..4].try_into().unwrap()) }, 4)
+    }
+}
+
+#[test]
+// Implementors without try_deserialize return an error instead of panicking on bytes read so far
+fn stream_default_try_deserialize() {
+    let minimal = Minimal { value: 7 };
+    let mut writer:Vec<u8> = Vec::new();
+    assert_eq!(minimal.serialize_to_writer(&mut writer), Ok(4));
+
+    let error = Error::InvalidValue { offset: 0, reason: String::from("implementor of Tampon without try_deserialize can't be read from a reader") };
+    assert_eq!(Minimal::deserialize_from_reader(&mut &writer[..]), Err(error.clone()));
+
+    // Nested in collections and markers
+    let buffer = buffer!((vec![Minimal { value: 7 }]):Vec<Minimal>);
+    assert_eq!(Vec::<Minimal>::deserialize_from_reader(&mut &buffer[..]), Err(error.clone().offset_by(4)));
+    let result = (|| -> Result<Minimal, Error> {
+        deserialize_from_reader!(&mut &buffer[4..], (a):Prefixed<u8, Minimal>);
+        Ok(a)
+    })();
+    assert_eq!(result, Err(error));
+
+    // Buffers are still read with deserialize
+    assert_eq!(<Minimal as Tampon>::try_deserialize(&writer), Ok((minimal, 4)));
+
+    // Containers are fallible if their elements are
+    assert!(!Minimal::is_fallible() && !Vec::<Minimal>::is_fallible() && !<(u8, Option<Minimal>)>::is_fallible());
+    assert!(Names::is_fallible() && Vec::<Names>::is_fallible() && <(u8, Option<String>)>::is_fallible());
+}
+
+// Hand-written implementor decoded as a whole by streams
+#[derive(Debug, PartialEq)]
+struct Names {
+    first:String,
+    last:String,
+}
+
+impl Tampon for Names {
+    fn bytes_size(&self) -> usize {
+        bytes_size!((self.first):String, (self.last):String)
+    }
+
+    fn serialize(&self, buffer : &mut [u8]) -> usize {
+        serialize!(buffer, bytes_copied, (self.first):String, (self.last):String);
+        bytes_copied
+    }
+
+    fn deserialize(buffer : &[u8]) -> (Self, usize) {
+        deserialize!(buffer, bytes_read, (first):String, (last):String);
+        (Names { first, last }, bytes_read)
+    }
+
+    fn try_deserialize_with(buffer : &[u8], limits : &Limits, budget : &mut Budget) -> Result<(Self, usize), Error> {
+        try_deserialize!(buffer, bytes_read, limits = limits, budget = budget, (first):String, (last):String);
+        Ok((Names { first, last }, bytes_read))
+    }
+
+    fn is_fallible() -> bool {
+        true
+    }
+}
+
+#[test]
+// Allocations of attempts decoding bytes read so far are counted once
+fn stream_limits_attempts() {
+    let names = Names { first: "a".repeat(10), last: "b".repeat(10) };
+    let mut writer:Vec<u8> = Vec::new();
+    names.serialize_to_writer(&mut writer).unwrap();
+
+    assert_eq!(Names::deserialize_from_reader_with(&mut SlowReader { bytes: &writer }, &Limits::new(16, 16, 20)), Ok((names, writer.len())));
+    assert_eq!(Names::deserialize_from_reader_with(&mut SlowReader { bytes: &writer }, &Limits::new(16, 16, 19)),
+        Err(Error::LimitExceeded { offset: 14, length: 20, limit: 19 }));
+}
+
+// Count of decoding attempts of Catalog
+static CATALOG_ATTEMPTS : AtomicUsize = AtomicUsize::new(0);
+
+// Hand-written implementor with many elements decoded as a whole by streams
+#[derive(Debug, PartialEq)]
+struct Catalog {
+    names:Vec<String>,
+}
+
+impl Tampon for Catalog {
+    fn bytes_size(&self) -> usize {
+        bytes_size!([self.names]:String)
+    }
+
+    fn serialize(&self, buffer : &mut [u8]) -> usize {
+        serialize!(buffer, bytes_copied, [self.names]:String);
+        bytes_copied
+    }
+
+    fn deserialize(buffer : &[u8]) -> (Self, usize) {
+        deserialize!(buffer, bytes_read, [names]:String);
+        (Catalog { names }, bytes_read)
+    }
+
+    fn try_deserialize_with(buffer : &[u8], limits : &Limits, budget : &mut Budget) -> Result<(Self, usize), Error> {
+        CATALOG_ATTEMPTS.fetch_add(1, Ordering::Relaxed);
+        try_deserialize!(buffer, bytes_read, limits = limits, budget = budget, [names]:String);
+        Ok((Catalog { names }, bytes_read))
+    }
+
+    fn is_fallible() -> bool {
+        true
+    }
+}
+
+#[test]
+// Implementors decoded as a whole read ahead instead of being decoded again for each missing element
+fn stream_read_ahead() {
+    let catalog = Catalog { names: (0..10000).map(|index| index.to_string()).collect() };
+    let mut writer:Vec<u8> = Vec::new();
+    serialize_to_writer!(&mut writer, (catalog):Catalog, (7u32):u32).unwrap();
+
+    // Bytes read past the value are read by the next targets of the same stream
+    let result = (|| -> Result<(Catalog, u32), Error> {
+        deserialize_from_reader!(&mut &writer[..], bytes_read, (a):Catalog, (b):u32);
+        assert_eq!(bytes_read, writer.len());
+        Ok((a, b))
+    })();
+    assert_eq!(result, Ok((catalog, 7)));
+    assert!(CATALOG_ATTEMPTS.load(Ordering::Relaxed) < 10);
+}
+
+#[test]
+// Prefixed and sorted collections are read element by element
+fn stream_prefixed_collections() {
+    let a:Vec<String> = (0..3000).map(|index| format!("entity {}", index)).collect();
+    let b:BTreeMap<u32, String> = (0..3000).map(|index| (index, index.to_string())).collect();
+    let c:Sorted<HashSet<String>> = Sorted((0..3000).map(|index| index.to_string()).collect());
+    let d:Option<(u8, Vec<String>)> = Some((1, vec![String::from("x"); 200]));
+
+    let mut writer:Vec<u8> = Vec::new();
+    serialize_to_writer!(&mut writer, (a):Prefixed<u16, Vec<String>>, (b):Prefixed<Leb128, BTreeMap<u32, String>>, (c):Sorted<HashSet<String>>, (d):Prefixed<u8, Option<(u8, Vec<String>)>>).unwrap();
+    assert_eq!(writer, buffer!((a):Prefixed<u16, Vec<String>>, (b):Prefixed<Leb128, BTreeMap<u32, String>>, (c):Sorted<HashSet<String>>, (d):Prefixed<u8, Option<(u8, Vec<String>)>>));
+
+    let result:Result<_, Error> = (|| {
+        deserialize_from_reader!(&mut SlowReader { bytes: &writer }, bytes_read, (a):Prefixed<u16, Vec<String>>, (b):Prefixed<Leb128, BTreeMap<u32, String>>, (c):Sorted<HashSet<String>>, (d):Prefixed<u8, Option<(u8, Vec<String>)>>);
+        Ok(((a, b, c.0, d), bytes_read))
+    })();
+    assert_eq!(result, Ok(((a, b, c.0, d), writer.len())));
+
+    // Limits use the width of prefixes
+    let result:Result<Vec<String>, Error> = (|| {
+        deserialize_from_reader!(&mut &writer[..], limits = &Limits::new(2999, 16, usize::MAX), (a):Prefixed<u16, Vec<String>>);
+        Ok(a)
+    })();
+    assert_eq!(result, Err(Error::LimitExceeded { offset: 0, length: 3000, limit: 2999 }));
+}
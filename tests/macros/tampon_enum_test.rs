@@ -0,0 +1,180 @@
+/*
+ * @file tampon/tests/tampon_enum_test.rs
+ *
+ * @module tampon::tests
+ *
+ * @brief Contains unit and integration tests for tampon_enum! macro.
+ *
+ * @details
+ * Contains unit and integration tests for tampon_enum! macro.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use tampon::{tampon_enum, buffer, deserialize, try_deserialize, bytes_size, Error, TagWidth};
+pub use tampon::Tampon;
+use crate::implementation::TamponS1;
+
+tampon_enum! {
+    // Enum with every kind of variant
+    #[derive(Debug, PartialEq)]
+    pub enum Message : u8 {
+        Ping = 0,
+        Data(u32, [u8], bool) = 1,
+        Login { name: String, tokens: [u32] } = 2,
+        Nested(TamponS1, [TamponS1]) = 7,
+    }
+}
+
+tampon_enum! {
+    // Enum with u16 tag
+    #[derive(Debug, PartialEq)]
+    enum Wide16 : u16 {
+        A = 0,
+        B(i8) = 1000,
+    }
+}
+
+tampon_enum! {
+    // Enum with u32 tag
+    #[derive(Debug, PartialEq)]
+    enum Wide32 : u32 {
+        A = 70000,
+    }
+}
+
+tampon_enum! {
+    // Enum with varint tag
+    #[derive(Debug, PartialEq)]
+    enum Var : varint {
+        A = 1,
+        B { f1: f32 } = 300,
+    }
+}
+
+// Alias of a field type
+type Position = (f32, f32);
+
+tampon_enum! {
+    // Enum with generic, path, option, tuple, array and nested field types
+    #[derive(Debug, PartialEq, Clone)]
+    enum Shape : u8 {
+        Path(Vec<Position>, Option<String>) = 0,
+        Grid { cells: [Vec<u8>], size: [u16; 2], names: std::collections::BTreeMap<u8, std::string::String> } = 1,
+        Layers([Option<(u8, bool)>],) = 2,
+    }
+}
+
+// Deserialize a message without panicking
+fn try_deserialize_message(buffer : &[u8]) -> Result<Message, Error> {
+    try_deserialize!(buffer, (a):Message);
+    Ok(a)
+}
+
+#[test]
+// Test tampon_enum! round trip of every kind of variant
+fn tampon_enum_round_trip() {
+    let a = Message::Ping;
+    let b = Message::Data(12, vec![1, 2, 3], true);
+    let c = Message::Login { name: String::from("Tampon"), tokens: vec![u32::MAX, 0] };
+    let d = Message::Nested(TamponS1::new(1, 2, 3.0, 4), vec![TamponS1::new(5, 6, 7.0, 8)]);
+
+    let e = vec![Message::Ping];
+
+    let buffer = buffer!((a, b, c, d):Message, [e]:Message);
+    assert_eq!(buffer.len(), bytes_size!((a, b, c, d):Message, [e]:Message));
+
+    deserialize!(buffer, bytes_read, (f, g, h, i):Message, [j]:Message);
+    assert_eq!(bytes_read, buffer.len());
+    assert!(a == f && b == g && c == h && d == i && e == j);
+}
+
+#[test]
+// Test tampon_enum! wire format of tag followed by fields
+fn tampon_enum_wire_format() {
+    let a = Message::Data(12, vec![1, 2], false);
+    assert_eq!(buffer!((a):Message), vec![1, 12, 0, 0, 0, 2, 0, 0, 0, 1, 2, 0]);
+
+    let a = Wide16::B(-1);
+    assert_eq!(buffer!((a):Wide16), vec![232, 3, 255]);
+    let b = Wide32::A;
+    assert_eq!(buffer!((b):Wide32), vec![112, 17, 1, 0]);
+    let c = Var::B { f1: 0.0 };
+    assert_eq!(buffer!((c):Var), vec![172, 2, 0, 0, 0, 0]);
+    let d = Var::A;
+    assert_eq!(buffer!((d):Var), vec![1]);
+}
+
+#[test]
+// Test tampon_enum! returning error for unknown tag
+fn tampon_enum_unknown_tag() {
+    assert_eq!(try_deserialize_message(&[3]), Err(Error::UnknownTag { offset: 0, tag: 3 }));
+
+    // Offset of nested error is from start of buffer
+    assert_eq!(try_deserialize_message(&[1, 12, 0, 0, 0, 2, 0, 0, 0, 1]), Err(Error::InvalidLength { offset: 5, length: 2, available: 1 }));
+    assert_eq!(Var::try_deserialize(&[172, 3]), Err(Error::UnknownTag { offset: 0, tag: 428 }));
+}
+
+#[test]
+#[should_panic]
+// Test tampon_enum! panicking for unknown tag with deserialize!
+fn tampon_enum_unknown_tag_panic() {
+    let buffer: Vec<u8> = vec![3];
+    deserialize!(buffer, (_a):Message);
+}
+
+#[test]
+// Test tampon_enum! try_serialize leaving buffer untouched when too small
+fn tampon_enum_try_serialize() {
+    let a = Message::Login { name: String::from("Tampon"), tokens: vec![1] };
+    let mut buffer = vec![0u8; 8];
+
+    assert_eq!(a.try_serialize(&mut buffer), Err(Error::BufferTooSmall { required: 19, available: 8 }));
+    assert_eq!(buffer, vec![0u8; 8]);
+}
+
+#[test]
+// Test tag width rejecting tags bigger than width instead of truncating them
+fn tampon_enum_tag_width() {
+    let mut buffer = vec![0u8; 4];
+
+    assert_eq!(TagWidth::U8.max(), u8::MAX as u64);
+    assert_eq!(TagWidth::U8.try_serialize(300, &mut buffer), Err(Error::OutOfRange { offset: 0, value: 300 }));
+    assert_eq!(TagWidth::U16.try_serialize(70000, &mut buffer), Err(Error::OutOfRange { offset: 0, value: 70000 }));
+    assert_eq!(TagWidth::U16.try_serialize(300, &mut buffer), Ok(2));
+    assert_eq!(buffer, vec![44, 1, 0, 0]);
+    assert_eq!(TagWidth::U32.try_serialize(1, &mut buffer[..2]), Err(Error::BufferTooSmall { required: 4, available: 2 }));
+}
+
+#[test]
+#[should_panic]
+// Test tag width panicking for tag bigger than width
+fn tampon_enum_tag_width_panic() {
+    let mut buffer = vec![0u8; 4];
+    TagWidth::U8.serialize(256, &mut buffer);
+}
+
+#[test]
+// Test tampon_enum! with fields of any compatible type
+fn tampon_enum_field_types() {
+    let a = Shape::Path(vec![(1.0, 2.0), (3.0, 4.0)], Some(String::from("path")));
+    let b = Shape::Grid { cells: vec![vec![1, 2], vec![]], size: [2, 1], names: [(1, String::from("one"))].into_iter().collect() };
+    let c = Shape::Layers(vec![Some((7, true)), None]);
+
+    let buffer = buffer!((a, b, c):Shape);
+    assert_eq!(buffer[0..22], [0, 2, 0, 0, 0, 0, 0, 128, 63, 0, 0, 0, 64, 0, 0, 64, 64, 0, 0, 128, 64, 1]);
+    assert_eq!(buffer.len(), bytes_size!((a, b, c):Shape));
+
+    deserialize!(buffer, (d, e, f):Shape);
+    assert_eq!((d, e, f), (a, b, c));
+}
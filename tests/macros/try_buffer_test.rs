@@ -0,0 +1,80 @@
+/*
+ * @file tampon/tests/try_buffer_test.rs
+ *
+ * @module tampon::tests
+ *
+ * @brief Contains unit and integration tests for try_buffer! macro.
+ *
+ * @details
+ * Contains unit and integration tests for try_buffer! macro.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use tampon::{try_buffer, deserialize, Error};
+pub use tampon::Tampon;
+use crate::implementation::{TamponS1, TamponS3, TamponS4};
+
+use crate::{data::{ do_vecs_match, STRINGS, do_vecs_eq_match}, numeric_var, numeric_slice, string_var, string_slice, tampon_var};
+
+#[test]
+// Test try_buffer! with a maximum size too small
+fn try_buffer_too_small(){
+    let mut var_size = 0;
+    numeric_var!(var_size, to_n0:u64);
+    string_var!(var_size, STRINGS, 0, to_s0);
+
+    assert_eq!(try_buffer!(var_size - 1, (to_n0):u64, (to_s0):String), Err(Error::BufferTooSmall { required: var_size, available: var_size - 1 }));
+}
+
+#[test]
+// Test try_buffer! with a maximum size of exactly the content
+fn try_buffer_exact_size() -> Result<(), Error> {
+    let mut var_size = 0;
+    numeric_slice!(var_size, 0, to_ns0:i16);
+    string_slice!(var_size, STRINGS, 0, to_ss0);
+    tampon_var!(var_size, to_t0:TamponS1);
+
+    let buffer = try_buffer!(var_size, [to_ns0]:i16, [to_ss0]:String, (to_t0):TamponS1)?;
+
+    deserialize!(buffer, from_size, [from_ns0]:i16, [from_ss0]:String, (from_t0):TamponS1);
+
+    // All size should be the same
+    println!("SIZE | VAR={} | BUF={} | FROM={}", var_size, buffer.len(), from_size);
+    assert!(var_size == buffer.len() && buffer.len() == from_size);
+
+    assert!(do_vecs_match(&from_ns0, &to_ns0) && do_vecs_eq_match(&from_ss0, &to_ss0) && from_t0.eq(&to_t0));
+
+    Ok(())
+}
+
+#[test]
+// Test try_buffer! create a buffer of content size, not maximum size
+fn try_buffer_content_size() -> Result<(), Error> {
+    let mut var_size = 0;
+    numeric_var!(var_size, to_n0:u8, to_n1:f32);
+
+    let buffer = try_buffer!(usize::MAX, (to_n0):u8, (to_n1):f32)?;
+
+    assert!(buffer.len() == var_size);
+
+    Ok(())
+}
+
+#[test]
+// Test try_buffer! propagating error of nested implementor of Tampon
+fn try_buffer_nested_invalid_value(){
+    let a = TamponS4 { f1: 7, f2: TamponS3 { percent: 150 }, v1: Vec::new() };
+
+    assert_eq!(try_buffer!(1024, (a):TamponS4), Err(Error::InvalidValue { offset: 4, reason: String::from("percent > 100") }));
+}
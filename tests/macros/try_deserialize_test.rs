@@ -0,0 +1,331 @@
+/*
+ * @file tampon/tests/try_deserialize_test.rs
+ *
+ * @module tampon::tests
+ *
+ * @brief Contains unit and integration tests for try_deserialize! macro.
+ *
+ * @details
+ * Contains unit and integration tests for try_deserialize! macro.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use tampon::{serialize, try_deserialize, Error, Limits};
+pub use tampon::Tampon;
+use crate::implementation::{ TamponS1, TamponS2, TamponS3, TamponS4 };
+
+use crate::tampon_slice;
+use crate::{data::{ do_vecs_match, STRINGS, do_vecs_eq_match}, boolean_var, boolean_slice, numeric_var, numeric_slice, string_var, string_slice, tampon_var};
+
+// Error type of caller used to test From<tampon::Error> conversion
+#[derive(Debug, PartialEq)]
+enum PacketError {
+    Tampon(Error)
+}
+
+impl From<Error> for PacketError {
+    fn from(err: Error) -> Self {
+        PacketError::Tampon(err)
+    }
+}
+
+// Deserialize numerics and a string used by error tests
+fn try_deserialize_packet(buffer : &[u8]) -> Result<(u16, f64, String, usize), Error> {
+    try_deserialize!(buffer, bytes_read, (a):u16, (b):f64, (c):String);
+    Ok((a, b, c, bytes_read))
+}
+
+// Deserialize a slice used by error tests
+fn try_deserialize_slice(buffer : &[u8]) -> Result<Vec<u32>, Error> {
+    try_deserialize!(buffer, [a]:u32);
+    Ok(a)
+}
+
+// Deserialize with caller error type
+fn try_deserialize_packet_error(buffer : &[u8]) -> Result<u32, PacketError> {
+    try_deserialize!(buffer, (a):u32);
+    Ok(a)
+}
+
+#[test]
+// Test try_deserialize! of a valid buffer
+fn try_deserialize_valid() -> Result<(), Error> {
+    let mut var_size = 0;
+    numeric_var!(var_size, to_n0:u16, to_n1:f64);
+    string_var!(var_size, STRINGS, 0, to_s0);
+
+    let mut buffer:Vec<u8> = vec![0;var_size];
+    serialize!(buffer, (to_n0):u16, (to_n1):f64, (to_s0):String);
+
+    try_deserialize!(buffer, from_size, (from_n0):u16, (from_n1):f64, (from_s0):String);
+
+    println!("SIZE | VAR={} | FROM={}", var_size, from_size);
+    assert!(var_size == from_size);
+    assert!(from_n0 == to_n0 && from_n1 == to_n1 && from_s0.eq(&to_s0));
+
+    Ok(())
+}
+
+#[test]
+// Test try_deserialize! returning an empty buffer error
+fn try_deserialize_empty() {
+    assert_eq!(try_deserialize_packet(&[]), Err(Error::UnexpectedEnd { offset: 0, needed: 2, available: 0 }));
+}
+
+#[test]
+// Test try_deserialize! returning error at the offset where numeric is truncated
+fn try_deserialize_truncated_numeric() {
+    let buffer: Vec<u8> = vec![202, 17, 145, 184, 199];
+    assert_eq!(try_deserialize_packet(&buffer), Err(Error::UnexpectedEnd { offset: 2, needed: 8, available: 3 }));
+}
+
+#[test]
+// Test try_deserialize! returning error when string length prefix is bigger than buffer
+fn try_deserialize_invalid_length() {
+    let buffer: Vec<u8> = vec![202, 17, 145, 184, 199, 210, 231, 3, 240, 64, 14, 0, 0, 0, 69, 120, 97];
+    assert_eq!(try_deserialize_packet(&buffer), Err(Error::InvalidLength { offset: 10, length: 14, available: 3 }));
+}
+
+#[test]
+// Test try_deserialize! returning error when string isn't valid UTF-8
+fn try_deserialize_invalid_utf8() {
+    let buffer: Vec<u8> = vec![202, 17, 145, 184, 199, 210, 231, 3, 240, 64, 4, 0, 0, 0, 69, 120, 0xC3, 0x28];
+    assert_eq!(try_deserialize_packet(&buffer), Err(Error::InvalidUtf8 { offset: 16 }));
+}
+
+#[test]
+// Test try_deserialize! returning error when slice is truncated
+fn try_deserialize_truncated_slice() {
+    // Slice announce 3 u32 but only contains 2 and a half. Rejected before reading elements.
+    let buffer: Vec<u8> = vec![3, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3, 0];
+    assert_eq!(try_deserialize_slice(&buffer), Err(Error::InvalidLength { offset: 0, length: 12, available: 10 }));
+}
+
+#[test]
+// Test try_deserialize! converting error into caller error type
+fn try_deserialize_error_conversion() {
+    assert_eq!(try_deserialize_packet_error(&[1, 0, 0, 0]), Ok(1));
+    assert_eq!(try_deserialize_packet_error(&[1, 0]), Err(PacketError::Tampon(Error::UnexpectedEnd { offset: 0, needed: 4, available: 2 })));
+}
+
+// Deserialize a u16 followed by implementor of Tampon with validation
+fn try_deserialize_nested(buffer : &[u8]) -> Result<(u16, TamponS4), Error> {
+    try_deserialize!(buffer, (a):u16, (b):TamponS4);
+    Ok((a, b))
+}
+
+#[test]
+// Test try_deserialize! of valid nested implementors of Tampon
+fn try_deserialize_nested_valid() {
+    let buffer: Vec<u8> = vec![1, 0, 7, 0, 0, 0, 50, 2, 0, 0, 0, 10, 100];
+    assert_eq!(try_deserialize_nested(&buffer), Ok((1, TamponS4 { f1: 7, f2: TamponS3 { percent: 50 }, v1: vec![TamponS3 { percent: 10 }, TamponS3 { percent: 100 }] })));
+}
+
+#[test]
+// Test try_deserialize! propagating error of nested implementor with the offset in top buffer
+fn try_deserialize_nested_invalid_value() {
+    let buffer: Vec<u8> = vec![1, 0, 7, 0, 0, 0, 50, 2, 0, 0, 0, 10, 101];
+    assert_eq!(try_deserialize_nested(&buffer), Err(Error::InvalidValue { offset: 12, reason: String::from("percent > 100") }));
+
+    let buffer: Vec<u8> = vec![1, 0, 7, 0, 0, 0, 200, 2, 0, 0, 0, 10, 100];
+    assert_eq!(try_deserialize_nested(&buffer), Err(Error::InvalidValue { offset: 6, reason: String::from("percent > 100") }));
+}
+
+#[test]
+// Test try_deserialize! propagating truncated buffer of nested implementor with the offset in top buffer
+fn try_deserialize_nested_truncated() {
+    let buffer: Vec<u8> = vec![1, 0, 7, 0, 0, 0, 50, 2, 0, 0, 0, 10];
+    assert_eq!(try_deserialize_nested(&buffer), Err(Error::UnexpectedEnd { offset: 12, needed: 1, available: 0 }));
+}
+
+// Implementor written without bytes
+#[derive(Debug, PartialEq)]
+struct Empty;
+
+impl Tampon for Empty {
+    fn bytes_size(&self) -> usize {
+        0
+    }
+
+    fn serialize(&self, _buffer : &mut [u8]) -> usize {
+        0
+    }
+
+    fn deserialize(_buffer : &[u8]) -> (Self, usize) {
+        (Empty, 0)
+    }
+
+    fn try_deserialize(_buffer : &[u8]) -> Result<(Self, usize), Error> {
+        Ok((Empty, 0))
+    }
+}
+
+// Deserialize a slice and strings bounded by limits
+fn try_deserialize_limited(buffer : &[u8], limits : &Limits) -> Result<(Vec<u128>, Vec<String>), Error> {
+    try_deserialize!(buffer, limits = limits, [a]:u128, [b]:String);
+    Ok((a, b))
+}
+
+#[test]
+// Test try_deserialize! rejecting hostile slice length prefix with default limits
+fn try_deserialize_hostile_slice() {
+    // 9 bytes packet claiming four billion u128
+    let buffer: Vec<u8> = vec![255, 255, 255, 255, 0, 0, 0, 0, 0];
+    assert_eq!(try_deserialize_slice(&buffer), Err(Error::InvalidLength { offset: 0, length: 17179869180, available: 5 }));
+    assert_eq!(try_deserialize_limited(&buffer, &Limits::default()), Err(Error::InvalidLength { offset: 0, length: 68719476720, available: 5 }));
+}
+
+#[test]
+// Test try_deserialize! rejecting hostile length of elements written without bytes
+fn try_deserialize_hostile_empty_elements() {
+    let result = |buffer : &[u8]| -> Result<usize, Error> {
+        try_deserialize!(buffer, [a]:Empty);
+        Ok(a.len())
+    };
+    assert_eq!(result(&[0, 0, 0, 16]), Err(Error::InvalidLength { offset: 0, length: 268435456, available: 0 }));
+    assert_eq!(result(&[255, 255, 255, 255]), Err(Error::InvalidLength { offset: 0, length: 4294967295, available: 0 }));
+
+    // Lengths up to remaining bytes or 65536 are read
+    assert_eq!(result(&[0, 0, 1, 0]), Ok(65536));
+    assert_eq!(result(&[1, 0, 1, 0]), Err(Error::InvalidLength { offset: 0, length: 65537, available: 0 }));
+    let mut buffer = vec![1, 0, 1, 0];
+    buffer.resize(65541, 0);
+    assert_eq!(result(&buffer), Ok(65537));
+}
+
+#[test]
+// Test try_deserialize! rejecting slice over limit
+fn try_deserialize_slice_limit() {
+    let buffer: Vec<u8> = vec![2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+    assert_eq!(try_deserialize_limited(&buffer, &Limits::new(1, 16, 1024)), Err(Error::LimitExceeded { offset: 0, length: 2, limit: 1 }));
+}
+
+#[test]
+// Test try_deserialize! rejecting string over limit
+fn try_deserialize_string_limit() {
+    let buffer: Vec<u8> = vec![0, 0, 0, 0, 1, 0, 0, 0, 5, 0, 0, 0, 72, 101, 108, 108, 111];
+    assert_eq!(try_deserialize_limited(&buffer, &Limits::new(16, 4, 1024)), Err(Error::LimitExceeded { offset: 8, length: 5, limit: 4 }));
+
+    let limits = Limits::new(16, 5, 1024);
+    assert_eq!(try_deserialize_limited(&buffer, &limits), Ok((Vec::new(), vec![String::from("Hello")])));
+}
+
+#[test]
+// Test try_deserialize! rejecting total allocation over limit
+fn try_deserialize_alloc_limit() {
+    let buffer: Vec<u8> = vec![1, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0];
+    let limits = Limits::new(16, 16, 24);
+    assert_eq!(try_deserialize_limited(&buffer, &limits), Err(Error::LimitExceeded { offset: 20, length: 16 + core::mem::size_of::<String>(), limit: 24 }));
+
+    // Budget is counted per call, so limits can be reused
+    let buffer: Vec<u8> = vec![1, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+    let limits = Limits::new(16, 16, 40);
+    for _ in 0..3 {
+        assert_eq!(try_deserialize_limited(&buffer, &limits), Ok((vec![7], Vec::new())));
+    }
+
+    // Budget of a failed call isn't carried to the next one
+    let failing: Vec<u8> = vec![3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+    assert_eq!(try_deserialize_limited(&failing, &limits), Err(Error::LimitExceeded { offset: 0, length: 48, limit: 40 }));
+    assert_eq!(try_deserialize_limited(&buffer, &limits), Ok((vec![7], Vec::new())));
+}
+
+// Limits shared by all threads
+static SHARED_LIMITS: Limits = Limits::new(16, 16, 40);
+
+#[test]
+// Test try_deserialize! with the same limits in many threads
+fn try_deserialize_shared_limits() {
+    let buffer: Vec<u8> = vec![1, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                for _ in 0..100 {
+                    assert_eq!(try_deserialize_limited(&buffer, &SHARED_LIMITS), Ok((vec![7], Vec::new())));
+                }
+            });
+        }
+    });
+}
+
+#[test]
+// Test try_deserialize! passing limits to nested implementors of Tampon
+fn try_deserialize_nested_limit() {
+    let buffer: Vec<u8> = vec![7, 0, 0, 0, 50, 2, 0, 0, 0, 10, 100];
+    let limits = Limits::new(1, 0, 1024);
+
+    let result = (|| -> Result<TamponS4, Error> {
+        try_deserialize!(buffer, limits = &limits, (a):TamponS4);
+        Ok(a)
+    })();
+    assert_eq!(result, Err(Error::LimitExceeded { offset: 5, length: 2, limit: 1 }));
+}
+
+#[test]
+// Test try_deserialize! with everythings at the same time
+fn try_deserialize_everythings() -> Result<(), Error> {
+    let mut var_size = 0;
+    boolean_var!(var_size, to_b0, to_b1);
+    boolean_slice!(var_size, 0, to_bs0, to_bs1);
+    numeric_var!(var_size, to_n0:u8, to_n1:u16, to_n2:u32, to_n3:u64, to_n4:u128, to_n5:f32, to_n6:f64,
+        to_n7:i8, to_n8:i16, to_n9:i32, to_n10:i64, to_n11:i128);
+    numeric_slice!(var_size, 0, to_ns0:u8, to_ns1:u16, to_ns2:u32, to_ns3:u64, to_ns4:u128, to_ns5:f32, to_ns6:f64,
+        to_ns7:i8, to_ns8:i16, to_ns9:i32, to_ns10:i64, to_ns11:i128);
+    string_var!(var_size, STRINGS, 0, to_s0, to_s1, to_s2);
+    string_slice!(var_size, STRINGS, 0, to_ss0);
+    tampon_var!(var_size, to_t0:TamponS1, to_t1:TamponS2);
+    tampon_slice!(var_size, 0, to_ts0:TamponS1, to_ts1:TamponS2);
+
+    // Create buffer with serialize!
+    let mut buffer:Vec<u8> = vec![0;var_size];
+    serialize!(buffer, to_size, (to_b0,to_b1):bool, [to_bs0,to_bs1]:bool,
+        (to_n0):u8, (to_n1):u16, (to_n2):u32, (to_n3):u64, (to_n4):u128, (to_n5):f32, (to_n6):f64,
+        (to_n7):i8, (to_n8):i16, (to_n9):i32, (to_n10):i64, (to_n11):i128,
+        [to_ns0]:u8, [to_ns1]:u16, [to_ns2]:u32, [to_ns3]:u64, [to_ns4]:u128, [to_ns5]:f32, [to_ns6]:f64,
+        [to_ns7]:i8, [to_ns8]:i16, [to_ns9]:i32, [to_ns10]:i64, [to_ns11]:i128,
+        (to_s0, to_s1, to_s2):String, [to_ss0]:String,
+        (to_t0):TamponS1, (to_t1):TamponS2, [to_ts0]:TamponS1, [to_ts1]:TamponS2);
+
+    // Get back data with try_deserialize!
+    try_deserialize!(buffer, from_size, (from_b0,from_b1):bool, [from_bs0,from_bs1]:bool,
+        (from_n0):u8, (from_n1):u16, (from_n2):u32, (from_n3):u64, (from_n4):u128, (from_n5):f32, (from_n6):f64,
+        (from_n7):i8, (from_n8):i16, (from_n9):i32, (from_n10):i64, (from_n11):i128,
+        [from_ns0]:u8, [from_ns1]:u16, [from_ns2]:u32, [from_ns3]:u64, [from_ns4]:u128, [from_ns5]:f32, [from_ns6]:f64,
+        [from_ns7]:i8, [from_ns8]:i16, [from_ns9]:i32, [from_ns10]:i64, [from_ns11]:i128,
+        (from_s0, from_s1, from_s2):String, [from_ss0]:String,
+        (from_t0):TamponS1, (from_t1):TamponS2, [from_ts0]:TamponS1, [from_ts1]:TamponS2);
+
+    // All size should be the same
+    println!("SIZE | VAR={} | TO={} | FROM={}", var_size, to_size, from_size);
+    assert!(var_size == to_size && to_size == from_size);
+
+    // Compare results of serialize! VS try_deserialize!
+    assert!(from_b0 == to_b0 && from_b1 == to_b1
+        && do_vecs_match(&from_bs0, &to_bs0) && do_vecs_match(&from_bs1, &to_bs1)
+        && from_n0 == to_n0 && from_n1 == to_n1 && from_n2 == to_n2 && from_n3 == to_n3
+        && from_n4 == to_n4 && from_n5 == to_n5 && from_n6 == to_n6 && from_n7 == to_n7
+        && from_n8 == to_n8 && from_n9 == to_n9 && from_n10 == to_n10 && from_n11 == to_n11
+        && do_vecs_match(&from_ns0, &to_ns0) && do_vecs_match(&from_ns1, &to_ns1) && do_vecs_match(&from_ns2, &to_ns2)
+        && do_vecs_match(&from_ns3, &to_ns3) && do_vecs_match(&from_ns4, &to_ns4) && do_vecs_match(&from_ns5, &to_ns5)
+        && do_vecs_match(&from_ns6, &to_ns6) && do_vecs_match(&from_ns7, &to_ns7) && do_vecs_match(&from_ns8, &to_ns8)
+        && do_vecs_match(&from_ns9, &to_ns9) && do_vecs_match(&from_ns10, &to_ns10) && do_vecs_match(&from_ns11, &to_ns11)
+        && from_s0.eq(&to_s0) && from_s1.eq(&to_s1) && from_s2.eq(&to_s2)
+        && do_vecs_eq_match(&from_ss0, &to_ss0)
+        && from_t0.eq(&to_t0) && from_t1.eq(&to_t1)
+        && do_vecs_eq_match(&from_ts0, &to_ts0) && do_vecs_eq_match(&from_ts1, &to_ts1));
+    println!("Value retrieved successfully!");
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+/*
+ * @file tampon/tests/try_serialize_test.rs
+ *
+ * @module tampon::tests
+ *
+ * @brief Contains unit and integration tests for try_serialize! macro.
+ *
+ * @details
+ * Contains unit and integration tests for try_serialize! macro.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use tampon::{try_serialize, deserialize, Error};
+pub use tampon::Tampon;
+use crate::implementation::{ TamponS1, TamponS2, TamponS3, TamponS4 };
+
+use crate::tampon_slice;
+use crate::{data::{ do_vecs_match, STRINGS, do_vecs_eq_match}, boolean_var, boolean_slice, numeric_var, numeric_slice, string_var, string_slice, tampon_var};
+
+#[test]
+// Test try_serialize! into a buffer too small
+fn try_serialize_too_small(){
+    let mut var_size = 0;
+    numeric_var!(var_size, to_n0:u8, to_n1:u16, to_n2:u32, to_n3:u64, to_n4:u128, to_n5:f32, to_n6:f64,
+        to_n7:i8, to_n8:i16, to_n9:i32, to_n10:i64, to_n11:i128);
+    let mut buffer:Vec<u8> = vec![0;10];
+
+    let result = try_serialize!(buffer, (to_n0):u8, (to_n1):u16, (to_n2):u32, (to_n3):u64, (to_n4):u128, (to_n5):f32, (to_n6):f64,
+        (to_n7):i8, (to_n8):i16, (to_n9):i32, (to_n10):i64, (to_n11):i128);
+
+    assert_eq!(result, Err(Error::BufferTooSmall { required: var_size, available: 10 }));
+}
+
+#[test]
+// Test try_serialize! leave buffer untouched when too small
+fn try_serialize_too_small_untouched(){
+    let mut var_size = 0;
+    string_slice!(var_size, STRINGS, 0, to_ss0);
+    let mut buffer:Vec<u8> = vec![0;var_size - 1];
+
+    assert_eq!(try_serialize!(buffer, [to_ss0]:String), Err(Error::BufferTooSmall { required: var_size, available: var_size - 1 }));
+    assert!(buffer.iter().all(|b| *b == 0));
+}
+
+#[test]
+// Test try_serialize! into a buffer of exact size
+fn try_serialize_exact_size(){
+    let mut var_size = 0;
+    numeric_var!(var_size, to_n0:u16, to_n1:f64);
+    string_var!(var_size, STRINGS, 0, to_s0);
+
+    let mut buffer:Vec<u8> = vec![0;var_size];
+    let to_size = try_serialize!(buffer, (to_n0):u16, (to_n1):f64, (to_s0):String);
+
+    deserialize!(buffer, from_size, (from_n0):u16, (from_n1):f64, (from_s0):String);
+
+    assert_eq!(to_size, Ok(var_size));
+    assert!(var_size == from_size);
+    assert!(from_n0 == to_n0 && from_n1 == to_n1 && from_s0.eq(&to_s0));
+}
+
+#[test]
+// Test try_serialize! into a buffer bigger than needed
+fn try_serialize_bigger(){
+    let mut var_size = 0;
+    numeric_slice!(var_size, 0, to_ns0:u32);
+
+    // Fixed size frame bigger than content
+    let mut buffer = [0u8; 1024];
+    let to_size = try_serialize!(buffer, [to_ns0]:u32);
+
+    deserialize!(buffer, from_size, [from_ns0]:u32);
+
+    assert_eq!(to_size, Ok(var_size));
+    assert!(var_size == from_size);
+    assert!(do_vecs_match(&from_ns0, &to_ns0));
+}
+
+#[test]
+// Test try_serialize! of valid nested implementors of Tampon
+fn try_serialize_nested_valid(){
+    let a:u16 = 1;
+    let b = TamponS4 { f1: 7, f2: TamponS3 { percent: 50 }, v1: vec![TamponS3 { percent: 10 }, TamponS3 { percent: 100 }] };
+    let mut buffer = [0u8; 13];
+
+    assert_eq!(try_serialize!(buffer, (a):u16, (b):TamponS4), Ok(13));
+    assert_eq!(buffer, [1, 0, 7, 0, 0, 0, 50, 2, 0, 0, 0, 10, 100]);
+}
+
+#[test]
+// Test try_serialize! propagating error of nested implementor with the offset in top buffer
+fn try_serialize_nested_invalid_value(){
+    let a:u16 = 1;
+    let b = TamponS4 { f1: 7, f2: TamponS3 { percent: 50 }, v1: vec![TamponS3 { percent: 10 }, TamponS3 { percent: 101 }] };
+    let mut buffer = [0u8; 13];
+
+    assert_eq!(try_serialize!(buffer, (a):u16, (b):TamponS4), Err(Error::InvalidValue { offset: 12, reason: String::from("percent > 100") }));
+}
+
+#[test]
+// Test try_serialize! with everythings at the same time
+fn try_serialize_everythings(){
+    let mut var_size = 0;
+    boolean_var!(var_size, to_b0, to_b1);
+    boolean_slice!(var_size, 0, to_bs0);
+    numeric_var!(var_size, to_n0:u8, to_n1:i128);
+    numeric_slice!(var_size, 0, to_ns0:f32, to_ns1:i64);
+    string_var!(var_size, STRINGS, 0, to_s0, to_s1);
+    string_slice!(var_size, STRINGS, 0, to_ss0);
+    tampon_var!(var_size, to_t0:TamponS1, to_t1:TamponS2);
+    tampon_slice!(var_size, 0, to_ts0:TamponS1, to_ts1:TamponS2);
+
+    let mut buffer:Vec<u8> = vec![0;var_size];
+    let to_size = try_serialize!(buffer, (to_b0,to_b1):bool, [to_bs0]:bool, (to_n0):u8, (to_n1):i128,
+        [to_ns0]:f32, [to_ns1]:i64, (to_s0, to_s1):String, [to_ss0]:String,
+        (to_t0):TamponS1, (to_t1):TamponS2, [to_ts0]:TamponS1, [to_ts1]:TamponS2);
+
+    deserialize!(buffer, from_size, (from_b0,from_b1):bool, [from_bs0]:bool, (from_n0):u8, (from_n1):i128,
+        [from_ns0]:f32, [from_ns1]:i64, (from_s0, from_s1):String, [from_ss0]:String,
+        (from_t0):TamponS1, (from_t1):TamponS2, [from_ts0]:TamponS1, [from_ts1]:TamponS2);
+
+    // All size should be the same
+    println!("SIZE | VAR={} | TO={:?} | FROM={}", var_size, to_size, from_size);
+    assert!(to_size == Ok(var_size) && var_size == from_size);
+
+    assert!(from_b0 == to_b0 && from_b1 == to_b1 && do_vecs_match(&from_bs0, &to_bs0)
+        && from_n0 == to_n0 && from_n1 == to_n1
+        && do_vecs_match(&from_ns0, &to_ns0) && do_vecs_match(&from_ns1, &to_ns1)
+        && from_s0.eq(&to_s0) && from_s1.eq(&to_s1) && do_vecs_eq_match(&from_ss0, &to_ss0)
+        && from_t0.eq(&to_t0) && from_t1.eq(&to_t1)
+        && do_vecs_eq_match(&from_ts0, &to_ts0) && do_vecs_eq_match(&from_ts1, &to_ts1));
+    println!("Value retrieved successfully!");
+}
@@ -0,0 +1,80 @@
+/*
+ * @file tampon/tests/tuple_test.rs
+ *
+ * @module tampon::tests
+ *
+ * @brief Contains integration tests for tuples written as the concatenation of their members.
+ *
+ * @details
+ * Contains integration tests for tuples written as the concatenation of their members.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use tampon::{buffer, deserialize, bytes_size, try_buffer, try_deserialize, Error, Budget, Limits};
+pub use tampon::Tampon;
+use crate::implementation::{TamponS2, TamponS3};
+
+// Coordinate pair
+type Coordinate = (u16, f32);
+
+// Deserialize a pair and coordinates without panicking
+fn try_deserialize_pairs(buffer : &[u8]) -> Result<((u32, String), Vec<Coordinate>), Error> {
+    try_deserialize!(buffer, (a):(u32, String), [b]:Coordinate);
+    Ok((a, b))
+}
+
+#[test]
+// Tuples round trip in macros and slices
+fn tuple_round_trip() {
+    let a:(u32, String) = (12, String::from("tampon"));
+    let b:Vec<(u16, f32)> = vec![(1, 1.5), (2, -2.5)];
+    let c:(bool, (i8, TamponS2), [u8; 2]) = (true, (-1, TamponS2::new(1, 2)), [3, 4]);
+    let d = (1u8, 2u16, 3u32, 4u64, 5u128, 6i8, 7i16, 8i32, 9i64, 10i128, 11.0f32, 12.0f64);
+
+    let buffer = buffer!((a):(u32, String), [b]:(u16, f32), (c):(bool, (i8, TamponS2), [u8; 2]),
+        (d):(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64));
+    deserialize!(buffer, bytes_read, (e):(u32, String), [f]:(u16, f32), (g):(bool, (i8, TamponS2), [u8; 2]),
+        (h):(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64));
+
+    assert_eq!(bytes_read, buffer.len());
+    assert!(a == e && b == f && c == g && d == h);
+}
+
+#[test]
+// Tuples are written as the concatenation of their members
+fn tuple_wire_format() {
+    let a:(u8, u16) = (1, 0x0203);
+    let b:(u8,) = (4,);
+    let c:(u8, u16) = (1, 0x0203);
+
+    assert_eq!(bytes_size!((a):(u8, u16), (b):(u8,)), 4);
+    assert_eq!(buffer!((a):(u8, u16), (b):(u8,)), vec![1, 3, 2, 4]);
+    assert_eq!(buffer!((a):(u8, u16)), buffer!((c.0):u8, (c.1):u16));
+}
+
+#[test]
+// Errors of members are offset from the start of tuple
+fn tuple_errors() {
+    let a:(u32, String) = (12, String::from("tampon"));
+    let b:Vec<Coordinate> = vec![(1, 1.5)];
+    let buffer = buffer!((a):(u32, String), [b]:Coordinate);
+
+    assert_eq!(try_deserialize_pairs(&buffer), Ok((a, b)));
+    assert_eq!(try_deserialize_pairs(&buffer[0..6]), Err(Error::UnexpectedEnd { offset: 4, needed: 4, available: 2 }));
+    assert_eq!(<(u32, String)>::try_deserialize_with(&buffer, &Limits::new(16, 2, 1024), &mut Budget::new()), Err(Error::LimitExceeded { offset: 4, length: 6, limit: 2 }));
+
+    let c:(u8, TamponS3) = (1, TamponS3 { percent: 101 });
+    assert_eq!(try_buffer!(2, (c):(u8, TamponS3)), Err(Error::InvalidValue { offset: 1, reason: String::from("percent > 100") }));
+    assert_eq!(<(u8, TamponS3)>::try_deserialize(&[1, 101]).map(|(tuple, _)| tuple.0), Err(Error::InvalidValue { offset: 1, reason: String::from("percent > 100") }));
+}
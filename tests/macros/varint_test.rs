@@ -0,0 +1,140 @@
+/*
+ * @file tampon/tests/varint_test.rs
+ *
+ * @module tampon::tests
+ *
+ * @brief Contains integration tests for varint fields.
+ *
+ * @details
+ * Contains integration tests for integer fields written as LEB128 and ZigZag varint with Varint<T> and its aliases.
+ *
+ * @author Mathieu Grenier
+ * @copyright NickelAnge.Studio
+ *
+ * @date 2026-10-18
+ *
+ * @version
+ * 1.0 : 2026-10-18 | Mathieu Grenier | Code creation
+ *
+ * @ref
+ *
+ * @todo
+ */
+
+use tampon::{buffer, deserialize, bytes_size, serialize, try_buffer, try_deserialize, try_serialize, Error, Limits, Varint,
+    varu16, varu32, varu64, varusize, vari16, vari32, vari64, varisize};
+
+// Record of a telemetry stream
+type Record = (u64, i32, Vec<u32>, u16);
+
+// Deserialize a varint record without panicking
+fn try_deserialize_record(buffer : &[u8], limits : &Limits) -> Result<Record, Error> {
+    try_deserialize!(buffer, limits = limits, (a):varu64, (b):vari32, [c]:varu32, (d):u16);
+    Ok((a, b, c, d))
+}
+
+#[test]
+// Integers are written as LEB128, signed integers with ZigZag
+fn varint_wire_format() {
+    assert_eq!(buffer!((0):varu64), vec![0]);
+    assert_eq!(buffer!((127):varu32), vec![127]);
+    assert_eq!(buffer!((128):varu16), vec![0x80, 0x01]);
+    assert_eq!(buffer!((300):varusize), vec![0xAC, 0x02]);
+    assert_eq!(buffer!((u64::MAX):varu64), vec![0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01]);
+
+    assert_eq!(buffer!((0):vari16), vec![0]);
+    assert_eq!(buffer!((-1):vari32), vec![1]);
+    assert_eq!(buffer!((1):vari64), vec![2]);
+    assert_eq!(buffer!((-64):varisize), vec![127]);
+    assert_eq!(buffer!((64):vari32), vec![0x80, 0x01]);
+    assert_eq!(buffer!((i64::MIN):vari64), buffer!((u64::MAX):varu64));
+
+    // Slices have a length prefix followed by varints
+    let a:Vec<u32> = vec![1, 300];
+    assert_eq!(buffer!([a]:varu32), vec![2, 0, 0, 0, 1, 0xAC, 0x02]);
+    assert_eq!(buffer!([a]:varu32), buffer!((a):Varint<Vec<u32>>));
+}
+
+#[test]
+// bytes_size! returns exact size of varints
+fn varint_bytes_size() {
+    let values:[u64; 6] = [0, 127, 128, 16383, 16384, u64::MAX];
+    let sizes:[usize; 6] = [1, 1, 2, 2, 3, 10];
+
+    for (value, size) in values.into_iter().zip(sizes) {
+        assert_eq!(bytes_size!((value):varu64), size);
+        assert_eq!(bytes_size!((value):varu64), buffer!((value):varu64).len());
+    }
+
+    let a:Vec<i64> = vec![-1, 200, i64::MAX];
+    assert_eq!(bytes_size!((1u16):varu16, (-65):vari16, [a]:vari64), 1 + 2 + 4 + 1 + 2 + 10);
+}
+
+#[test]
+// Varint fields round trip in all macros
+fn varint_round_trip() {
+    let a:u64 = 1 << 40;
+    let b:i32 = -300;
+    let c:Vec<u32> = vec![0, 127, u32::MAX];
+    let d:u16 = 0x0102;
+
+    let size = bytes_size!((a):varu64, (b):vari32, [c]:varu32, (d):u16);
+    assert_eq!(size, 6 + 2 + 4 + 1 + 1 + 5 + 2);
+
+    let buffer = buffer!((a):varu64, (b):vari32, [c]:varu32, (d):u16);
+    assert_eq!(buffer.len(), size);
+    assert_eq!(try_buffer!(size, (a):varu64, (b):vari32, [c]:varu32, (d):u16), Ok(buffer.clone()));
+
+    let mut copy = vec![0u8; size];
+    serialize!(copy, bytes_copied, (a):varu64, (b):vari32, [c]:varu32, (d):u16);
+    assert_eq!((bytes_copied, &copy), (size, &buffer));
+
+    let mut fallible = vec![0u8; size];
+    assert_eq!(try_serialize!(fallible, (a):varu64, (b):vari32, [c]:varu32, (d):u16), Ok(size));
+    assert_eq!(fallible, buffer);
+
+    deserialize!(buffer, bytes_read, (a2):varu64, (b2):vari32, [c2]:varu32, (d2):u16);
+    assert_eq!(bytes_read, size);
+    assert_eq!((a2, b2, &c2, d2), (a, b, &c, d));
+
+    assert_eq!(try_deserialize_record(&buffer, &Limits::default()), Ok((a, b, c, d)));
+
+    // Extremes of signed types
+    let e:[i64; 4] = [i64::MIN, i64::MAX, -1, 0];
+    let buffer = buffer!([e]:vari64);
+    deserialize!(buffer, [f]:vari64);
+    assert_eq!(f, e);
+}
+
+#[test]
+#[should_panic]
+// deserialize! panics when a varint doesn't fit in the field type
+fn varint_out_of_range_panic() {
+    let buffer = buffer!((70000):varu32);
+    deserialize!(buffer, (_a):varu16);
+}
+
+#[test]
+// Varint fields return errors for invalid buffers
+fn varint_errors() {
+    let mut buffer = [0u8; 2];
+    assert_eq!(try_serialize!(buffer, (300):varu32, (1):varu32), Err(Error::BufferTooSmall { required: 3, available: 2 }));
+
+    // Values bigger than field type
+    let buffer = buffer!((7):u8, (70000):varu32, (-40000):vari32);
+    let result = (|| -> Result<u16, Error> { try_deserialize!(buffer, (_a):u8, (b):varu16); Ok(b) })();
+    assert_eq!(result, Err(Error::OutOfRange { offset: 1, value: 70000 }));
+    let result = (|| -> Result<i16, Error> { try_deserialize!(buffer, (_a):u8, (_b):varu32, (c):vari16); Ok(c) })();
+    assert_eq!(result, Err(Error::OutOfRange { offset: 4, value: -40000 }));
+
+    // Overlong and truncated varints
+    let overlong = [0x81u8, 0x80, 0x00, 0, 0];
+    assert_eq!(try_deserialize_record(&overlong, &Limits::default()), Err(Error::InvalidValue { offset: 0, reason: String::from("varint is overlong") }));
+
+    let buffer = buffer!((300):varu64, (-2):vari32, [vec![1u32, 128]]:varu32, (9):u16);
+    assert_eq!(try_deserialize_record(&buffer[0..1], &Limits::default()), Err(Error::UnexpectedEnd { offset: 1, needed: 1, available: 0 }));
+    assert_eq!(try_deserialize_record(&buffer[0..9], &Limits::default()), Err(Error::UnexpectedEnd { offset: 9, needed: 1, available: 0 }));
+
+    // Limits are enforced on varint slices
+    assert_eq!(try_deserialize_record(&buffer, &Limits::new(1, 16, 1024)), Err(Error::LimitExceeded { offset: 3, length: 2, limit: 1 }));
+}
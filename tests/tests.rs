@@ -22,9 +22,11 @@
  */
 
 // Tests data
+#[allow(clippy::redundant_static_lifetimes, clippy::manual_abs_diff, clippy::ptr_arg)]
 pub mod data;
 
 // Trait implementation
+#[allow(clippy::unnecessary_cast)]
 pub mod implementation;
 
 // Macro tests